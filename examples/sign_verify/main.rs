@@ -1,11 +1,5 @@
-extern crate moz_cbor as cbor;
 extern crate cose;
 
-#[macro_use(defer)]
-extern crate scopeguard;
-
-mod nss;
-mod test_nss;
 mod test_setup;
 mod util_test;
 
@@ -24,12 +18,6 @@ pub struct SignatureParameters<'a> {
     pkcs8: &'a [u8],
 }
 
-#[derive(Debug)]
-pub struct Signature<'a> {
-    parameter: &'a SignatureParameters<'a>,
-    signature_bytes: Vec<u8>,
-}
-
 const P256_PARAMS: SignatureParameters = SignatureParameters {
     certificate: &test::P256_EE,
     algorithm: SignatureAlgorithm::ES256,
@@ -239,11 +227,6 @@ fn test_cose_sign_verify_two_signatures_tampered_signature() {
 }
 
 fn main() {
-    // Basic NSS exmaple usage.
-    test_nss::test_nss_sign_verify();
-    test_nss::test_nss_sign_verify_different_payload();
-    test_nss::test_nss_sign_verify_wrong_cert();
-
     // COSE sign/verify example usages.
     test_cose_sign_verify_two_signatures_tampered_signature();
     test_cose_sign_verify_two_signatures_tampered_payload();
@@ -0,0 +1,374 @@
+//! The registry of signature and MAC algorithms.
+//!
+//! Every `SignatureAlgorithm` and `MacAlgorithm` has an entry with its identifier and name from
+//! the IANA "COSE Algorithms" registry and the parameters it is used with. The decoder, the
+//! encoder and the backends look algorithms up here rather than keeping their own tables.
+
+use {MacAlgorithm, SignatureAlgorithm};
+use decoder::{COSE_TYPE_ED25519, COSE_TYPE_ED448, COSE_TYPE_EDDSA, COSE_TYPE_ES256,
+              COSE_TYPE_ES256K, COSE_TYPE_ES384, COSE_TYPE_ES512, COSE_TYPE_HMAC256_256,
+              COSE_TYPE_HMAC256_64, COSE_TYPE_HMAC384_384, COSE_TYPE_HMAC512_512, COSE_TYPE_PS256,
+              COSE_TYPE_PS384, COSE_TYPE_PS512, COSE_TYPE_RS256, COSE_TYPE_RS384,
+              COSE_TYPE_RS512};
+
+/// The signature scheme of a signature algorithm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SignatureScheme {
+    Ecdsa,
+    /// RSASSA-PSS. The salt is as long as the hash and MGF1 uses the same hash.
+    RsaPss,
+    /// RSASSA-PKCS1-v1_5.
+    RsaPkcs1v15,
+    EdDSA,
+}
+
+/// The hash function a signature algorithm applies to the signed bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HashAlgorithm {
+    SHA256,
+    SHA384,
+    SHA512,
+}
+
+impl HashAlgorithm {
+    /// The size of the hash in bytes.
+    pub fn size(self) -> usize {
+        match self {
+            HashAlgorithm::SHA256 => 32,
+            HashAlgorithm::SHA384 => 48,
+            HashAlgorithm::SHA512 => 64,
+        }
+    }
+}
+
+/// A COSE key type (kty), see RFC 9053 section 7.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyType {
+    /// Octet key pair, e.g. Ed25519.
+    OKP,
+    /// Elliptic curve key with x and y coordinates.
+    EC2,
+    RSA,
+    Symmetric,
+}
+
+impl KeyType {
+    /// The kty value of the key type.
+    pub fn id(self) -> i64 {
+        match self {
+            KeyType::OKP => 1,
+            KeyType::EC2 => 2,
+            KeyType::RSA => 3,
+            KeyType::Symmetric => 4,
+        }
+    }
+
+    /// Look up a key type by its kty value.
+    pub fn from_id(id: i64) -> Option<KeyType> {
+        [KeyType::OKP, KeyType::EC2, KeyType::RSA, KeyType::Symmetric]
+            .iter()
+            .cloned()
+            .find(|key_type| key_type.id() == id)
+    }
+}
+
+/// An elliptic curve, see RFC 9053 section 7.1 and RFC 8812 section 3.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Curve {
+    P256,
+    P384,
+    P521,
+    Secp256k1,
+    X25519,
+    X448,
+    Ed25519,
+    Ed448,
+}
+
+const CURVES: [Curve; 8] = [Curve::P256,
+                            Curve::P384,
+                            Curve::P521,
+                            Curve::Secp256k1,
+                            Curve::X25519,
+                            Curve::X448,
+                            Curve::Ed25519,
+                            Curve::Ed448];
+
+impl Curve {
+    /// The crv value of the curve.
+    pub fn id(self) -> i64 {
+        match self {
+            Curve::P256 => 1,
+            Curve::P384 => 2,
+            Curve::P521 => 3,
+            Curve::X25519 => 4,
+            Curve::X448 => 5,
+            Curve::Ed25519 => 6,
+            Curve::Ed448 => 7,
+            Curve::Secp256k1 => 8,
+        }
+    }
+
+    /// Look up a curve by its crv value.
+    pub fn from_id(id: i64) -> Option<Curve> {
+        CURVES.iter().cloned().find(|curve| curve.id() == id)
+    }
+
+    /// The name of the curve, which is the same in the COSE and the JOSE registries.
+    pub fn name(self) -> &'static str {
+        match self {
+            Curve::P256 => "P-256",
+            Curve::P384 => "P-384",
+            Curve::P521 => "P-521",
+            Curve::Secp256k1 => "secp256k1",
+            Curve::X25519 => "X25519",
+            Curve::X448 => "X448",
+            Curve::Ed25519 => "Ed25519",
+            Curve::Ed448 => "Ed448",
+        }
+    }
+
+    /// Look up a curve by its name, e.g. "P-256".
+    pub fn from_name(name: &str) -> Option<Curve> {
+        CURVES.iter().cloned().find(|curve| curve.name() == name)
+    }
+
+    /// The type of the keys on the curve.
+    pub fn key_type(self) -> KeyType {
+        match self {
+            Curve::P256 | Curve::P384 | Curve::P521 | Curve::Secp256k1 => KeyType::EC2,
+            Curve::X25519 | Curve::X448 | Curve::Ed25519 | Curve::Ed448 => KeyType::OKP,
+        }
+    }
+
+    /// The size in bytes of the coordinates of EC2 keys and of OKP keys.
+    pub fn key_size(self) -> usize {
+        match self {
+            Curve::P256 | Curve::Secp256k1 | Curve::X25519 | Curve::Ed25519 => 32,
+            Curve::P384 => 48,
+            Curve::P521 => 66,
+            Curve::X448 => 56,
+            Curve::Ed448 => 57,
+        }
+    }
+}
+
+/// The registry entry of a signature algorithm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AlgorithmInfo {
+    pub algorithm: SignatureAlgorithm,
+    /// The COSE algorithm identifier.
+    pub id: i64,
+    /// The name of the algorithm in the IANA registry.
+    pub name: &'static str,
+    pub scheme: SignatureScheme,
+    /// The hash of the signed bytes. EdDSA signs the bytes themselves and has none.
+    pub hash: Option<HashAlgorithm>,
+    pub key_type: KeyType,
+    /// The curve of the key. RSA keys have none, and EdDSA works with Ed25519 and Ed448 keys.
+    pub curve: Option<Curve>,
+    /// Deprecated algorithms should not be used for new signatures. EdDSA is superseded by
+    /// Ed25519 and Ed448, which fix the curve.
+    pub deprecated: bool,
+    /// The minimum size of the key in bits. RSA keys must have at least 2048 bits (RFC 8230
+    /// section 6 and RFC 8812 section 2), the size of other keys follows from the curve.
+    pub min_key_size: Option<usize>,
+}
+
+/// All signature algorithms, in the order of `SignatureAlgorithm`.
+pub const ALGORITHMS: [SignatureAlgorithm; 13] = [SignatureAlgorithm::ES256,
+                                                  SignatureAlgorithm::ES384,
+                                                  SignatureAlgorithm::ES512,
+                                                  SignatureAlgorithm::ES256K,
+                                                  SignatureAlgorithm::PS256,
+                                                  SignatureAlgorithm::PS384,
+                                                  SignatureAlgorithm::PS512,
+                                                  SignatureAlgorithm::RS256,
+                                                  SignatureAlgorithm::RS384,
+                                                  SignatureAlgorithm::RS512,
+                                                  SignatureAlgorithm::EdDSA,
+                                                  SignatureAlgorithm::Ed25519,
+                                                  SignatureAlgorithm::Ed448];
+
+const RSA_MIN_KEY_SIZE: usize = 2048;
+
+fn ecdsa(
+    algorithm: SignatureAlgorithm,
+    id: i64,
+    name: &'static str,
+    hash: HashAlgorithm,
+    curve: Curve,
+) -> AlgorithmInfo {
+    AlgorithmInfo {
+        algorithm,
+        id,
+        name,
+        scheme: SignatureScheme::Ecdsa,
+        hash: Some(hash),
+        key_type: KeyType::EC2,
+        curve: Some(curve),
+        deprecated: false,
+        min_key_size: None,
+    }
+}
+
+fn rsa(
+    algorithm: SignatureAlgorithm,
+    id: i64,
+    name: &'static str,
+    scheme: SignatureScheme,
+    hash: HashAlgorithm,
+) -> AlgorithmInfo {
+    AlgorithmInfo {
+        algorithm,
+        id,
+        name,
+        scheme,
+        hash: Some(hash),
+        key_type: KeyType::RSA,
+        curve: None,
+        deprecated: false,
+        min_key_size: Some(RSA_MIN_KEY_SIZE),
+    }
+}
+
+fn eddsa(
+    algorithm: SignatureAlgorithm,
+    id: i64,
+    name: &'static str,
+    curve: Option<Curve>,
+) -> AlgorithmInfo {
+    AlgorithmInfo {
+        algorithm,
+        id,
+        name,
+        scheme: SignatureScheme::EdDSA,
+        hash: None,
+        key_type: KeyType::OKP,
+        curve,
+        deprecated: curve.is_none(),
+        min_key_size: None,
+    }
+}
+
+impl SignatureAlgorithm {
+    /// The registry entry of the algorithm.
+    pub fn info(self) -> AlgorithmInfo {
+        use self::HashAlgorithm::*;
+        use self::SignatureScheme::{RsaPkcs1v15, RsaPss};
+        match self {
+            SignatureAlgorithm::ES256 => ecdsa(self, COSE_TYPE_ES256, "ES256", SHA256, Curve::P256),
+            SignatureAlgorithm::ES384 => ecdsa(self, COSE_TYPE_ES384, "ES384", SHA384, Curve::P384),
+            SignatureAlgorithm::ES512 => ecdsa(self, COSE_TYPE_ES512, "ES512", SHA512, Curve::P521),
+            SignatureAlgorithm::ES256K => {
+                ecdsa(self, COSE_TYPE_ES256K, "ES256K", SHA256, Curve::Secp256k1)
+            }
+            SignatureAlgorithm::PS256 => rsa(self, COSE_TYPE_PS256, "PS256", RsaPss, SHA256),
+            SignatureAlgorithm::PS384 => rsa(self, COSE_TYPE_PS384, "PS384", RsaPss, SHA384),
+            SignatureAlgorithm::PS512 => rsa(self, COSE_TYPE_PS512, "PS512", RsaPss, SHA512),
+            SignatureAlgorithm::RS256 => rsa(self, COSE_TYPE_RS256, "RS256", RsaPkcs1v15, SHA256),
+            SignatureAlgorithm::RS384 => rsa(self, COSE_TYPE_RS384, "RS384", RsaPkcs1v15, SHA384),
+            SignatureAlgorithm::RS512 => rsa(self, COSE_TYPE_RS512, "RS512", RsaPkcs1v15, SHA512),
+            SignatureAlgorithm::EdDSA => eddsa(self, COSE_TYPE_EDDSA, "EdDSA", None),
+            SignatureAlgorithm::Ed25519 => {
+                eddsa(self, COSE_TYPE_ED25519, "Ed25519", Some(Curve::Ed25519))
+            }
+            SignatureAlgorithm::Ed448 => eddsa(self, COSE_TYPE_ED448, "Ed448", Some(Curve::Ed448)),
+        }
+    }
+
+    /// The COSE algorithm identifier.
+    pub fn id(self) -> i64 {
+        self.info().id
+    }
+
+    /// The name of the algorithm in the IANA registry.
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+}
+
+/// Look up a signature algorithm by its COSE identifier.
+pub fn from_id(id: i64) -> Option<SignatureAlgorithm> {
+    ALGORITHMS.iter().cloned().find(|algorithm| algorithm.id() == id)
+}
+
+/// Look up a signature algorithm by its name in the IANA registry, e.g. "ES256".
+pub fn from_name(name: &str) -> Option<SignatureAlgorithm> {
+    ALGORITHMS.iter().cloned().find(|algorithm| algorithm.name() == name)
+}
+
+/// The registry entry of a MAC algorithm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MacAlgorithmInfo {
+    pub algorithm: MacAlgorithm,
+    /// The COSE algorithm identifier.
+    pub id: i64,
+    /// The name of the algorithm in the IANA registry.
+    pub name: &'static str,
+    /// The hash function of the HMAC.
+    pub hash: HashAlgorithm,
+    /// The length of the tag in bytes. The HMAC output is truncated to it.
+    pub tag_length: usize,
+}
+
+/// All MAC algorithms, in the order of `MacAlgorithm`.
+pub const MAC_ALGORITHMS: [MacAlgorithm; 4] = [MacAlgorithm::HMAC256_64,
+                                               MacAlgorithm::HMAC256_256,
+                                               MacAlgorithm::HMAC384_384,
+                                               MacAlgorithm::HMAC512_512];
+
+fn hmac(
+    algorithm: MacAlgorithm,
+    id: i64,
+    name: &'static str,
+    hash: HashAlgorithm,
+    tag_length: usize,
+) -> MacAlgorithmInfo {
+    MacAlgorithmInfo {
+        algorithm,
+        id,
+        name,
+        hash,
+        tag_length,
+    }
+}
+
+impl MacAlgorithm {
+    /// The registry entry of the algorithm.
+    pub fn info(self) -> MacAlgorithmInfo {
+        use self::HashAlgorithm::*;
+        match self {
+            MacAlgorithm::HMAC256_64 => hmac(self, COSE_TYPE_HMAC256_64, "HMAC 256/64", SHA256, 8),
+            MacAlgorithm::HMAC256_256 => {
+                hmac(self, COSE_TYPE_HMAC256_256, "HMAC 256/256", SHA256, 32)
+            }
+            MacAlgorithm::HMAC384_384 => {
+                hmac(self, COSE_TYPE_HMAC384_384, "HMAC 384/384", SHA384, 48)
+            }
+            MacAlgorithm::HMAC512_512 => {
+                hmac(self, COSE_TYPE_HMAC512_512, "HMAC 512/512", SHA512, 64)
+            }
+        }
+    }
+
+    /// The COSE algorithm identifier.
+    pub fn id(self) -> i64 {
+        self.info().id
+    }
+
+    /// The name of the algorithm in the IANA registry.
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+}
+
+/// Look up a MAC algorithm by its COSE identifier.
+pub fn mac_from_id(id: i64) -> Option<MacAlgorithm> {
+    MAC_ALGORITHMS.iter().cloned().find(|algorithm| algorithm.id() == id)
+}
+
+/// Look up a MAC algorithm by its name in the IANA registry, e.g. "HMAC 256/256".
+pub fn mac_from_name(name: &str) -> Option<MacAlgorithm> {
+    MAC_ALGORITHMS.iter().cloned().find(|algorithm| algorithm.name() == name)
+}
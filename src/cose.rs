@@ -1,39 +1,62 @@
-//! This crate implements [COSE](https://tools.ietf.org/html/rfc8152) signature
-//! parsing. Verification has to be performed by the caller.
+//! This crate implements [COSE](https://tools.ietf.org/html/rfc8152) signature and MAC
+//! parsing and encoding. The cryptography is provided by the caller through the `Signer`,
+//! `Verifier` and `Mac` traits.
 //!
 //! Example usage: Let `payload` and `cose_signature` be variables holding the
 //! signed payload and the COSE signature bytes respectively.
-//! Let further `verify_callback` be a function callback that implements
-//! signature verification.
+//! Let further `verifier` be an implementation of `Verifier` for the
+//! signature algorithms that are accepted.
 //!
 //!```rust,ignore
-//! use cose::decoder::decode_signature;
+//! use cose::verify::{SignerPolicy, VerificationPolicy, verify_signature};
 //!
-//! // Parse the incoming signature.
-//! let cose_signatures = decode_signature(cose_signature, &payload);
-//! let cose_signatures = match cose_signatures {
-//!     Ok(signature) => signature,
-//!     Err(_) => Vec::new(),
-//! };
-//! if cose_signatures.len() < 1 {
-//!     return false;
-//! }
-//!
-//! let mut result = true;
-//! for cose_signature in cose_signatures {
-//!     // Call callback to verify the parsed signatures.
-//!     result &= verify_callback(cose_signature);
-//!
-//!     // We can stop early. The cose_signature is not valid.
-//!     if !result {
-//!         return result;
-//!     }
+//! // Parse the incoming signature and verify all signatures with the signing certificates they
+//! // carry.
+//! let policy = VerificationPolicy::new(SignerPolicy::All);
+//! match verify_signature(cose_signature, &payload, &verifier, &policy) {
+//!     Ok(report) => report.is_verified(),
+//!     Err(_) => false,
 //! }
 //!```
+#[cfg(feature = "rustcrypto")]
+extern crate ed25519_dalek;
+#[cfg(feature = "rustcrypto")]
+extern crate hmac;
+#[cfg(feature = "rustcrypto")]
+extern crate k256;
 extern crate moz_cbor as cbor;
+#[cfg(feature = "openssl")]
+extern crate openssl as ossl;
+#[cfg(feature = "rustcrypto")]
+extern crate p256;
+#[cfg(feature = "rustcrypto")]
+extern crate p384;
+#[cfg(feature = "rustcrypto")]
+extern crate p521;
+#[cfg(feature = "rustcrypto")]
+extern crate rand_core;
+#[cfg(feature = "rustcrypto")]
+extern crate rsa;
+#[cfg(feature = "rustcrypto")]
+extern crate sha2;
+#[cfg(feature = "rustcrypto")]
+extern crate x509_cert;
 
+pub mod algorithm;
 pub mod decoder;
+pub mod encoder;
+pub mod header;
+pub mod jwk;
+pub mod key;
+#[cfg(feature = "nss")]
+pub mod nss;
+#[cfg(feature = "openssl")]
+pub mod openssl;
+#[cfg(feature = "rustcrypto")]
+pub mod rustcrypto;
 pub mod util;
+pub mod verify;
+pub mod x509;
 
 /// Errors that can be returned from COSE functions.
 #[derive(Debug, PartialEq)]
@@ -50,23 +73,116 @@ pub enum CoseError {
     UnknownSignatureScheme,
     SigningFailed,
     InvalidArgument,
+    UnknownCriticalHeader,
+    UnmappableParameter,
+    InvalidCertificate,
+    UntrustedCertificate,
+    KeyAlgorithmMismatch,
 }
 
 /// An enum identifying supported signature algorithms.
 /// Currently ES256 (ECDSA with P256 and SHA256), ES384 (ECDSA with P384 and SHA384)
-/// ES512 (ECDSA with P521 and SHA512), and PS256 (RSASSA-PSS with SHA256)
-/// are supported. Note that with PS256, the salt length is defined
-/// to be 32 bytes.
-#[derive(Debug)]
-#[derive(PartialEq)]
+/// ES512 (ECDSA with P521 and SHA512), ES256K (ECDSA with secp256k1 and SHA256), PS256, PS384
+/// and PS512 (RSASSA-PSS with SHA256, SHA384 and SHA512), RS256, RS384 and RS512
+/// (RSASSA-PKCS1-v1_5 with SHA256, SHA384 and SHA512, see RFC 8812), and EdDSA are supported.
+/// Note that with PSS, the salt length is defined to be the length of the hash, e.g. 32 bytes
+/// for PS256, and MGF1 uses the same hash.
+/// ES256K signatures must have a low S value, i.e. S is at most half the order of the group:
+/// signers only produce such signatures and verifiers reject the equally valid ECDSA signature
+/// with the high S value, so that signatures are not malleable.
+/// EdDSA (-8) signs with Ed25519 or Ed448, depending on the key, while the
+/// fully specified Ed25519 (-19) and Ed448 (-53) also fix the curve. EdDSA signs the
+/// Sig_structure itself, it is not hashed first.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SignatureAlgorithm {
     ES256,
     ES384,
     ES512,
+    ES256K,
     PS256,
+    PS384,
+    PS512,
+    RS256,
+    RS384,
+    RS512,
+    EdDSA,
+    Ed25519,
+    /// Only the `openssl` backend implements Ed448. The `rustcrypto` backend has no Ed448
+    /// implementation and fails with `UnknownSignatureScheme`, like `nss` does for all of EdDSA.
+    Ed448,
+}
+
+/// Produces signatures over the bytes of a Sig_structure.
+///
+/// An implementation holds the private key. ECDSA signatures have to be returned in the fixed
+/// size `r || s` form COSE uses, not DER encoded.
+pub trait Signer {
+    /// Sign `to_sign` with the given algorithm.
+    fn sign(&self, algorithm: SignatureAlgorithm, to_sign: &[u8]) -> Result<Vec<u8>, CoseError>;
+}
+
+/// Verifies signatures over the bytes of a Sig_structure.
+///
+/// `key` is the key material of the signer, e.g. the DER encoded signing certificate of a
+/// `decoder::CoseSignature`. Implementations return `VerificationFailed` for invalid signatures
+/// and `UnknownSignatureScheme` for algorithms they don't support.
+pub trait Verifier {
+    /// Verify `signature` over `to_verify` with the given algorithm and key.
+    fn verify(
+        &self,
+        algorithm: SignatureAlgorithm,
+        key: &[u8],
+        to_verify: &[u8],
+        signature: &[u8],
+    ) -> Result<(), CoseError>;
+}
+
+/// An enum identifying supported MAC algorithms: HMAC with SHA-256 truncated to 64 bits, and
+/// HMAC with SHA-256, SHA-384 and SHA-512 (RFC 9053 section 3.1).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MacAlgorithm {
+    HMAC256_64,
+    HMAC256_256,
+    HMAC384_384,
+    HMAC512_512,
+}
+
+/// Computes HMACs over the bytes of a MAC_structure.
+///
+/// An implementation holds the symmetric key. It returns the full HMAC output, which is truncated
+/// to the tag length of the algorithm, e.g. 8 bytes for HMAC 256/64.
+pub trait Mac {
+    /// Compute the HMAC of `to_mac` with the given algorithm.
+    fn mac(&self, algorithm: MacAlgorithm, to_mac: &[u8]) -> Result<Vec<u8>, CoseError>;
 }
 
 #[cfg(test)]
 mod test_setup;
 #[cfg(test)]
+mod test_algorithm;
+#[cfg(all(test, any(feature = "nss", feature = "openssl", feature = "rustcrypto")))]
+mod test_keys;
+#[cfg(test)]
 mod test_cose;
+#[cfg(test)]
+mod test_encoder;
+#[cfg(test)]
+mod test_header;
+#[cfg(test)]
+mod test_jwk;
+#[cfg(test)]
+mod test_key;
+#[cfg(test)]
+mod test_mac;
+#[cfg(all(test, feature = "nss"))]
+mod test_nss;
+#[cfg(all(test, feature = "openssl"))]
+mod test_openssl;
+#[cfg(all(test, feature = "rustcrypto"))]
+mod test_rustcrypto;
+#[cfg(test)]
+mod test_util;
+#[cfg(test)]
+mod test_verify;
+#[cfg(test)]
+mod test_x509;
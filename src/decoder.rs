@@ -1,30 +1,264 @@
-//! Parse and decode COSE signatures.
+//! Parse and decode COSE signatures and MACs.
 
 use cbor::CborType;
 use cbor::decoder::decode;
-use {CoseError, SignatureAlgorithm};
-use util::get_sig_struct_bytes;
+use {CoseError, Mac, MacAlgorithm, SignatureAlgorithm, Verifier};
+use algorithm;
+use header::{COSE_HEADER_CONTENT_TYPE, COSE_HEADER_CRIT, COSE_HEADER_IV, COSE_HEADER_PARTIAL_IV,
+             COSE_HEADER_X5BAG, COSE_HEADER_X5CHAIN, CertificateProfile, Header, Label};
+use util::{MacContext, MacStructure, SigContext, SigStructure, constant_time_eq,
+           get_sig_struct_bytes, truncate_tag};
+use key::CoseKey;
+use x509::{ChainValidator, signer_public_key};
 use std::collections::BTreeMap;
 
 pub const COSE_SIGN_TAG: u64 = 98;
+pub const COSE_SIGN1_TAG: u64 = 18;
+pub const COSE_MAC_TAG: u64 = 97;
+pub const COSE_MAC0_TAG: u64 = 17;
 
 /// The result of `decode_signature` holding a decoded COSE signature.
 #[derive(Debug)]
 pub struct CoseSignature {
     pub signature_type: SignatureAlgorithm,
     pub signature: Vec<u8>,
+    /// The certificate of the signer.
     pub signer_cert: Vec<u8>,
+    /// The other certificates for building the chain of the signer. These are the intermediate
+    /// certificates of the legacy layout, or the rest of the x5chain followed by the certificates
+    /// in x5bags.
     pub certs: Vec<Vec<u8>>,
     pub to_verify: Vec<u8>,
+    pub protected_header: Header,
+    pub unprotected_header: Header,
+}
+
+/// The result of `decode_sign` holding a decoded COSE_Sign message.
+#[derive(Debug)]
+pub struct CoseSign {
+    pub payload: Vec<u8>,
+    pub protected_header: Header,
+    pub unprotected_header: Header,
+    pub signatures: Vec<CoseSignature>,
+}
+
+/// The result of `decode_sign1` holding a decoded COSE_Sign1 message.
+#[derive(Debug)]
+pub struct CoseSign1 {
+    pub signature_type: SignatureAlgorithm,
+    pub signature: Vec<u8>,
+    pub payload: Vec<u8>,
+    pub to_verify: Vec<u8>,
+    pub protected_header: Header,
+    pub unprotected_header: Header,
+}
+
+/// The result of `decode_mac0` holding a decoded COSE_Mac0 message.
+#[derive(Debug)]
+pub struct CoseMac0 {
+    pub mac_type: MacAlgorithm,
+    pub tag: Vec<u8>,
+    pub payload: Vec<u8>,
+    pub to_mac: Vec<u8>,
+    pub protected_header: Header,
+    pub unprotected_header: Header,
+}
+
+/// A COSE_recipient of a COSE_Mac message. With the direct algorithm, the recipient already has
+/// the MAC key, which is e.g. identified by the kid, and the ciphertext is empty.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CoseRecipient {
+    pub protected_header: Header,
+    pub unprotected_header: Header,
+    /// The encrypted key, or `None` if it is nil.
+    pub ciphertext: Option<Vec<u8>>,
+    pub recipients: Vec<CoseRecipient>,
+}
+
+/// The result of `decode_mac` holding a decoded COSE_Mac message.
+#[derive(Debug)]
+pub struct CoseMac {
+    pub mac_type: MacAlgorithm,
+    pub tag: Vec<u8>,
+    pub payload: Vec<u8>,
+    pub to_mac: Vec<u8>,
+    pub protected_header: Header,
+    pub unprotected_header: Header,
+    pub recipients: Vec<CoseRecipient>,
+}
+
+impl CoseSignature {
+    /// Verify the signature with the signing certificate.
+    pub fn verify_with(&self, verifier: &dyn Verifier) -> Result<(), CoseError> {
+        verifier.verify(self.signature_type, &self.signer_cert, &self.to_verify, &self.signature)
+    }
+
+    /// Validate the certificate chain of the signer and return the path from the signing
+    /// certificate to a trust anchor, see `ChainValidator::validate`.
+    pub fn validate_chain(
+        &self,
+        validator: &ChainValidator,
+        verifier: &dyn Verifier,
+    ) -> Result<Vec<Vec<u8>>, CoseError> {
+        validator.validate(&self.signer_cert, &self.certs, verifier)
+    }
+
+    /// Get the public key of the signing certificate and check that it fits the signature
+    /// algorithm, see `x509::signer_public_key`.
+    pub fn public_key(&self) -> Result<CoseKey, CoseError> {
+        signer_public_key(&self.signer_cert, self.signature_type)
+    }
+}
+
+impl CoseSign {
+    /// Verify all signatures of the message with their signing certificates.
+    pub fn verify_with(&self, verifier: &dyn Verifier) -> Result<(), CoseError> {
+        for signature in &self.signatures {
+            signature.verify_with(verifier)?;
+        }
+        Ok(())
+    }
+}
+
+impl CoseSign1 {
+    /// Verify the signature with the given key. COSE_Sign1 messages don't carry the key of the
+    /// signer, the caller has to find it, e.g. using the kid.
+    pub fn verify_with(&self, key: &[u8], verifier: &dyn Verifier) -> Result<(), CoseError> {
+        verifier.verify(self.signature_type, key, &self.to_verify, &self.signature)
+    }
+}
+
+/// Compute the tag over `to_mac` and compare it with the received one in constant time.
+fn verify_tag(
+    algorithm: MacAlgorithm,
+    to_mac: &[u8],
+    tag: &[u8],
+    mac: &dyn Mac,
+) -> Result<(), CoseError> {
+    let expected = truncate_tag(algorithm, mac.mac(algorithm, to_mac)?)?;
+    if !constant_time_eq(&expected, tag) {
+        return Err(CoseError::VerificationFailed);
+    }
+    Ok(())
+}
+
+impl CoseMac0 {
+    /// Verify the tag with the key of `mac`. COSE_Mac0 messages don't carry the key, the caller
+    /// has to find it, e.g. using the kid. The tags are compared in constant time.
+    pub fn verify_with(&self, mac: &dyn Mac) -> Result<(), CoseError> {
+        verify_tag(self.mac_type, &self.to_mac, &self.tag, mac)
+    }
+}
+
+impl CoseRecipient {
+    /// A recipient with the direct algorithm and the kid of the shared MAC key.
+    pub fn direct(kid: &[u8]) -> CoseRecipient {
+        let unprotected_header = Header {
+            alg: Some(Label::Int(COSE_ALG_DIRECT)),
+            kid: Some(kid.to_vec()),
+            ..Header::default()
+        };
+        CoseRecipient {
+            unprotected_header,
+            ciphertext: Some(Vec::new()),
+            ..CoseRecipient::default()
+        }
+    }
+}
+
+impl CoseMac {
+    /// Verify the tag with the key of `mac`. The key is not derived from the recipients: the
+    /// caller has to find it, e.g. using the kid of a direct recipient. The tags are compared in
+    /// constant time.
+    pub fn verify_with(&self, mac: &dyn Mac) -> Result<(), CoseError> {
+        verify_tag(self.mac_type, &self.to_mac, &self.tag, mac)
+    }
 }
 
 pub const COSE_TYPE_ES256: i64 = -7;
 pub const COSE_TYPE_ES384: i64 = -35;
 pub const COSE_TYPE_ES512: i64 = -36;
+pub const COSE_TYPE_ES256K: i64 = -47;
 pub const COSE_TYPE_PS256: i64 = -37;
+pub const COSE_TYPE_PS384: i64 = -38;
+pub const COSE_TYPE_PS512: i64 = -39;
+pub const COSE_TYPE_RS256: i64 = -257;
+pub const COSE_TYPE_RS384: i64 = -258;
+pub const COSE_TYPE_RS512: i64 = -259;
+pub const COSE_TYPE_EDDSA: i64 = -8;
+pub const COSE_TYPE_ED25519: i64 = -19;
+pub const COSE_TYPE_ED448: i64 = -53;
+pub const COSE_TYPE_HMAC256_64: i64 = 4;
+pub const COSE_TYPE_HMAC256_256: i64 = 5;
+pub const COSE_TYPE_HMAC384_384: i64 = 6;
+pub const COSE_TYPE_HMAC512_512: i64 = 7;
+/// The direct key agreement algorithm of recipients: the MAC key is shared with the recipient.
+pub const COSE_ALG_DIRECT: i64 = -6;
+
+pub use header::{COSE_HEADER_ALG, COSE_HEADER_KID};
 
-pub const COSE_HEADER_ALG: u64 = 1;
-pub const COSE_HEADER_KID: u64 = 4;
+/// A decoded, but not yet parsed, header map.
+type HeaderMap = BTreeMap<CborType, CborType>;
+
+/// A caller-provided check for a critical header parameter. It is called with the value of the
+/// header parameter and returns an error if the value can't be processed.
+type CriticalHeaderHandler = Box<dyn Fn(&CborType) -> Result<(), CoseError>>;
+
+/// Options for decoding COSE messages.
+#[derive(Default)]
+pub struct DecodeOptions {
+    allowed_algorithms: Option<Vec<SignatureAlgorithm>>,
+    certificate_profile: CertificateProfile,
+    critical_header_handlers: BTreeMap<Label, CriticalHeaderHandler>,
+    external_aad: Vec<u8>,
+    nil_external_aad: bool,
+}
+
+impl DecodeOptions {
+    pub fn new() -> DecodeOptions {
+        DecodeOptions::default()
+    }
+
+    /// Set the externally supplied data that is bound into the signatures. It is encoded as a
+    /// bstr and defaults to empty.
+    pub fn external_aad(mut self, external_aad: &[u8]) -> DecodeOptions {
+        self.external_aad = external_aad.to_vec();
+        self
+    }
+
+    /// Encode the external_aad of COSE_Signature Sig_structures as nil rather than as a bstr. This
+    /// is needed to verify signatures that were produced with `util::get_sig_struct_bytes`, like
+    /// the ones on Firefox add-ons. `decode_signature` always does this. COSE_Sign messages fail to
+    /// decode with `InvalidArgument` if external_aad is set as well.
+    pub fn nil_external_aad(mut self) -> DecodeOptions {
+        self.nil_external_aad = true;
+        self
+    }
+
+    /// Only accept signatures with one of the given algorithms. Signatures with any other algorithm
+    /// fail to decode with `UnexpectedHeaderValue`. By default all algorithms are accepted.
+    pub fn allowed_algorithms(mut self, algorithms: &[SignatureAlgorithm]) -> DecodeOptions {
+        self.allowed_algorithms = Some(algorithms.to_vec());
+        self
+    }
+
+    /// Set where the certificates of the signers of COSE_Sign messages are. Defaults to
+    /// `CertificateProfile::LegacyKid`.
+    pub fn certificate_profile(mut self, profile: CertificateProfile) -> DecodeOptions {
+        self.certificate_profile = profile;
+        self
+    }
+
+    /// Register a handler for a header parameter that this library doesn't process. Messages that
+    /// list the label as critical are only accepted if the handler accepts its value.
+    pub fn critical_header_handler<F>(mut self, label: Label, handler: F) -> DecodeOptions
+    where
+        F: Fn(&CborType) -> Result<(), CoseError> + 'static,
+    {
+        self.critical_header_handlers.insert(label, Box::new(handler));
+        self
+    }
+}
 
 macro_rules! unpack {
    ($to:tt, $var:ident) => (
@@ -33,7 +267,7 @@ macro_rules! unpack {
                 cbor_object
             }
             _ => return Err(CoseError::UnexpectedType),
-        };
+        }
     )
 }
 
@@ -47,13 +281,224 @@ fn get_map_value(
     }
 }
 
-/// Ensure that the referenced `CborType` is an empty map.
-fn ensure_empty_map(map: &CborType) -> Result<(), CoseError> {
-    let unpacked = unpack!(Map, map);
-    if !unpacked.is_empty() {
+/// Decode a serialized protected header (`empty_or_serialized_map`). A zero-length bstr is an
+/// empty map.
+fn decode_protected_header(protected_header: &CborType) -> Result<HeaderMap, CoseError> {
+    let protected_header_bytes = unpack!(Bytes, protected_header);
+    if protected_header_bytes.is_empty() {
+        return Ok(BTreeMap::new());
+    }
+    let protected_header = &match decode(protected_header_bytes) {
+        Err(_) => return Err(CoseError::DecodingFailure),
+        Ok(value) => value,
+    };
+    let protected_header = unpack!(Map, protected_header);
+    Ok(protected_header.clone())
+}
+
+/// Map the `alg` value of a header to a `SignatureAlgorithm` using the algorithm registry. The
+/// algorithm has to be one of the allowed ones.
+fn get_signature_algorithm(
+    header: &Header,
+    options: &DecodeOptions,
+) -> Result<SignatureAlgorithm, CoseError> {
+    let signature_algorithm = match header.alg {
+        Some(Label::Int(val)) => {
+            algorithm::from_id(val).ok_or(CoseError::UnexpectedHeaderValue)?
+        }
+        Some(Label::Text(_)) => return Err(CoseError::UnexpectedHeaderValue),
+        None => return Err(CoseError::MissingHeader),
+    };
+    if let Some(ref allowed_algorithms) = options.allowed_algorithms {
+        if !allowed_algorithms.contains(&signature_algorithm) {
+            return Err(CoseError::UnexpectedHeaderValue);
+        }
+    }
+    Ok(signature_algorithm)
+}
+
+/// Map the `alg` value of a header to a `MacAlgorithm` using the algorithm registry.
+fn get_mac_algorithm(header: &Header) -> Result<MacAlgorithm, CoseError> {
+    match header.alg {
+        Some(Label::Int(val)) => {
+            algorithm::mac_from_id(val).ok_or(CoseError::UnexpectedHeaderValue)
+        }
+        Some(Label::Text(_)) => Err(CoseError::UnexpectedHeaderValue),
+        None => Err(CoseError::MissingHeader),
+    }
+}
+
+/// Decode a COSE message that is either tagged with the given tag or an untagged array, and
+/// return the elements of the array.
+fn decode_message_array(bytes: &[u8], expected_tag: u64) -> Result<Vec<CborType>, CoseError> {
+    let message = match decode(bytes) {
+        Err(_) => return Err(CoseError::DecodingFailure),
+        Ok(value) => value,
+    };
+    match message {
+        CborType::Tag(tag, message) => {
+            if tag != expected_tag {
+                return Err(CoseError::UnexpectedTag);
+            }
+            match *message {
+                CborType::Array(values) => Ok(values),
+                _ => Err(CoseError::UnexpectedType),
+            }
+        }
+        CborType::Array(values) => Ok(values),
+        _ => Err(CoseError::UnexpectedType),
+    }
+}
+
+/// Decode the protected and unprotected header buckets of a COSE structure. A label must not
+/// appear in both buckets.
+fn decode_header_buckets(
+    protected_header: &CborType,
+    unprotected_header: &CborType,
+) -> Result<(HeaderMap, HeaderMap), CoseError> {
+    let protected_header = decode_protected_header(protected_header)?;
+    let unprotected_header = unpack!(Map, unprotected_header);
+    if protected_header.keys().any(|label| unprotected_header.contains_key(label)) {
         return Err(CoseError::MalformedInput);
     }
-    Ok(())
+    Ok((protected_header, unprotected_header.clone()))
+}
+
+/// The labels of the header parameters this library processes: the common header parameters of
+/// RFC 9052 except the counter signature, and the x5bag and x5chain of RFC 9360. The x5t and x5u
+/// are parsed into `Header`s but not checked, so they need a handler when they are critical.
+const KNOWN_HEADER_LABELS: [u64; 8] = [COSE_HEADER_ALG,
+                                       COSE_HEADER_CRIT,
+                                       COSE_HEADER_CONTENT_TYPE,
+                                       COSE_HEADER_KID,
+                                       COSE_HEADER_IV,
+                                       COSE_HEADER_PARTIAL_IV,
+                                       COSE_HEADER_X5BAG,
+                                       COSE_HEADER_X5CHAIN];
+
+/// Returns true if the label is one of `KNOWN_HEADER_LABELS`.
+fn is_known_header_label(label: &Label) -> bool {
+    match *label {
+        Label::Int(val) => val >= 0 && KNOWN_HEADER_LABELS.contains(&(val as u64)),
+        Label::Text(_) => false,
+    }
+}
+
+/// Parse the protected and unprotected header buckets into `Header`s.
+///
+/// Every label listed in the `crit` header parameter has to be present in the protected header
+/// and understood. The header parameters in `KNOWN_HEADER_LABELS` are understood by this
+/// library. Any other critical header parameter is passed to the handler registered in
+/// `options`.
+fn parse_header_buckets(
+    protected_map: &HeaderMap,
+    unprotected_map: &HeaderMap,
+    options: &DecodeOptions,
+) -> Result<(Header, Header), CoseError> {
+    parse_header_buckets_without(protected_map, unprotected_map, None, options)
+}
+
+/// Parse the header buckets like `parse_header_buckets`, leaving the given protected header
+/// parameter out of the protected `Header`. It still counts as present for `crit`.
+fn parse_header_buckets_without(
+    protected_map: &HeaderMap,
+    unprotected_map: &HeaderMap,
+    skipped_label: Option<&CborType>,
+    options: &DecodeOptions,
+) -> Result<(Header, Header), CoseError> {
+    let protected_header = match skipped_label {
+        Some(label) => {
+            let mut protected_map = protected_map.clone();
+            protected_map.remove(label);
+            Header::from_map(&protected_map)?
+        }
+        None => Header::from_map(protected_map)?,
+    };
+    let unprotected_header = Header::from_map(unprotected_map)?;
+    // The crit header parameter must be integrity protected.
+    if !unprotected_header.crit.is_empty() {
+        return Err(CoseError::MalformedInput);
+    }
+    for label in &protected_header.crit {
+        let value = match protected_map.get(&label.to_cbor()) {
+            Some(value) => value,
+            None => return Err(CoseError::MissingHeader),
+        };
+        if is_known_header_label(label) {
+            continue;
+        }
+        match options.critical_header_handlers.get(label) {
+            Some(handler) => handler(value)?,
+            None => return Err(CoseError::UnknownCriticalHeader),
+        };
+    }
+    Ok((protected_header, unprotected_header))
+}
+
+/// Decode the COSE_Sign body headers. With the legacy certificate profile, the protected header
+/// has to hold a kid with the (possibly empty) array of intermediate certificates. Any other
+/// header parameters are parsed into `Header`s.
+fn decode_body_headers(
+    protected_body_head: &CborType,
+    unprotected_body_head: &CborType,
+    options: &DecodeOptions,
+) -> Result<(Header, Header, Vec<Vec<u8>>), CoseError> {
+    let (protected_body_head_map, unprotected_body_head_map) =
+        decode_header_buckets(protected_body_head, unprotected_body_head)?;
+    if options.certificate_profile == CertificateProfile::X509 {
+        let (protected_header, unprotected_header) =
+            parse_header_buckets(&protected_body_head_map, &unprotected_body_head_map, options)?;
+        return Ok((protected_header, unprotected_header, Vec::new()));
+    }
+    // Read intermediate certificates from protected_body_head.
+    // Any tampering of the protected header during transport will be detected
+    // because it is input to the signature verification.
+    let intermediate_certs_array =
+        &get_map_value(&protected_body_head_map, &CborType::Integer(COSE_HEADER_KID))?;
+    let intermediate_certs = unpack!(Array, intermediate_certs_array);
+    let mut certs: Vec<Vec<u8>> = Vec::new();
+    for cert in intermediate_certs {
+        let cert = unpack!(Bytes, cert);
+        certs.push(cert.clone());
+    }
+    // The kid holding the certificates is not a bstr and can't be part of the `Header`.
+    let (protected_header, unprotected_header) = parse_header_buckets_without(
+        &protected_body_head_map,
+        &unprotected_body_head_map,
+        Some(&CborType::Integer(COSE_HEADER_KID)),
+        options,
+    )?;
+    Ok((protected_header, unprotected_header, certs))
+}
+
+/// Get the certificate of a signer and the other certificates for building its chain from the
+/// X.509 certificate header parameters. The first x5chain of the given headers is the chain of
+/// the signer, the certificates of all x5bags are added to the other certificates.
+fn get_x509_certs(headers: &[&Header]) -> Result<(Vec<u8>, Vec<Vec<u8>>), CoseError> {
+    let chain = match headers.iter().find(|header| !header.x5chain.is_empty()) {
+        Some(header) => &header.x5chain,
+        None => return Err(CoseError::MissingHeader),
+    };
+    let mut certs = chain[1..].to_vec();
+    for header in headers {
+        for cert in &header.x5bag {
+            if !certs.contains(cert) {
+                certs.push(cert.clone());
+            }
+        }
+    }
+    Ok((chain[0].clone(), certs))
+}
+
+/// Get the payload of a COSE_Sign or COSE_Sign1 message. The payload is either embedded in the
+/// message or detached and passed in by the caller, but never both.
+fn get_payload(payload: &CborType, detached_payload: Option<&[u8]>) -> Result<Vec<u8>, CoseError> {
+    match (payload, detached_payload) {
+        (CborType::Bytes(payload), None) => Ok(payload.clone()),
+        (CborType::Null, Some(payload)) => Ok(payload.to_vec()),
+        (CborType::Bytes(_), Some(_)) | (CborType::Null, None) => Err(CoseError::InvalidArgument),
+        _ => Err(CoseError::UnexpectedType),
+    }
 }
 
 // This syntax is a little unintuitive. Taken together, the two previous definitions essentially
@@ -67,9 +512,9 @@ fn ensure_empty_map(map: &CborType) -> Result<(), CoseError> {
 // ]
 //
 // (COSE_Sign is an array. The first element is an empty or serialized map (in our case, it is
-// never expected to be empty). The second element is a map (it is expected to be empty. The third
-// element is a bstr or nil (it is expected to be nil). The fourth element is an array of
-// COSE_Signature.)
+// never expected to be empty). The second element is a map that must not repeat any label of the
+// first. The third element is a bstr or nil (nil if the payload is detached). The fourth element is
+// an array of COSE_Signature.)
 //
 // COSE_Signature =  [
 //     Headers,
@@ -87,92 +532,76 @@ fn decode_signature_struct(
     cose_signature: &CborType,
     payload: &[u8],
     protected_body_head: &CborType,
+    body_headers: (&Header, &Header),
+    certs: &[Vec<u8>],
+    options: &DecodeOptions,
 ) -> Result<CoseSignature, CoseError> {
     let cose_signature = unpack!(Array, cose_signature);
     if cose_signature.len() != 3 {
         return Err(CoseError::MalformedInput);
     }
     let protected_signature_header_serialized = &cose_signature[0];
-    let protected_signature_header_bytes = unpack!(Bytes, protected_signature_header_serialized);
 
-    // Parse the protected signature header.
-    let protected_signature_header = &match decode(protected_signature_header_bytes) {
-        Err(_) => return Err(CoseError::DecodingFailure),
-        Ok(value) => value,
-    };
-    let protected_signature_header = unpack!(Map, protected_signature_header);
-    if protected_signature_header.len() != 2 {
-        return Err(CoseError::MalformedInput);
-    }
-    let signature_algorithm = get_map_value(
-        protected_signature_header,
-        &CborType::Integer(COSE_HEADER_ALG),
-    )?;
-    let signature_algorithm = match signature_algorithm {
-        CborType::SignedInteger(val) => {
-            match val {
-                COSE_TYPE_ES256 => SignatureAlgorithm::ES256,
-                COSE_TYPE_ES384 => SignatureAlgorithm::ES384,
-                COSE_TYPE_ES512 => SignatureAlgorithm::ES512,
-                COSE_TYPE_PS256 => SignatureAlgorithm::PS256,
-                _ => return Err(CoseError::UnexpectedHeaderValue),
+    // Parse the signature headers.
+    let (protected_signature_header, unprotected_signature_header) =
+        decode_header_buckets(protected_signature_header_serialized, &cose_signature[1])?;
+    let (protected_signature_header, unprotected_signature_header) =
+        parse_header_buckets(&protected_signature_header, &unprotected_signature_header, options)?;
+    let signature_algorithm = get_signature_algorithm(&protected_signature_header, options)?;
+
+    let (ee_cert, certs) = match options.certificate_profile {
+        CertificateProfile::LegacyKid => {
+            match protected_signature_header.kid {
+                Some(ref kid) => (kid.clone(), certs.to_vec()),
+                None => return Err(CoseError::MissingHeader),
             }
         }
-        _ => return Err(CoseError::UnexpectedType),
+        // The chain of the signer is in its own headers, or in the body headers.
+        CertificateProfile::X509 => {
+            get_x509_certs(&[&protected_signature_header,
+                             &unprotected_signature_header,
+                             body_headers.0,
+                             body_headers.1])?
+        }
     };
 
-    let ee_cert = &get_map_value(
-        protected_signature_header,
-        &CborType::Integer(COSE_HEADER_KID),
-    )?;
-    let ee_cert = unpack!(Bytes, ee_cert).clone();
-
-    // The unprotected header section is expected to be an empty map.
-    ensure_empty_map(&cose_signature[1])?;
-
     // Build signature structure to verify.
     let signature_bytes = &cose_signature[2];
     let signature_bytes = unpack!(Bytes, signature_bytes).clone();
-    let sig_structure_bytes = get_sig_struct_bytes(
-        protected_body_head.clone(),
-        protected_signature_header_serialized.clone(),
-        payload,
-    );
-
-    // Read intermediate certificates from protected_body_head.
-    // Any tampering of the protected header during transport will be detected
-    // because it is input to the signature verification.
-    // Note that a protected header has to be present and hold a kid with an
-    // empty list of intermediate certificates.
-    let protected_body_head_bytes = unpack!(Bytes, protected_body_head);
-    let protected_body_head_map = &match decode(protected_body_head_bytes) {
-        Ok(value) => value,
-        Err(_) => return Err(CoseError::DecodingFailure),
+    let sig_structure_bytes = if options.nil_external_aad {
+        get_sig_struct_bytes(
+            protected_body_head.clone(),
+            protected_signature_header_serialized.clone(),
+            payload,
+        )
+    } else {
+        let body_protected = unpack!(Bytes, protected_body_head);
+        let sign_protected = unpack!(Bytes, protected_signature_header_serialized);
+        SigStructure::new(SigContext::Signature, body_protected, payload)
+            .sign_protected(sign_protected)
+            .external_aad(&options.external_aad)
+            .to_bytes()?
     };
-    let protected_body_head_map = unpack!(Map, protected_body_head_map);
-    if protected_body_head_map.len() != 1 {
-        return Err(CoseError::MalformedInput);
-    }
-    let intermediate_certs_array =
-        &get_map_value(protected_body_head_map, &CborType::Integer(COSE_HEADER_KID))?;
-    let intermediate_certs = unpack!(Array, intermediate_certs_array);
-    let mut certs: Vec<Vec<u8>> = Vec::new();
-    for cert in intermediate_certs {
-        let cert = unpack!(Bytes, cert);
-        certs.push(cert.clone());
-    }
 
     Ok(CoseSignature {
         signature_type: signature_algorithm,
         signature: signature_bytes,
         signer_cert: ee_cert,
-        certs: certs,
+        certs,
         to_verify: sig_structure_bytes,
+        protected_header: protected_signature_header,
+        unprotected_header: unprotected_signature_header,
     })
 }
 
 /// Decode COSE signature bytes and return a vector of `CoseSignature`.
 ///
+/// The payload is detached and passed in by the caller. Use `decode_sign` for messages that carry
+/// their payload. The certificates are expected in the legacy layout and the Sig_structures are
+/// built with nil external_aad, see `DecodeOptions::certificate_profile` and
+/// `DecodeOptions::nil_external_aad`. This decodes the messages `encoder::CoseSignBuilder` builds
+/// with `nil_external_aad`.
+///
 ///```rust,ignore
 /// COSE_Sign = [
 ///     Headers,
@@ -186,6 +615,28 @@ fn decode_signature_struct(
 /// )
 ///```
 pub fn decode_signature(bytes: &[u8], payload: &[u8]) -> Result<Vec<CoseSignature>, CoseError> {
+    let options = DecodeOptions::new()
+        .certificate_profile(CertificateProfile::LegacyKid)
+        .nil_external_aad();
+    Ok(decode_sign_with_options(bytes, Some(payload), &options)?.signatures)
+}
+
+/// Decode COSE_Sign bytes and return a `CoseSign`.
+///
+/// If the message carries the payload it is used to build the Sig_structures, otherwise the
+/// detached payload has to be passed in. It is an error to pass in a detached payload for a
+/// message that carries one. The default `DecodeOptions` expect the legacy layout like
+/// `decode_signature`, but build the Sig_structures with an empty bstr external_aad.
+pub fn decode_sign(bytes: &[u8], detached_payload: Option<&[u8]>) -> Result<CoseSign, CoseError> {
+    decode_sign_with_options(bytes, detached_payload, &DecodeOptions::default())
+}
+
+/// Decode COSE_Sign bytes like `decode_sign`, using the given `DecodeOptions`.
+pub fn decode_sign_with_options(
+    bytes: &[u8],
+    detached_payload: Option<&[u8]>,
+    options: &DecodeOptions,
+) -> Result<CoseSign, CoseError> {
     // This has to be a COSE_Sign object, which is a tagged array.
     let tagged_cose_sign = match decode(bytes) {
         Err(_) => return Err(CoseError::DecodingFailure),
@@ -207,29 +658,252 @@ pub fn decode_signature(bytes: &[u8], payload: &[u8]) -> Result<Vec<CoseSignatur
         return Err(CoseError::MalformedInput);
     }
 
-    // The unprotected header section is expected to be an empty map.
-    ensure_empty_map(&cose_sign_array[1])?;
+    if options.nil_external_aad && !options.external_aad.is_empty() {
+        return Err(CoseError::InvalidArgument);
+    }
 
-    // The payload is expected to be Null (i.e. this is a detached signature).
-    match cose_sign_array[2] {
-        CborType::Null => {}
-        _ => return Err(CoseError::UnexpectedType),
-    };
+    let payload = get_payload(&cose_sign_array[2], detached_payload)?;
+
+    // cose_sign_array[0] and cose_sign_array[1] hold the body headers.
+    let (protected_header, unprotected_header, certs) =
+        decode_body_headers(&cose_sign_array[0], &cose_sign_array[1], options)?;
 
     let signatures = &cose_sign_array[3];
     let signatures = unpack!(Array, signatures);
 
     // Decode COSE_Signatures.
     // There has to be at least one signature to make this a valid COSE signature.
-    if signatures.len() < 1 {
+    if signatures.is_empty() {
         return Err(CoseError::MalformedInput);
     }
     let mut result = Vec::new();
     for cose_signature in signatures {
-        // cose_sign_array[0] holds the protected body header.
-        let signature = decode_signature_struct(cose_signature, payload, &cose_sign_array[0])?;
+        let signature = decode_signature_struct(
+            cose_signature,
+            &payload,
+            &cose_sign_array[0],
+            (&protected_header, &unprotected_header),
+            &certs,
+            options,
+        )?;
         result.push(signature);
     }
 
-    Ok(result)
+    Ok(CoseSign {
+        payload,
+        protected_header,
+        unprotected_header,
+        signatures: result,
+    })
+}
+
+/// Decode COSE_Sign1 bytes and return a `CoseSign1`.
+///
+/// The message may be tagged with the COSE_Sign1 tag (18) or be an untagged array. If the message
+/// carries the payload it is used to build the Sig_structure, otherwise the detached payload has
+/// to be passed in.
+///
+///```rust,ignore
+/// COSE_Sign1 = [
+///     Headers,
+///     payload : bstr / nil,
+///     signature : bstr
+/// ]
+///```
+pub fn decode_sign1(bytes: &[u8], detached_payload: Option<&[u8]>) -> Result<CoseSign1, CoseError> {
+    decode_sign1_with_options(bytes, detached_payload, &DecodeOptions::default())
+}
+
+/// Decode COSE_Sign1 bytes like `decode_sign1`, using the given `DecodeOptions`.
+pub fn decode_sign1_with_options(
+    bytes: &[u8],
+    detached_payload: Option<&[u8]>,
+    options: &DecodeOptions,
+) -> Result<CoseSign1, CoseError> {
+    let cose_sign1_array = decode_message_array(bytes, COSE_SIGN1_TAG)?;
+    if cose_sign1_array.len() != 4 {
+        return Err(CoseError::MalformedInput);
+    }
+
+    let (protected_header, unprotected_header) =
+        decode_header_buckets(&cose_sign1_array[0], &cose_sign1_array[1])?;
+    let (protected_header, unprotected_header) =
+        parse_header_buckets(&protected_header, &unprotected_header, options)?;
+    let signature_algorithm = get_signature_algorithm(&protected_header, options)?;
+
+    let payload = get_payload(&cose_sign1_array[2], detached_payload)?;
+
+    let signature_bytes = &cose_sign1_array[3];
+    let signature_bytes = unpack!(Bytes, signature_bytes).clone();
+    let body_protected = &cose_sign1_array[0];
+    let body_protected = unpack!(Bytes, body_protected);
+    let sig_structure_bytes = SigStructure::new(SigContext::Signature1, body_protected, &payload)
+        .external_aad(&options.external_aad)
+        .to_bytes()?;
+
+    Ok(CoseSign1 {
+        signature_type: signature_algorithm,
+        signature: signature_bytes,
+        payload,
+        to_verify: sig_structure_bytes,
+        protected_header,
+        unprotected_header,
+    })
+}
+
+/// Decode the headers, payload and tag that COSE_Mac0 and COSE_Mac messages share and build the
+/// MAC_structure. The tag has to have the length of the algorithm.
+fn decode_mac_body(
+    cose_mac_array: &[CborType],
+    context: MacContext,
+    detached_payload: Option<&[u8]>,
+    options: &DecodeOptions,
+) -> Result<CoseMac0, CoseError> {
+    let (protected_header, unprotected_header) =
+        decode_header_buckets(&cose_mac_array[0], &cose_mac_array[1])?;
+    let (protected_header, unprotected_header) =
+        parse_header_buckets(&protected_header, &unprotected_header, options)?;
+    let mac_algorithm = get_mac_algorithm(&protected_header)?;
+
+    let payload = get_payload(&cose_mac_array[2], detached_payload)?;
+
+    let tag = &cose_mac_array[3];
+    let tag = unpack!(Bytes, tag).clone();
+    if tag.len() != mac_algorithm.info().tag_length {
+        return Err(CoseError::MalformedInput);
+    }
+    let protected = &cose_mac_array[0];
+    let protected = unpack!(Bytes, protected);
+    let to_mac = MacStructure::new(context, protected, &payload)
+        .external_aad(&options.external_aad)
+        .to_bytes();
+    Ok(CoseMac0 {
+        mac_type: mac_algorithm,
+        tag,
+        payload,
+        to_mac,
+        protected_header,
+        unprotected_header,
+    })
+}
+
+/// Decode a COSE_recipient and the recipients nested in it.
+///
+///```rust,ignore
+/// COSE_recipient = [
+///     Headers,
+///     ciphertext : bstr / nil,
+///     ? recipients : [+ COSE_recipient]
+/// ]
+///```
+fn decode_recipient(
+    cose_recipient: &CborType,
+    options: &DecodeOptions,
+) -> Result<CoseRecipient, CoseError> {
+    let cose_recipient = unpack!(Array, cose_recipient);
+    if cose_recipient.len() != 3 && cose_recipient.len() != 4 {
+        return Err(CoseError::MalformedInput);
+    }
+    let (protected_header, unprotected_header) =
+        decode_header_buckets(&cose_recipient[0], &cose_recipient[1])?;
+    let (protected_header, unprotected_header) =
+        parse_header_buckets(&protected_header, &unprotected_header, options)?;
+    let ciphertext = match cose_recipient[2] {
+        CborType::Bytes(ref ciphertext) => Some(ciphertext.clone()),
+        CborType::Null => None,
+        _ => return Err(CoseError::UnexpectedType),
+    };
+    let recipients = match cose_recipient.get(3) {
+        Some(recipients) => decode_recipients(recipients, options)?,
+        None => Vec::new(),
+    };
+    Ok(CoseRecipient {
+        protected_header,
+        unprotected_header,
+        ciphertext,
+        recipients,
+    })
+}
+
+/// Decode a non-empty array of COSE_recipients.
+fn decode_recipients(
+    recipients: &CborType,
+    options: &DecodeOptions,
+) -> Result<Vec<CoseRecipient>, CoseError> {
+    let recipients = unpack!(Array, recipients);
+    if recipients.is_empty() {
+        return Err(CoseError::MalformedInput);
+    }
+    recipients.iter().map(|recipient| decode_recipient(recipient, options)).collect()
+}
+
+/// Decode COSE_Mac0 bytes and return a `CoseMac0`.
+///
+/// The message may be tagged with the COSE_Mac0 tag (17) or be an untagged array. If the message
+/// carries the payload it is used to build the MAC_structure, otherwise the detached payload has
+/// to be passed in. The tag is not verified, see `CoseMac0::verify_with`.
+///
+///```rust,ignore
+/// COSE_Mac0 = [
+///     Headers,
+///     payload : bstr / nil,
+///     tag : bstr
+/// ]
+///```
+pub fn decode_mac0(bytes: &[u8], detached_payload: Option<&[u8]>) -> Result<CoseMac0, CoseError> {
+    decode_mac0_with_options(bytes, detached_payload, &DecodeOptions::default())
+}
+
+/// Decode COSE_Mac0 bytes like `decode_mac0`, using the given `DecodeOptions`.
+pub fn decode_mac0_with_options(
+    bytes: &[u8],
+    detached_payload: Option<&[u8]>,
+    options: &DecodeOptions,
+) -> Result<CoseMac0, CoseError> {
+    let cose_mac0_array = decode_message_array(bytes, COSE_MAC0_TAG)?;
+    if cose_mac0_array.len() != 4 {
+        return Err(CoseError::MalformedInput);
+    }
+    decode_mac_body(&cose_mac0_array, MacContext::Mac0, detached_payload, options)
+}
+
+/// Decode COSE_Mac bytes and return a `CoseMac`.
+///
+/// The message may be tagged with the COSE_Mac tag (97) or be an untagged array, and has to have
+/// at least one recipient. The payload is handled like in `decode_mac0`. The tag is not verified,
+/// see `CoseMac::verify_with`.
+///
+///```rust,ignore
+/// COSE_Mac = [
+///     Headers,
+///     payload : bstr / nil,
+///     tag : bstr,
+///     recipients : [+ COSE_recipient]
+/// ]
+///```
+pub fn decode_mac(bytes: &[u8], detached_payload: Option<&[u8]>) -> Result<CoseMac, CoseError> {
+    decode_mac_with_options(bytes, detached_payload, &DecodeOptions::default())
+}
+
+/// Decode COSE_Mac bytes like `decode_mac`, using the given `DecodeOptions`.
+pub fn decode_mac_with_options(
+    bytes: &[u8],
+    detached_payload: Option<&[u8]>,
+    options: &DecodeOptions,
+) -> Result<CoseMac, CoseError> {
+    let cose_mac_array = decode_message_array(bytes, COSE_MAC_TAG)?;
+    if cose_mac_array.len() != 5 {
+        return Err(CoseError::MalformedInput);
+    }
+    let body = decode_mac_body(&cose_mac_array[..4], MacContext::Mac, detached_payload, options)?;
+    let recipients = decode_recipients(&cose_mac_array[4], options)?;
+    Ok(CoseMac {
+        mac_type: body.mac_type,
+        tag: body.tag,
+        payload: body.payload,
+        to_mac: body.to_mac,
+        protected_header: body.protected_header,
+        unprotected_header: body.unprotected_header,
+        recipients,
+    })
 }
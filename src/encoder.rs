@@ -0,0 +1,668 @@
+//! Build and encode COSE signatures, COSE_Sign1 messages, and COSE_Mac0 and COSE_Mac messages.
+//!
+//! Signing itself is performed by the caller. Every signer is added either as a `Signer` or with a
+//! callback that gets the bytes of the Sig_structure and returns the signature over them. MACs
+//! are computed the same way, with a `Mac` or a callback that gets the bytes of the
+//! MAC_structure.
+
+use cbor::CborType;
+use {CoseError, Mac, MacAlgorithm, SignatureAlgorithm, Signer};
+use decoder::{COSE_MAC_TAG, COSE_MAC0_TAG, COSE_SIGN_TAG, COSE_SIGN1_TAG, CoseRecipient};
+use header::{COSE_HEADER_ALG, COSE_HEADER_KID, CertificateProfile, Header};
+use util::{MacContext, MacStructure, SigContext, SigStructure, get_sig_struct_bytes,
+           truncate_tag};
+use std::collections::BTreeMap;
+
+/// An encoded header map, before serialization.
+type HeaderMap = BTreeMap<CborType, CborType>;
+
+/// A caller-provided signing function. It is called with the bytes of the Sig_structure and
+/// returns the signature.
+type SignCallback<'a> = Box<dyn Fn(&[u8]) -> Result<Vec<u8>, CoseError> + 'a>;
+
+/// A caller-provided HMAC function. It is called with the bytes of the MAC_structure and returns
+/// the full HMAC output.
+type MacCallback<'a> = Box<dyn Fn(&[u8]) -> Result<Vec<u8>, CoseError> + 'a>;
+
+struct CoseSigner<'a> {
+    algorithm: SignatureAlgorithm,
+    signer_cert: Vec<u8>,
+    sign: SignCallback<'a>,
+}
+
+/// Converts a `SignatureAlgorithm` to its corresponding `CborType`, the identifier from the
+/// algorithm registry.
+fn signature_algorithm_to_cbor(signature_algorithm: SignatureAlgorithm) -> CborType {
+    CborType::SignedInteger(signature_algorithm.id())
+}
+
+/// Serialize a protected header map (`empty_or_serialized_map`). An empty map is encoded as a
+/// zero-length bstr.
+fn serialize_protected_header(header_map: HeaderMap) -> Vec<u8> {
+    if header_map.is_empty() {
+        return Vec::new();
+    }
+    CborType::Map(header_map).serialize()
+}
+
+/// Encode the header buckets of a COSE structure. The crit header parameter has to be in the
+/// protected bucket and a label must not appear in both buckets.
+fn encode_header_buckets(
+    protected_header: &Header,
+    unprotected_header: &Header,
+) -> Result<(HeaderMap, HeaderMap), CoseError> {
+    if !unprotected_header.crit.is_empty() {
+        return Err(CoseError::InvalidArgument);
+    }
+    let protected_header = protected_header.to_map();
+    let unprotected_header = unprotected_header.to_map();
+    if protected_header.keys().any(|label| unprotected_header.contains_key(label)) {
+        return Err(CoseError::InvalidArgument);
+    }
+    Ok((protected_header, unprotected_header))
+}
+
+/// Builder for tagged COSE_Sign messages.
+///
+/// By default the message has the legacy layout `decoder::decode_signature` expects: the
+/// protected body header holds a kid with the (possibly empty) array of intermediate certificates
+/// and the protected header of every COSE_Signature holds the algorithm and a kid with the signing
+/// certificate. With `CertificateProfile::X509`, the protected header of every COSE_Signature
+/// holds the algorithm and an x5chain with the signing certificate followed by the intermediate
+/// certificates. The external_aad of the Sig_structures is a bstr, `decode_signature` needs
+/// `nil_external_aad`.
+///
+///```rust,ignore
+/// use cose::SignatureAlgorithm;
+/// use cose::decoder::decode_signature;
+/// use cose::encoder::CoseSignBuilder;
+///
+/// let cose_sign = CoseSignBuilder::new(&payload)
+///     .detached()
+///     .nil_external_aad()
+///     .certs(&[&intermediate_cert])
+///     .signer(SignatureAlgorithm::ES256, &ee_cert, |to_sign| sign(&pkcs8, to_sign))
+///     .build()?;
+/// let cose_signatures = decode_signature(&cose_sign, &payload)?;
+///```
+pub struct CoseSignBuilder<'a> {
+    payload: Vec<u8>,
+    detached: bool,
+    certs: Vec<Vec<u8>>,
+    certificate_profile: CertificateProfile,
+    protected_header: Header,
+    unprotected_header: Header,
+    external_aad: Vec<u8>,
+    nil_external_aad: bool,
+    signers: Vec<CoseSigner<'a>>,
+}
+
+impl<'a> CoseSignBuilder<'a> {
+    /// Start a COSE_Sign message that signs the given payload.
+    pub fn new(payload: &[u8]) -> CoseSignBuilder<'a> {
+        CoseSignBuilder {
+            payload: payload.to_vec(),
+            detached: false,
+            certs: Vec::new(),
+            certificate_profile: CertificateProfile::default(),
+            protected_header: Header::default(),
+            unprotected_header: Header::default(),
+            external_aad: Vec::new(),
+            nil_external_aad: false,
+            signers: Vec::new(),
+        }
+    }
+
+    /// Don't carry the payload in the message. It is signed, but has to be transported
+    /// separately.
+    pub fn detached(mut self) -> CoseSignBuilder<'a> {
+        self.detached = true;
+        self
+    }
+
+    /// Set the intermediate certificates. With the legacy layout, they are put into the protected
+    /// body header. With `CertificateProfile::X509`, they follow the signing certificate in the
+    /// x5chain of every signature and have to be in chain order.
+    pub fn certs(mut self, certs: &[&[u8]]) -> CoseSignBuilder<'a> {
+        self.certs = certs.iter().map(|cert| cert.to_vec()).collect();
+        self
+    }
+
+    /// Set where the certificates are put. Defaults to `CertificateProfile::LegacyKid`.
+    pub fn certificate_profile(mut self, profile: CertificateProfile) -> CoseSignBuilder<'a> {
+        self.certificate_profile = profile;
+        self
+    }
+
+    /// Set additional protected body header parameters. With the legacy layout, the kid is used
+    /// for the intermediate certificates and must not be set.
+    pub fn protected_header(mut self, header: Header) -> CoseSignBuilder<'a> {
+        self.protected_header = header;
+        self
+    }
+
+    /// Set the unprotected body header parameters.
+    pub fn unprotected_header(mut self, header: Header) -> CoseSignBuilder<'a> {
+        self.unprotected_header = header;
+        self
+    }
+
+    /// Set the externally supplied data that is bound into the signatures. Defaults to empty.
+    /// It is encoded as a bstr, so this can't be combined with `nil_external_aad`.
+    pub fn external_aad(mut self, external_aad: &[u8]) -> CoseSignBuilder<'a> {
+        self.external_aad = external_aad.to_vec();
+        self
+    }
+
+    /// Encode the external_aad of the Sig_structures as nil rather than as a bstr. This is needed
+    /// for signatures that are verified with `decoder::decode_signature`.
+    pub fn nil_external_aad(mut self) -> CoseSignBuilder<'a> {
+        self.nil_external_aad = true;
+        self
+    }
+
+    /// Add a signer with its algorithm, DER encoded certificate, and signing function. The
+    /// signatures are in the order the signers are added.
+    pub fn signer<F>(
+        mut self,
+        algorithm: SignatureAlgorithm,
+        signer_cert: &[u8],
+        sign: F,
+    ) -> CoseSignBuilder<'a>
+    where
+        F: Fn(&[u8]) -> Result<Vec<u8>, CoseError> + 'a,
+    {
+        self.signers.push(CoseSigner {
+            algorithm,
+            signer_cert: signer_cert.to_vec(),
+            sign: Box::new(sign),
+        });
+        self
+    }
+
+    /// Add a signer with its algorithm and DER encoded certificate that signs with `signer`.
+    pub fn sign_with(
+        self,
+        algorithm: SignatureAlgorithm,
+        signer_cert: &[u8],
+        signer: &'a dyn Signer,
+    ) -> CoseSignBuilder<'a> {
+        self.signer(algorithm, signer_cert, move |to_sign| signer.sign(algorithm, to_sign))
+    }
+
+    /// Sign the payload with all signers and encode the COSE_Sign message.
+    ///
+    /// Returns `InvalidArgument` if there are no signers, if the protected body header has a kid
+    /// with the legacy layout, if external_aad is set with a nil external_aad, or if a label is
+    /// in both body header buckets. Errors of the signing functions are passed through.
+    pub fn build(&self) -> Result<Vec<u8>, CoseError> {
+        let legacy = self.certificate_profile == CertificateProfile::LegacyKid;
+        let body_kid = self.protected_header.kid.is_some() || self.unprotected_header.kid.is_some();
+        if self.signers.is_empty() || (legacy && body_kid) ||
+            (self.nil_external_aad && !self.external_aad.is_empty())
+        {
+            return Err(CoseError::InvalidArgument);
+        }
+        let (mut protected_body_head, unprotected_body_head) =
+            encode_header_buckets(&self.protected_header, &self.unprotected_header)?;
+        if legacy {
+            let kid = CborType::Integer(COSE_HEADER_KID);
+            let certs = self.certs.iter().map(|cert| CborType::Bytes(cert.clone())).collect();
+            protected_body_head.insert(kid, CborType::Array(certs));
+        }
+        let protected_body_head = serialize_protected_header(protected_body_head);
+
+        let mut signatures: Vec<CborType> = Vec::new();
+        for signer in &self.signers {
+            let mut protected_signature_header = Header::default();
+            if legacy {
+                protected_signature_header.kid = Some(signer.signer_cert.clone());
+            } else {
+                protected_signature_header.x5chain.push(signer.signer_cert.clone());
+                protected_signature_header.x5chain.extend_from_slice(&self.certs);
+            }
+            let mut protected_signature_head = protected_signature_header.to_map();
+            protected_signature_head.insert(
+                CborType::Integer(COSE_HEADER_ALG),
+                signature_algorithm_to_cbor(signer.algorithm),
+            );
+            let protected_signature_head = serialize_protected_header(protected_signature_head);
+
+            // Build the signature structure containing the protected headers and the payload to
+            // generate the bytes that are actually signed.
+            let to_sign = if self.nil_external_aad {
+                get_sig_struct_bytes(
+                    CborType::Bytes(protected_body_head.clone()),
+                    CborType::Bytes(protected_signature_head.clone()),
+                    &self.payload,
+                )
+            } else {
+                SigStructure::new(SigContext::Signature, &protected_body_head, &self.payload)
+                    .sign_protected(&protected_signature_head)
+                    .external_aad(&self.external_aad)
+                    .to_bytes()?
+            };
+            let signature = (signer.sign)(&to_sign)?;
+
+            signatures.push(CborType::Array(vec![CborType::Bytes(protected_signature_head),
+                                                 CborType::Map(BTreeMap::new()),
+                                                 CborType::Bytes(signature)]));
+        }
+
+        let payload = if self.detached {
+            CborType::Null
+        } else {
+            CborType::Bytes(self.payload.clone())
+        };
+        let cose_sign = vec![CborType::Bytes(protected_body_head),
+                             CborType::Map(unprotected_body_head),
+                             payload,
+                             CborType::Array(signatures)];
+        Ok(CborType::Tag(COSE_SIGN_TAG, Box::new(CborType::Array(cose_sign))).serialize())
+    }
+}
+
+/// Builder for COSE_Sign1 messages.
+///
+/// The algorithm of the signer is put into the protected header. Any other header parameters,
+/// e.g. a kid identifying the key, are set by the caller. The message is tagged with the
+/// COSE_Sign1 tag (18) unless `untagged` is used.
+///
+///```rust,ignore
+/// use cose::encoder::CoseSign1Builder;
+///
+/// let cose_sign1 = CoseSign1Builder::new(&payload)
+///     .unprotected_header(header_with_kid)
+///     .signer(SignatureAlgorithm::ES256, |to_sign| sign(&pkcs8, to_sign))
+///     .build()?;
+///```
+pub struct CoseSign1Builder<'a> {
+    payload: Vec<u8>,
+    detached: bool,
+    tagged: bool,
+    protected_header: Header,
+    unprotected_header: Header,
+    external_aad: Vec<u8>,
+    signer: Option<(SignatureAlgorithm, SignCallback<'a>)>,
+}
+
+impl<'a> CoseSign1Builder<'a> {
+    /// Start a COSE_Sign1 message that signs the given payload.
+    pub fn new(payload: &[u8]) -> CoseSign1Builder<'a> {
+        CoseSign1Builder {
+            payload: payload.to_vec(),
+            detached: false,
+            tagged: true,
+            protected_header: Header::default(),
+            unprotected_header: Header::default(),
+            external_aad: Vec::new(),
+            signer: None,
+        }
+    }
+
+    /// Don't carry the payload in the message. It is signed, but has to be transported
+    /// separately.
+    pub fn detached(mut self) -> CoseSign1Builder<'a> {
+        self.detached = true;
+        self
+    }
+
+    /// Emit the bare COSE_Sign1 array without the COSE_Sign1 tag, for protocols that identify
+    /// the message type themselves.
+    pub fn untagged(mut self) -> CoseSign1Builder<'a> {
+        self.tagged = false;
+        self
+    }
+
+    /// Set additional protected header parameters. The alg is set from the signer and must not
+    /// be set.
+    pub fn protected_header(mut self, header: Header) -> CoseSign1Builder<'a> {
+        self.protected_header = header;
+        self
+    }
+
+    /// Set the unprotected header parameters.
+    pub fn unprotected_header(mut self, header: Header) -> CoseSign1Builder<'a> {
+        self.unprotected_header = header;
+        self
+    }
+
+    /// Set the externally supplied data that is bound into the signature. Defaults to empty.
+    pub fn external_aad(mut self, external_aad: &[u8]) -> CoseSign1Builder<'a> {
+        self.external_aad = external_aad.to_vec();
+        self
+    }
+
+    /// Set the algorithm and signing function of the signer.
+    pub fn signer<F>(mut self, algorithm: SignatureAlgorithm, sign: F) -> CoseSign1Builder<'a>
+    where
+        F: Fn(&[u8]) -> Result<Vec<u8>, CoseError> + 'a,
+    {
+        self.signer = Some((algorithm, Box::new(sign)));
+        self
+    }
+
+    /// Set the algorithm of the signer and sign with `signer`.
+    pub fn sign_with(
+        self,
+        algorithm: SignatureAlgorithm,
+        signer: &'a dyn Signer,
+    ) -> CoseSign1Builder<'a> {
+        self.signer(algorithm, move |to_sign| signer.sign(algorithm, to_sign))
+    }
+
+    /// Sign the payload and encode the COSE_Sign1 message.
+    ///
+    /// Returns `InvalidArgument` if there is no signer, if the alg header parameter is set by the
+    /// caller, or if a label is in both header buckets. Errors of the signing function are passed
+    /// through.
+    pub fn build(&self) -> Result<Vec<u8>, CoseError> {
+        let (algorithm, sign) = match self.signer {
+            Some((algorithm, ref sign)) => (algorithm, sign),
+            None => return Err(CoseError::InvalidArgument),
+        };
+        if self.protected_header.alg.is_some() || self.unprotected_header.alg.is_some() {
+            return Err(CoseError::InvalidArgument);
+        }
+        let (mut protected_head, unprotected_head) =
+            encode_header_buckets(&self.protected_header, &self.unprotected_header)?;
+        protected_head.insert(
+            CborType::Integer(COSE_HEADER_ALG),
+            signature_algorithm_to_cbor(algorithm),
+        );
+        let protected_head = serialize_protected_header(protected_head);
+
+        let to_sign = SigStructure::new(SigContext::Signature1, &protected_head, &self.payload)
+            .external_aad(&self.external_aad)
+            .to_bytes()?;
+        let signature = sign(&to_sign)?;
+
+        let payload = if self.detached {
+            CborType::Null
+        } else {
+            CborType::Bytes(self.payload.clone())
+        };
+        let cose_sign1 = CborType::Array(vec![CborType::Bytes(protected_head),
+                                              CborType::Map(unprotected_head),
+                                              payload,
+                                              CborType::Bytes(signature)]);
+        if self.tagged {
+            Ok(CborType::Tag(COSE_SIGN1_TAG, Box::new(cose_sign1)).serialize())
+        } else {
+            Ok(cose_sign1.serialize())
+        }
+    }
+}
+
+/// The parts of a COSE_Mac0 or COSE_Mac message that both builders set.
+struct MacBody<'a> {
+    payload: Vec<u8>,
+    detached: bool,
+    tagged: bool,
+    protected_header: Header,
+    unprotected_header: Header,
+    external_aad: Vec<u8>,
+    mac: Option<(MacAlgorithm, MacCallback<'a>)>,
+}
+
+impl<'a> MacBody<'a> {
+    fn new(payload: &[u8]) -> MacBody<'a> {
+        MacBody {
+            payload: payload.to_vec(),
+            detached: false,
+            tagged: true,
+            protected_header: Header::default(),
+            unprotected_header: Header::default(),
+            external_aad: Vec::new(),
+            mac: None,
+        }
+    }
+
+    /// Compute the tag and encode the headers, payload and tag of the message.
+    fn encode(&self, context: MacContext) -> Result<Vec<CborType>, CoseError> {
+        let (algorithm, mac) = match self.mac {
+            Some((algorithm, ref mac)) => (algorithm, mac),
+            None => return Err(CoseError::InvalidArgument),
+        };
+        if self.protected_header.alg.is_some() || self.unprotected_header.alg.is_some() {
+            return Err(CoseError::InvalidArgument);
+        }
+        let (mut protected_head, unprotected_head) =
+            encode_header_buckets(&self.protected_header, &self.unprotected_header)?;
+        protected_head.insert(
+            CborType::Integer(COSE_HEADER_ALG),
+            CborType::Integer(algorithm.id() as u64),
+        );
+        let protected_head = serialize_protected_header(protected_head);
+
+        let to_mac = MacStructure::new(context, &protected_head, &self.payload)
+            .external_aad(&self.external_aad)
+            .to_bytes();
+        let tag = truncate_tag(algorithm, mac(&to_mac)?)?;
+
+        let payload = if self.detached {
+            CborType::Null
+        } else {
+            CborType::Bytes(self.payload.clone())
+        };
+        Ok(vec![CborType::Bytes(protected_head),
+                CborType::Map(unprotected_head),
+                payload,
+                CborType::Bytes(tag)])
+    }
+
+    /// Serialize the message, with the given COSE tag unless it is untagged.
+    fn serialize(&self, tag: u64, message: Vec<CborType>) -> Vec<u8> {
+        if self.tagged {
+            CborType::Tag(tag, Box::new(CborType::Array(message))).serialize()
+        } else {
+            CborType::Array(message).serialize()
+        }
+    }
+}
+
+/// Builder for COSE_Mac0 messages.
+///
+/// The MAC algorithm is put into the protected header. Any other header parameters, e.g. a kid
+/// identifying the key, are set by the caller. The message is tagged with the COSE_Mac0 tag (17)
+/// unless `untagged` is used.
+///
+///```rust,ignore
+/// use cose::encoder::CoseMac0Builder;
+///
+/// let cose_mac0 = CoseMac0Builder::new(&payload)
+///     .unprotected_header(header_with_kid)
+///     .mac_with(MacAlgorithm::HMAC256_256, &mac)
+///     .build()?;
+///```
+pub struct CoseMac0Builder<'a> {
+    body: MacBody<'a>,
+}
+
+impl<'a> CoseMac0Builder<'a> {
+    /// Start a COSE_Mac0 message over the given payload.
+    pub fn new(payload: &[u8]) -> CoseMac0Builder<'a> {
+        CoseMac0Builder { body: MacBody::new(payload) }
+    }
+
+    /// Don't carry the payload in the message. It is MACed, but has to be transported
+    /// separately.
+    pub fn detached(mut self) -> CoseMac0Builder<'a> {
+        self.body.detached = true;
+        self
+    }
+
+    /// Emit the bare COSE_Mac0 array without the COSE_Mac0 tag, for protocols that identify the
+    /// message type themselves.
+    pub fn untagged(mut self) -> CoseMac0Builder<'a> {
+        self.body.tagged = false;
+        self
+    }
+
+    /// Set additional protected header parameters. The alg is set from the MAC algorithm and must
+    /// not be set.
+    pub fn protected_header(mut self, header: Header) -> CoseMac0Builder<'a> {
+        self.body.protected_header = header;
+        self
+    }
+
+    /// Set the unprotected header parameters.
+    pub fn unprotected_header(mut self, header: Header) -> CoseMac0Builder<'a> {
+        self.body.unprotected_header = header;
+        self
+    }
+
+    /// Set the externally supplied data that is bound into the tag. Defaults to empty.
+    pub fn external_aad(mut self, external_aad: &[u8]) -> CoseMac0Builder<'a> {
+        self.body.external_aad = external_aad.to_vec();
+        self
+    }
+
+    /// Set the MAC algorithm and the HMAC function. Its output is truncated to the tag length of
+    /// the algorithm.
+    pub fn mac<F>(mut self, algorithm: MacAlgorithm, mac: F) -> CoseMac0Builder<'a>
+    where
+        F: Fn(&[u8]) -> Result<Vec<u8>, CoseError> + 'a,
+    {
+        self.body.mac = Some((algorithm, Box::new(mac)));
+        self
+    }
+
+    /// Set the MAC algorithm and compute the tag with `mac`.
+    pub fn mac_with(self, algorithm: MacAlgorithm, mac: &'a dyn Mac) -> CoseMac0Builder<'a> {
+        self.mac(algorithm, move |to_mac| mac.mac(algorithm, to_mac))
+    }
+
+    /// Compute the tag over the payload and encode the COSE_Mac0 message.
+    ///
+    /// Returns `InvalidArgument` if there is no MAC function, if the alg header parameter is set
+    /// by the caller, or if a label is in both header buckets. Errors of the MAC function are
+    /// passed through.
+    pub fn build(&self) -> Result<Vec<u8>, CoseError> {
+        let cose_mac0 = self.body.encode(MacContext::Mac0)?;
+        Ok(self.body.serialize(COSE_MAC0_TAG, cose_mac0))
+    }
+}
+
+/// Encode a COSE_recipient and the recipients nested in it.
+fn encode_recipient(recipient: &CoseRecipient) -> Result<CborType, CoseError> {
+    let (protected_head, unprotected_head) =
+        encode_header_buckets(&recipient.protected_header, &recipient.unprotected_header)?;
+    let ciphertext = match recipient.ciphertext {
+        Some(ref ciphertext) => CborType::Bytes(ciphertext.clone()),
+        None => CborType::Null,
+    };
+    let mut cose_recipient = vec![CborType::Bytes(serialize_protected_header(protected_head)),
+                                  CborType::Map(unprotected_head),
+                                  ciphertext];
+    if !recipient.recipients.is_empty() {
+        let recipients = recipient.recipients
+            .iter()
+            .map(encode_recipient)
+            .collect::<Result<Vec<CborType>, CoseError>>()?;
+        cose_recipient.push(CborType::Array(recipients));
+    }
+    Ok(CborType::Array(cose_recipient))
+}
+
+/// Builder for COSE_Mac messages.
+///
+/// Like `CoseMac0Builder`, but the message carries recipients that tell how to get the MAC key,
+/// e.g. `CoseRecipient::direct` with the kid of a shared key. The recipients are encoded as given,
+/// the key is not derived from them. The message is tagged with the COSE_Mac tag (97) unless
+/// `untagged` is used.
+///
+///```rust,ignore
+/// use cose::encoder::CoseMacBuilder;
+///
+/// let cose_mac = CoseMacBuilder::new(&payload)
+///     .recipient(CoseRecipient::direct(b"our-secret"))
+///     .mac_with(MacAlgorithm::HMAC256_256, &mac)
+///     .build()?;
+///```
+pub struct CoseMacBuilder<'a> {
+    body: MacBody<'a>,
+    recipients: Vec<CoseRecipient>,
+}
+
+impl<'a> CoseMacBuilder<'a> {
+    /// Start a COSE_Mac message over the given payload.
+    pub fn new(payload: &[u8]) -> CoseMacBuilder<'a> {
+        CoseMacBuilder {
+            body: MacBody::new(payload),
+            recipients: Vec::new(),
+        }
+    }
+
+    /// Don't carry the payload in the message. It is MACed, but has to be transported
+    /// separately.
+    pub fn detached(mut self) -> CoseMacBuilder<'a> {
+        self.body.detached = true;
+        self
+    }
+
+    /// Emit the bare COSE_Mac array without the COSE_Mac tag, for protocols that identify the
+    /// message type themselves.
+    pub fn untagged(mut self) -> CoseMacBuilder<'a> {
+        self.body.tagged = false;
+        self
+    }
+
+    /// Set additional protected header parameters. The alg is set from the MAC algorithm and must
+    /// not be set.
+    pub fn protected_header(mut self, header: Header) -> CoseMacBuilder<'a> {
+        self.body.protected_header = header;
+        self
+    }
+
+    /// Set the unprotected header parameters.
+    pub fn unprotected_header(mut self, header: Header) -> CoseMacBuilder<'a> {
+        self.body.unprotected_header = header;
+        self
+    }
+
+    /// Set the externally supplied data that is bound into the tag. Defaults to empty.
+    pub fn external_aad(mut self, external_aad: &[u8]) -> CoseMacBuilder<'a> {
+        self.body.external_aad = external_aad.to_vec();
+        self
+    }
+
+    /// Add a recipient. The recipients are in the order they are added.
+    pub fn recipient(mut self, recipient: CoseRecipient) -> CoseMacBuilder<'a> {
+        self.recipients.push(recipient);
+        self
+    }
+
+    /// Set the MAC algorithm and the HMAC function. Its output is truncated to the tag length of
+    /// the algorithm.
+    pub fn mac<F>(mut self, algorithm: MacAlgorithm, mac: F) -> CoseMacBuilder<'a>
+    where
+        F: Fn(&[u8]) -> Result<Vec<u8>, CoseError> + 'a,
+    {
+        self.body.mac = Some((algorithm, Box::new(mac)));
+        self
+    }
+
+    /// Set the MAC algorithm and compute the tag with `mac`.
+    pub fn mac_with(self, algorithm: MacAlgorithm, mac: &'a dyn Mac) -> CoseMacBuilder<'a> {
+        self.mac(algorithm, move |to_mac| mac.mac(algorithm, to_mac))
+    }
+
+    /// Compute the tag over the payload and encode the COSE_Mac message.
+    ///
+    /// Returns `InvalidArgument` if there are no recipients or no MAC function, if the alg header
+    /// parameter is set by the caller, or if a label is in both header buckets of the message or
+    /// of a recipient. Errors of the MAC function are passed through.
+    pub fn build(&self) -> Result<Vec<u8>, CoseError> {
+        if self.recipients.is_empty() {
+            return Err(CoseError::InvalidArgument);
+        }
+        let recipients = self.recipients
+            .iter()
+            .map(encode_recipient)
+            .collect::<Result<Vec<CborType>, CoseError>>()?;
+        let mut cose_mac = self.body.encode(MacContext::Mac)?;
+        cose_mac.push(CborType::Array(recipients));
+        Ok(self.body.serialize(COSE_MAC_TAG, cose_mac))
+    }
+}
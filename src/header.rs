@@ -0,0 +1,288 @@
+//! Parse and encode COSE header maps.
+//!
+//! See [RFC 9052 section 3](https://tools.ietf.org/html/rfc9052#section-3) for the header
+//! parameters defined for all COSE messages and
+//! [RFC 9360](https://tools.ietf.org/html/rfc9360) for the X.509 certificate header parameters.
+
+use cbor::CborType;
+use CoseError;
+use std::collections::BTreeMap;
+
+pub const COSE_HEADER_ALG: u64 = 1;
+pub const COSE_HEADER_CRIT: u64 = 2;
+pub const COSE_HEADER_CONTENT_TYPE: u64 = 3;
+pub const COSE_HEADER_KID: u64 = 4;
+pub const COSE_HEADER_IV: u64 = 5;
+pub const COSE_HEADER_PARTIAL_IV: u64 = 6;
+pub const COSE_HEADER_COUNTER_SIGNATURE: u64 = 7;
+pub const COSE_HEADER_X5BAG: u64 = 32;
+pub const COSE_HEADER_X5CHAIN: u64 = 33;
+pub const COSE_HEADER_X5T: u64 = 34;
+pub const COSE_HEADER_X5U: u64 = 35;
+
+/// A header label (or algorithm identifier), which is either an integer or a text string.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Label {
+    Int(i64),
+    Text(String),
+}
+
+impl Label {
+    /// Read a label from a `CborType`. Only integers and text strings are valid labels.
+    pub fn from_cbor(value: &CborType) -> Result<Label, CoseError> {
+        match *value {
+            CborType::Integer(val) => {
+                if val > i64::MAX as u64 {
+                    return Err(CoseError::MalformedInput);
+                }
+                Ok(Label::Int(val as i64))
+            }
+            CborType::SignedInteger(val) => Ok(Label::Int(val)),
+            CborType::String(ref val) => Ok(Label::Text(val.clone())),
+            _ => Err(CoseError::UnexpectedType),
+        }
+    }
+
+    /// Encode the label as a `CborType`.
+    pub fn to_cbor(&self) -> CborType {
+        match *self {
+            Label::Int(val) if val >= 0 => CborType::Integer(val as u64),
+            Label::Int(val) => CborType::SignedInteger(val),
+            Label::Text(ref val) => CborType::String(val.clone()),
+        }
+    }
+}
+
+/// The content type of a payload, either a CoAP Content-Format or a media type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContentType {
+    Uint(u64),
+    Text(String),
+}
+
+/// The hash of a certificate (`COSE_CertHash`), which identifies the certificate of the signer in
+/// the x5t header parameter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CertHash {
+    /// The hash algorithm, e.g. -16 for SHA-256.
+    pub alg: Label,
+    pub hash: Vec<u8>,
+}
+
+/// Where the certificates of the signers of a COSE_Sign message are.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CertificateProfile {
+    /// The layout of signed Firefox add-ons: the protected body header has a kid with the array
+    /// of intermediate certificates and the protected header of every signature a kid with the
+    /// signing certificate.
+    #[default]
+    LegacyKid,
+    /// The x5chain header parameter of every signature holds its certificate chain, starting
+    /// with the signing certificate (RFC 9360). Any certificates in x5bag header parameters are
+    /// available for building the chain as well.
+    X509,
+}
+
+/// A typed COSE header map.
+///
+/// The common header parameters are stored in their own fields. Any other label is kept as-is in
+/// `rest`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Header {
+    pub alg: Option<Label>,
+    pub crit: Vec<Label>,
+    pub content_type: Option<ContentType>,
+    pub kid: Option<Vec<u8>>,
+    pub iv: Option<Vec<u8>>,
+    pub partial_iv: Option<Vec<u8>>,
+    pub counter_signature: Option<CborType>,
+    /// An unordered bag of certificates.
+    pub x5bag: Vec<Vec<u8>>,
+    /// A certificate chain, starting with the certificate of the signer.
+    pub x5chain: Vec<Vec<u8>>,
+    pub x5t: Option<CertHash>,
+    /// The URI of the certificate chain of the signer.
+    pub x5u: Option<String>,
+    pub rest: BTreeMap<Label, CborType>,
+}
+
+fn get_bytes(value: &CborType) -> Result<Vec<u8>, CoseError> {
+    match *value {
+        CborType::Bytes(ref bytes) => Ok(bytes.clone()),
+        _ => Err(CoseError::UnexpectedType),
+    }
+}
+
+/// Read a `COSE_X509`, i.e. a single certificate or an array of at least two certificates.
+fn get_certs(value: &CborType) -> Result<Vec<Vec<u8>>, CoseError> {
+    match *value {
+        CborType::Bytes(ref cert) => Ok(vec![cert.clone()]),
+        CborType::Array(ref certs) => {
+            if certs.len() < 2 {
+                return Err(CoseError::MalformedInput);
+            }
+            certs.iter().map(get_bytes).collect()
+        }
+        _ => Err(CoseError::UnexpectedType),
+    }
+}
+
+/// Encode certificates as a `COSE_X509`.
+fn certs_to_cbor(certs: &[Vec<u8>]) -> CborType {
+    if certs.len() == 1 {
+        return CborType::Bytes(certs[0].clone());
+    }
+    CborType::Array(certs.iter().map(|cert| CborType::Bytes(cert.clone())).collect())
+}
+
+impl Header {
+    /// Parse a decoded header map.
+    ///
+    ///```rust,ignore
+    /// Generic_Headers = (
+    ///     ? 1 => int / tstr,  ; algorithm identifier
+    ///     ? 2 => [+label],    ; criticality
+    ///     ? 3 => tstr / int,  ; content type
+    ///     ? 4 => bstr,        ; key identifier
+    ///     ? ( 5 => bstr //    ; IV
+    ///         6 => bstr )     ; Partial IV
+    ///     ? 7 => COSE_Countersignature / [+COSE_Countersignature]
+    /// )
+    ///
+    /// COSE_X509 = bstr / [ 2*certs: bstr ]
+    /// COSE_CertHash = [ hashAlg: (int / tstr), hashValue: bstr ]
+    ///
+    /// X509_Headers = (
+    ///     ? 32 => COSE_X509,      ; x5bag
+    ///     ? 33 => COSE_X509,      ; x5chain
+    ///     ? 34 => COSE_CertHash,  ; x5t
+    ///     ? 35 => uri             ; x5u
+    /// )
+    ///```
+    pub fn from_map(map: &BTreeMap<CborType, CborType>) -> Result<Header, CoseError> {
+        let mut header = Header::default();
+        for (key, value) in map {
+            let label = Label::from_cbor(key)?;
+            let common_label = match label {
+                Label::Int(val) if val > 0 => Some(val as u64),
+                _ => None,
+            };
+            match common_label {
+                Some(COSE_HEADER_ALG) => header.alg = Some(Label::from_cbor(value)?),
+                Some(COSE_HEADER_CRIT) => {
+                    let labels = match *value {
+                        CborType::Array(ref labels) => labels,
+                        _ => return Err(CoseError::UnexpectedType),
+                    };
+                    if labels.is_empty() {
+                        return Err(CoseError::MalformedInput);
+                    }
+                    for label in labels {
+                        header.crit.push(Label::from_cbor(label)?);
+                    }
+                }
+                Some(COSE_HEADER_CONTENT_TYPE) => {
+                    header.content_type = Some(match *value {
+                        CborType::Integer(val) => ContentType::Uint(val),
+                        CborType::String(ref val) => ContentType::Text(val.clone()),
+                        _ => return Err(CoseError::UnexpectedType),
+                    });
+                }
+                Some(COSE_HEADER_KID) => header.kid = Some(get_bytes(value)?),
+                Some(COSE_HEADER_IV) => header.iv = Some(get_bytes(value)?),
+                Some(COSE_HEADER_PARTIAL_IV) => header.partial_iv = Some(get_bytes(value)?),
+                Some(COSE_HEADER_COUNTER_SIGNATURE) => {
+                    match *value {
+                        CborType::Array(_) => {}
+                        _ => return Err(CoseError::UnexpectedType),
+                    };
+                    header.counter_signature = Some(value.clone());
+                }
+                Some(COSE_HEADER_X5BAG) => header.x5bag = get_certs(value)?,
+                Some(COSE_HEADER_X5CHAIN) => header.x5chain = get_certs(value)?,
+                Some(COSE_HEADER_X5T) => {
+                    let cert_hash = match *value {
+                        CborType::Array(ref cert_hash) => cert_hash,
+                        _ => return Err(CoseError::UnexpectedType),
+                    };
+                    if cert_hash.len() != 2 {
+                        return Err(CoseError::MalformedInput);
+                    }
+                    header.x5t = Some(CertHash {
+                        alg: Label::from_cbor(&cert_hash[0])?,
+                        hash: get_bytes(&cert_hash[1])?,
+                    });
+                }
+                Some(COSE_HEADER_X5U) => {
+                    header.x5u = Some(match *value {
+                        CborType::String(ref uri) => uri.clone(),
+                        _ => return Err(CoseError::UnexpectedType),
+                    });
+                }
+                _ => {
+                    header.rest.insert(label, value.clone());
+                }
+            }
+        }
+        // The IV and the Partial IV must not both be present.
+        if header.iv.is_some() && header.partial_iv.is_some() {
+            return Err(CoseError::MalformedInput);
+        }
+        Ok(header)
+    }
+
+    /// Encode the header as a map. This is the inverse of `from_map`.
+    pub fn to_map(&self) -> BTreeMap<CborType, CborType> {
+        let mut map: BTreeMap<CborType, CborType> = BTreeMap::new();
+        for (label, value) in &self.rest {
+            map.insert(label.to_cbor(), value.clone());
+        }
+        if let Some(ref alg) = self.alg {
+            map.insert(CborType::Integer(COSE_HEADER_ALG), alg.to_cbor());
+        }
+        if !self.crit.is_empty() {
+            let labels = self.crit.iter().map(Label::to_cbor).collect();
+            map.insert(CborType::Integer(COSE_HEADER_CRIT), CborType::Array(labels));
+        }
+        if let Some(ref content_type) = self.content_type {
+            let value = match *content_type {
+                ContentType::Uint(val) => CborType::Integer(val),
+                ContentType::Text(ref val) => CborType::String(val.clone()),
+            };
+            map.insert(CborType::Integer(COSE_HEADER_CONTENT_TYPE), value);
+        }
+        if let Some(ref kid) = self.kid {
+            map.insert(CborType::Integer(COSE_HEADER_KID), CborType::Bytes(kid.clone()));
+        }
+        if let Some(ref iv) = self.iv {
+            map.insert(CborType::Integer(COSE_HEADER_IV), CborType::Bytes(iv.clone()));
+        }
+        if let Some(ref partial_iv) = self.partial_iv {
+            let value = CborType::Bytes(partial_iv.clone());
+            map.insert(CborType::Integer(COSE_HEADER_PARTIAL_IV), value);
+        }
+        if let Some(ref counter_signature) = self.counter_signature {
+            let label = CborType::Integer(COSE_HEADER_COUNTER_SIGNATURE);
+            map.insert(label, counter_signature.clone());
+        }
+        if !self.x5bag.is_empty() {
+            map.insert(CborType::Integer(COSE_HEADER_X5BAG), certs_to_cbor(&self.x5bag));
+        }
+        if !self.x5chain.is_empty() {
+            map.insert(CborType::Integer(COSE_HEADER_X5CHAIN), certs_to_cbor(&self.x5chain));
+        }
+        if let Some(ref x5t) = self.x5t {
+            let cert_hash = vec![x5t.alg.to_cbor(), CborType::Bytes(x5t.hash.clone())];
+            map.insert(CborType::Integer(COSE_HEADER_X5T), CborType::Array(cert_hash));
+        }
+        if let Some(ref x5u) = self.x5u {
+            map.insert(CborType::Integer(COSE_HEADER_X5U), CborType::String(x5u.clone()));
+        }
+        map
+    }
+
+    /// Get the value of an arbitrary header parameter that has no field of its own.
+    pub fn get(&self, label: &Label) -> Option<&CborType> {
+        self.rest.get(label)
+    }
+}
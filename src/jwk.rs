@@ -0,0 +1,617 @@
+//! Convert between COSE_Key and JSON Web Keys, see RFC 7517 and RFC 7518 section 6.
+//!
+//! EC, OKP, RSA and oct JWKs correspond to EC2, OKP, RSA and symmetric COSE keys. Curve names,
+//! key operations and algorithms are mapped by name. A parameter that has no counterpart in the
+//! target format, such as a JWK `use` or `x5c` member or a COSE Base IV, fails the conversion
+//! with `UnmappableParameter` rather than being dropped. So do the `mac create` and `mac verify`
+//! key operations, which JWK expresses as `sign` and `verify`, and algorithms that are not in
+//! the algorithm registry. The HMAC algorithms of oct keys are HS256, HS384 and HS512 in JWK,
+//! HMAC 256/64 has no JWK counterpart.
+//!
+//! Malformed JSON and base64url fails with `DecodingFailure`, and members of the wrong JSON type
+//! with `UnexpectedType`. So does JSON that nests arrays and objects deeper than JWK Sets need.
+
+use {CoseError, MacAlgorithm, algorithm};
+use algorithm::{Curve, KeyType};
+use header::Label;
+use key::{CoseKey, CoseKeySet, KeyOperation, KeyParameters, RsaPrivateKey};
+use std::collections::BTreeMap;
+
+/// A JSON value. Numbers are kept as they are written, JWKs don't use them.
+#[derive(Clone, Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(BTreeMap<String, Json>),
+}
+
+/// The maximum nesting depth of arrays and objects. A JWK Set with key_ops has a depth of 3.
+const MAX_JSON_DEPTH: usize = 16;
+
+struct JsonParser<'a> {
+    input: &'a [u8],
+    pos: usize,
+    /// The number of arrays and objects the parser is in.
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).cloned()
+    }
+
+    fn next(&mut self) -> Result<u8, CoseError> {
+        let byte = self.peek().ok_or(CoseError::DecodingFailure)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &[u8]) -> Result<(), CoseError> {
+        if !self.input[self.pos..].starts_with(literal) {
+            return Err(CoseError::DecodingFailure);
+        }
+        self.pos += literal.len();
+        Ok(())
+    }
+
+    fn parse_value(&mut self) -> Result<Json, CoseError> {
+        self.skip_whitespace();
+        let value = match self.peek().ok_or(CoseError::DecodingFailure)? {
+            b'{' => self.nested(JsonParser::parse_object)?,
+            b'[' => self.nested(JsonParser::parse_array)?,
+            b'"' => Json::String(self.parse_string()?),
+            b'n' => self.expect(b"null").map(|_| Json::Null)?,
+            b't' => self.expect(b"true").map(|_| Json::Bool(true))?,
+            b'f' => self.expect(b"false").map(|_| Json::Bool(false))?,
+            _ => self.parse_number()?,
+        };
+        self.skip_whitespace();
+        Ok(value)
+    }
+
+    /// Parse an array or object, which mustn't be nested too deeply. This bounds the recursion.
+    fn nested<F>(&mut self, parse: F) -> Result<Json, CoseError>
+    where
+        F: FnOnce(&mut JsonParser<'a>) -> Result<Json, CoseError>,
+    {
+        if self.depth == MAX_JSON_DEPTH {
+            return Err(CoseError::DecodingFailure);
+        }
+        self.depth += 1;
+        let value = parse(self)?;
+        self.depth -= 1;
+        Ok(value)
+    }
+
+    fn parse_object(&mut self) -> Result<Json, CoseError> {
+        let mut members = BTreeMap::new();
+        self.expect(b"{")?;
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let name = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(b":")?;
+            let value = self.parse_value()?;
+            // Member names must be unique.
+            if members.insert(name, value).is_some() {
+                return Err(CoseError::DecodingFailure);
+            }
+            match self.next()? {
+                b',' => {}
+                b'}' => return Ok(Json::Object(members)),
+                _ => return Err(CoseError::DecodingFailure),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Json, CoseError> {
+        let mut values = Vec::new();
+        self.expect(b"[")?;
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            match self.next()? {
+                b',' => {}
+                b']' => return Ok(Json::Array(values)),
+                _ => return Err(CoseError::DecodingFailure),
+            }
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, CoseError> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let digit = (self.next()? as char).to_digit(16).ok_or(CoseError::DecodingFailure)?;
+            value = value * 16 + digit;
+        }
+        Ok(value)
+    }
+
+    fn parse_string(&mut self) -> Result<String, CoseError> {
+        self.expect(b"\"")?;
+        let mut bytes = Vec::new();
+        loop {
+            match self.next()? {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = match self.next()? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let mut code = self.parse_hex4()?;
+                            // A character outside the BMP is escaped as a surrogate pair.
+                            if (0xd800..0xdc00).contains(&code) {
+                                self.expect(b"\\u")?;
+                                let low = self.parse_hex4()?;
+                                if !(0xdc00..0xe000).contains(&low) {
+                                    return Err(CoseError::DecodingFailure);
+                                }
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            ::std::char::from_u32(code).ok_or(CoseError::DecodingFailure)?
+                        }
+                        _ => return Err(CoseError::DecodingFailure),
+                    };
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                }
+                byte if byte < 0x20 => return Err(CoseError::DecodingFailure),
+                byte => bytes.push(byte),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| CoseError::DecodingFailure)
+    }
+
+    /// Skip the digits at the current position and return how many there are.
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    /// Parse a number with the grammar of RFC 8259 section 6: an optional minus, an integer
+    /// without leading zeros, an optional fraction and an optional exponent.
+    fn parse_number(&mut self) -> Result<Json, CoseError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let integer_start = self.pos;
+        let integer_digits = self.skip_digits();
+        if integer_digits == 0 || (integer_digits > 1 && self.input[integer_start] == b'0') {
+            return Err(CoseError::DecodingFailure);
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if self.skip_digits() == 0 {
+                return Err(CoseError::DecodingFailure);
+            }
+        }
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.skip_digits() == 0 {
+                return Err(CoseError::DecodingFailure);
+            }
+        }
+        let number = String::from_utf8(self.input[start..self.pos].to_vec())
+            .map_err(|_| CoseError::DecodingFailure)?;
+        Ok(Json::Number(number))
+    }
+}
+
+fn parse_json(input: &str) -> Result<Json, CoseError> {
+    let mut parser = JsonParser {
+        input: input.as_bytes(),
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.parse_value()?;
+    if parser.pos != parser.input.len() {
+        return Err(CoseError::DecodingFailure);
+    }
+    Ok(value)
+}
+
+fn write_json(value: &Json, out: &mut String) {
+    match *value {
+        Json::Null => out.push_str("null"),
+        Json::Bool(value) => out.push_str(if value { "true" } else { "false" }),
+        Json::Number(ref number) => out.push_str(number),
+        Json::String(ref string) => {
+            out.push('"');
+            for c in string.chars() {
+                match c {
+                    '"' => out.push_str("\\\""),
+                    '\\' => out.push_str("\\\\"),
+                    '\n' => out.push_str("\\n"),
+                    '\r' => out.push_str("\\r"),
+                    '\t' => out.push_str("\\t"),
+                    c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                    c => out.push(c),
+                }
+            }
+            out.push('"');
+        }
+        Json::Array(ref values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json(value, out);
+            }
+            out.push(']');
+        }
+        Json::Object(ref members) => {
+            out.push('{');
+            for (i, (name, value)) in members.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json(&Json::String(name.clone()), out);
+                out.push(':');
+                write_json(value, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encode bytes as base64url without padding, see RFC 7515 section 2.
+fn base64url_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(3) {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let bits = (group[0] as u32) << 16 | (group[1] as u32) << 8 | group[2] as u32;
+        for i in 0..chunk.len() + 1 {
+            let index = (bits >> (18 - 6 * i)) & 0x3f;
+            encoded.push(BASE64URL_ALPHABET[index as usize] as char);
+        }
+    }
+    encoded
+}
+
+/// Decode base64url without padding.
+fn base64url_decode(encoded: &str) -> Result<Vec<u8>, CoseError> {
+    if encoded.len() % 4 == 1 {
+        return Err(CoseError::DecodingFailure);
+    }
+    let mut bytes = Vec::new();
+    for chunk in encoded.as_bytes().chunks(4) {
+        let mut bits = 0u32;
+        for (i, c) in chunk.iter().enumerate() {
+            let value = BASE64URL_ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or(CoseError::DecodingFailure)?;
+            bits |= (value as u32) << (18 - 6 * i);
+        }
+        let group = [(bits >> 16) as u8, (bits >> 8) as u8, bits as u8];
+        // The bits that don't make a full byte must be zero.
+        let length = chunk.len() - 1;
+        if group[length..].iter().any(|&byte| byte != 0) {
+            return Err(CoseError::DecodingFailure);
+        }
+        bytes.extend_from_slice(&group[..length]);
+    }
+    Ok(bytes)
+}
+
+const KEY_OPERATIONS: [(KeyOperation, &str); 8] = [(KeyOperation::Sign, "sign"),
+                                                   (KeyOperation::Verify, "verify"),
+                                                   (KeyOperation::Encrypt, "encrypt"),
+                                                   (KeyOperation::Decrypt, "decrypt"),
+                                                   (KeyOperation::WrapKey, "wrapKey"),
+                                                   (KeyOperation::UnwrapKey, "unwrapKey"),
+                                                   (KeyOperation::DeriveKey, "deriveKey"),
+                                                   (KeyOperation::DeriveBits, "deriveBits")];
+
+/// The JWK names of the MAC algorithms, see RFC 7518 section 3.1.
+const MAC_ALGORITHMS: [(MacAlgorithm, &str); 3] = [(MacAlgorithm::HMAC256_256, "HS256"),
+                                                   (MacAlgorithm::HMAC384_384, "HS384"),
+                                                   (MacAlgorithm::HMAC512_512, "HS512")];
+
+/// Map the name of a JWK algorithm to its COSE algorithm identifier.
+fn algorithm_from_name(name: &str) -> Result<i64, CoseError> {
+    if let Some(algorithm) = algorithm::from_name(name) {
+        return Ok(algorithm.id());
+    }
+    MAC_ALGORITHMS
+        .iter()
+        .find(|&&(_, known)| known == name)
+        .map(|&(algorithm, _)| algorithm.id())
+        .ok_or(CoseError::UnmappableParameter)
+}
+
+/// Map the `alg` of a COSE_Key to the name of the JWK algorithm.
+fn algorithm_name(key: &CoseKey) -> Result<&'static str, CoseError> {
+    if let Some(algorithm) = key.signature_algorithm() {
+        return Ok(algorithm.name());
+    }
+    let algorithm = key.mac_algorithm().ok_or(CoseError::UnmappableParameter)?;
+    MAC_ALGORITHMS
+        .iter()
+        .find(|&&(known, _)| known == algorithm)
+        .map(|&(_, name)| name)
+        .ok_or(CoseError::UnmappableParameter)
+}
+
+/// The members of a JWK, which are removed as they are converted.
+struct Members(BTreeMap<String, Json>);
+
+impl Members {
+    fn take_string(&mut self, name: &str) -> Result<Option<String>, CoseError> {
+        match self.0.remove(name) {
+            Some(Json::String(value)) => Ok(Some(value)),
+            Some(_) => Err(CoseError::UnexpectedType),
+            None => Ok(None),
+        }
+    }
+
+    fn take_bytes(&mut self, name: &str) -> Result<Option<Vec<u8>>, CoseError> {
+        match self.take_string(name)? {
+            Some(value) => Ok(Some(base64url_decode(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn take_required_bytes(&mut self, name: &str) -> Result<Vec<u8>, CoseError> {
+        self.take_bytes(name)?.ok_or(CoseError::MalformedInput)
+    }
+}
+
+fn key_from_members(members: BTreeMap<String, Json>) -> Result<CoseKey, CoseError> {
+    let mut members = Members(members);
+    let kty = members.take_string("kty")?.ok_or(CoseError::MalformedInput)?;
+    let params = match kty.as_str() {
+        "EC" | "OKP" => {
+            let crv = members.take_string("crv")?.ok_or(CoseError::MalformedInput)?;
+            let key_type = if kty == "EC" { KeyType::EC2 } else { KeyType::OKP };
+            let crv = match Curve::from_name(&crv) {
+                Some(crv) if crv.key_type() == key_type => crv,
+                _ => return Err(CoseError::UnexpectedHeaderValue),
+            };
+            let x = Some(members.take_required_bytes("x")?);
+            if kty == "EC" {
+                KeyParameters::Ec2 {
+                    crv,
+                    x,
+                    y: Some(members.take_required_bytes("y")?),
+                    d: members.take_bytes("d")?,
+                }
+            } else {
+                KeyParameters::Okp {
+                    crv,
+                    x,
+                    d: members.take_bytes("d")?,
+                }
+            }
+        }
+        "RSA" => {
+            if members.0.contains_key("oth") {
+                return Err(CoseError::Unimplemented);
+            }
+            let n = members.take_required_bytes("n")?;
+            let e = members.take_required_bytes("e")?;
+            let private = match members.take_bytes("d")? {
+                // COSE needs all private parameters, JWK allows to only have d.
+                Some(d) => {
+                    let mut take = |name| {
+                        members.take_bytes(name)?.ok_or(CoseError::UnmappableParameter)
+                    };
+                    Some(RsaPrivateKey {
+                        d,
+                        p: take("p")?,
+                        q: take("q")?,
+                        dp: take("dp")?,
+                        dq: take("dq")?,
+                        qinv: take("qi")?,
+                    })
+                }
+                None => None,
+            };
+            KeyParameters::Rsa { n, e, private }
+        }
+        "oct" => KeyParameters::Symmetric { k: members.take_required_bytes("k")? },
+        _ => return Err(CoseError::UnexpectedHeaderValue),
+    };
+    let mut key = CoseKey::new(params);
+    key.kid = members.take_string("kid")?.map(String::into_bytes);
+    if let Some(alg) = members.take_string("alg")? {
+        key.alg = Some(Label::Int(algorithm_from_name(&alg)?));
+    }
+    match members.0.remove("key_ops") {
+        Some(Json::Array(operations)) => {
+            if operations.is_empty() {
+                return Err(CoseError::MalformedInput);
+            }
+            for operation in operations {
+                let name = match operation {
+                    Json::String(name) => name,
+                    _ => return Err(CoseError::UnexpectedType),
+                };
+                let operation = KEY_OPERATIONS
+                    .iter()
+                    .find(|&&(_, known)| known == name)
+                    .ok_or(CoseError::UnmappableParameter)?;
+                key.key_ops.push(operation.0.clone());
+            }
+        }
+        Some(_) => return Err(CoseError::UnexpectedType),
+        None => {}
+    }
+    // Everything else, e.g. use or x5c, has no COSE_Key counterpart.
+    if !members.0.is_empty() {
+        return Err(CoseError::UnmappableParameter);
+    }
+    key.validate()?;
+    Ok(key)
+}
+
+fn key_to_members(key: &CoseKey) -> Result<BTreeMap<String, Json>, CoseError> {
+    key.validate()?;
+    if key.base_iv.is_some() || !key.rest.is_empty() {
+        return Err(CoseError::UnmappableParameter);
+    }
+    let mut members = BTreeMap::new();
+    {
+        let mut insert = |name: &str, value: Json| {
+            members.insert(name.to_owned(), value);
+        };
+        let bytes = |value: &[u8]| Json::String(base64url_encode(value));
+        // JWKs always have the public key.
+        let public = |value: &Option<Vec<u8>>| -> Result<Json, CoseError> {
+            match *value {
+                Some(ref value) => Ok(bytes(value)),
+                None => Err(CoseError::MalformedInput),
+            }
+        };
+        match key.params {
+            KeyParameters::Ec2 { crv, ref x, ref y, ref d } => {
+                insert("kty", Json::String("EC".to_owned()));
+                insert("crv", Json::String(crv.name().to_owned()));
+                insert("x", public(x)?);
+                insert("y", public(y)?);
+                if let Some(ref d) = *d {
+                    insert("d", bytes(d));
+                }
+            }
+            KeyParameters::Okp { crv, ref x, ref d } => {
+                insert("kty", Json::String("OKP".to_owned()));
+                insert("crv", Json::String(crv.name().to_owned()));
+                insert("x", public(x)?);
+                if let Some(ref d) = *d {
+                    insert("d", bytes(d));
+                }
+            }
+            KeyParameters::Rsa { ref n, ref e, ref private } => {
+                insert("kty", Json::String("RSA".to_owned()));
+                insert("n", bytes(n));
+                insert("e", bytes(e));
+                if let Some(ref private) = *private {
+                    insert("d", bytes(&private.d));
+                    insert("p", bytes(&private.p));
+                    insert("q", bytes(&private.q));
+                    insert("dp", bytes(&private.dp));
+                    insert("dq", bytes(&private.dq));
+                    insert("qi", bytes(&private.qinv));
+                }
+            }
+            KeyParameters::Symmetric { ref k } => {
+                insert("kty", Json::String("oct".to_owned()));
+                insert("k", bytes(k));
+            }
+        }
+        if let Some(ref kid) = key.kid {
+            let kid = String::from_utf8(kid.clone()).map_err(|_| CoseError::UnmappableParameter)?;
+            insert("kid", Json::String(kid));
+        }
+        if key.alg.is_some() {
+            insert("alg", Json::String(algorithm_name(key)?.to_owned()));
+        }
+        if !key.key_ops.is_empty() {
+            let mut operations = Vec::new();
+            for operation in &key.key_ops {
+                let name = KEY_OPERATIONS
+                    .iter()
+                    .find(|&(known, _)| known == operation)
+                    .ok_or(CoseError::UnmappableParameter)?
+                    .1;
+                operations.push(Json::String(name.to_owned()));
+            }
+            insert("key_ops", Json::Array(operations));
+        }
+    }
+    Ok(members)
+}
+
+/// Convert a JWK to a COSE_Key.
+pub fn decode_jwk(jwk: &str) -> Result<CoseKey, CoseError> {
+    match parse_json(jwk)? {
+        Json::Object(members) => key_from_members(members),
+        _ => Err(CoseError::UnexpectedType),
+    }
+}
+
+/// Convert a COSE_Key to a JWK. The members of the JWK are sorted by name.
+pub fn encode_jwk(key: &CoseKey) -> Result<String, CoseError> {
+    let mut jwk = String::new();
+    write_json(&Json::Object(key_to_members(key)?), &mut jwk);
+    Ok(jwk)
+}
+
+/// Convert a JWK Set to a COSE_KeySet. The JWK Set must only have the `keys` member.
+pub fn decode_jwk_set(jwk_set: &str) -> Result<CoseKeySet, CoseError> {
+    let mut members = match parse_json(jwk_set)? {
+        Json::Object(members) => members,
+        _ => return Err(CoseError::UnexpectedType),
+    };
+    let jwks = match members.remove("keys") {
+        Some(Json::Array(jwks)) => jwks,
+        Some(_) => return Err(CoseError::UnexpectedType),
+        None => return Err(CoseError::MalformedInput),
+    };
+    if !members.is_empty() {
+        return Err(CoseError::UnmappableParameter);
+    }
+    if jwks.is_empty() {
+        return Err(CoseError::MalformedInput);
+    }
+    let mut key_set = CoseKeySet { keys: Vec::new() };
+    for jwk in jwks {
+        match jwk {
+            Json::Object(members) => key_set.keys.push(key_from_members(members)?),
+            _ => return Err(CoseError::UnexpectedType),
+        }
+    }
+    Ok(key_set)
+}
+
+/// Convert a COSE_KeySet to a JWK Set.
+pub fn encode_jwk_set(key_set: &CoseKeySet) -> Result<String, CoseError> {
+    if key_set.keys.is_empty() {
+        return Err(CoseError::MalformedInput);
+    }
+    let mut jwks = Vec::new();
+    for key in &key_set.keys {
+        jwks.push(Json::Object(key_to_members(key)?));
+    }
+    let mut members = BTreeMap::new();
+    members.insert("keys".to_owned(), Json::Array(jwks));
+    let mut jwk_set = String::new();
+    write_json(&Json::Object(members), &mut jwk_set);
+    Ok(jwk_set)
+}
@@ -0,0 +1,608 @@
+//! Parse and encode COSE_Key and COSE_KeySet, see RFC 9052 section 7.
+//!
+//! EC2 and OKP keys (RFC 9053 section 7), RSA keys (RFC 8230 section 4) and symmetric keys are
+//! supported. Keys are validated when they are decoded and before they are encoded: the
+//! parameters of the key type have to be present and have the right size, and an `alg` from the
+//! algorithm registry has to fit the key type and curve. Keys can be identified by their COSE Key
+//! Thumbprint (RFC 9679), computed with a hash function of the caller's choice.
+//!
+//! Decoding fails with `UnexpectedType` for a parameter of the wrong CBOR type,
+//! `UnexpectedHeaderValue` for an unknown key type or curve, and `MalformedInput` for keys that
+//! are invalid otherwise. EC2 points with a compressed `y` (a boolean) and multi-prime RSA keys
+//! are not supported.
+
+use cbor::CborType;
+use cbor::decoder::decode;
+use {CoseError, MacAlgorithm, SignatureAlgorithm};
+use algorithm;
+use algorithm::{Curve, KeyType};
+use header::Label;
+use std::collections::BTreeMap;
+
+pub const COSE_KEY_KTY: i64 = 1;
+pub const COSE_KEY_KID: i64 = 2;
+pub const COSE_KEY_ALG: i64 = 3;
+pub const COSE_KEY_KEY_OPS: i64 = 4;
+pub const COSE_KEY_BASE_IV: i64 = 5;
+
+// EC2 and OKP key parameters.
+pub const COSE_KEY_CRV: i64 = -1;
+pub const COSE_KEY_X: i64 = -2;
+pub const COSE_KEY_Y: i64 = -3;
+pub const COSE_KEY_D: i64 = -4;
+
+// RSA key parameters.
+pub const COSE_KEY_RSA_N: i64 = -1;
+pub const COSE_KEY_RSA_E: i64 = -2;
+pub const COSE_KEY_RSA_D: i64 = -3;
+pub const COSE_KEY_RSA_P: i64 = -4;
+pub const COSE_KEY_RSA_Q: i64 = -5;
+pub const COSE_KEY_RSA_DP: i64 = -6;
+pub const COSE_KEY_RSA_DQ: i64 = -7;
+pub const COSE_KEY_RSA_QINV: i64 = -8;
+pub const COSE_KEY_RSA_OTHER: i64 = -9;
+pub const COSE_KEY_RSA_T_I: i64 = -12;
+
+// Symmetric key parameters.
+pub const COSE_KEY_K: i64 = -1;
+
+/// A key operation, see RFC 9052 section 7.1.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KeyOperation {
+    Sign,
+    Verify,
+    Encrypt,
+    Decrypt,
+    WrapKey,
+    UnwrapKey,
+    DeriveKey,
+    DeriveBits,
+    MacCreate,
+    MacVerify,
+    /// Any other operation.
+    Other(Label),
+}
+
+const KEY_OPERATIONS: [KeyOperation; 10] = [KeyOperation::Sign,
+                                            KeyOperation::Verify,
+                                            KeyOperation::Encrypt,
+                                            KeyOperation::Decrypt,
+                                            KeyOperation::WrapKey,
+                                            KeyOperation::UnwrapKey,
+                                            KeyOperation::DeriveKey,
+                                            KeyOperation::DeriveBits,
+                                            KeyOperation::MacCreate,
+                                            KeyOperation::MacVerify];
+
+impl KeyOperation {
+    fn from_label(label: Label) -> KeyOperation {
+        KEY_OPERATIONS
+            .iter()
+            .find(|operation| operation.to_label() == label)
+            .cloned()
+            .unwrap_or(KeyOperation::Other(label))
+    }
+
+    fn to_label(&self) -> Label {
+        Label::Int(match *self {
+            KeyOperation::Sign => 1,
+            KeyOperation::Verify => 2,
+            KeyOperation::Encrypt => 3,
+            KeyOperation::Decrypt => 4,
+            KeyOperation::WrapKey => 5,
+            KeyOperation::UnwrapKey => 6,
+            KeyOperation::DeriveKey => 7,
+            KeyOperation::DeriveBits => 8,
+            KeyOperation::MacCreate => 9,
+            KeyOperation::MacVerify => 10,
+            KeyOperation::Other(ref label) => return label.clone(),
+        })
+    }
+}
+
+/// The private parameters of a two-prime RSA key.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RsaPrivateKey {
+    pub d: Vec<u8>,
+    pub p: Vec<u8>,
+    pub q: Vec<u8>,
+    pub dp: Vec<u8>,
+    pub dq: Vec<u8>,
+    pub qinv: Vec<u8>,
+}
+
+/// The parameters of a key, which depend on its type. Integers like the RSA modulus are
+/// unsigned big-endian byte strings.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KeyParameters {
+    /// An elliptic curve key. Public keys have `x` and `y`, private keys have `d` and may also
+    /// have the public coordinates.
+    Ec2 {
+        crv: Curve,
+        x: Option<Vec<u8>>,
+        y: Option<Vec<u8>>,
+        d: Option<Vec<u8>>,
+    },
+    /// An octet key pair. Public keys have `x`, private keys have `d` and may also have `x`.
+    Okp {
+        crv: Curve,
+        x: Option<Vec<u8>>,
+        d: Option<Vec<u8>>,
+    },
+    /// An RSA key with modulus `n` and public exponent `e`, and the private parameters for
+    /// private keys.
+    Rsa {
+        n: Vec<u8>,
+        e: Vec<u8>,
+        private: Option<RsaPrivateKey>,
+    },
+    Symmetric { k: Vec<u8> },
+}
+
+/// A COSE_Key.
+///
+///```rust,ignore
+/// COSE_Key = {
+///     1 => tstr / int,          ; kty
+///     ? 2 => bstr,              ; kid
+///     ? 3 => tstr / int,        ; alg
+///     ? 4 => [+ (tstr / int) ], ; key_ops
+///     ? 5 => bstr,              ; Base IV
+///     * label => values
+/// }
+///```
+///
+/// The key type follows from `params`. Parameters that are neither common nor defined for the
+/// key type are kept as-is in `rest`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoseKey {
+    pub params: KeyParameters,
+    pub kid: Option<Vec<u8>>,
+    pub alg: Option<Label>,
+    pub key_ops: Vec<KeyOperation>,
+    pub base_iv: Option<Vec<u8>>,
+    pub rest: BTreeMap<Label, CborType>,
+}
+
+fn get_bytes(value: &CborType) -> Result<Vec<u8>, CoseError> {
+    match *value {
+        CborType::Bytes(ref bytes) => Ok(bytes.clone()),
+        _ => Err(CoseError::UnexpectedType),
+    }
+}
+
+fn get_int(value: &CborType) -> Result<i64, CoseError> {
+    match Label::from_cbor(value)? {
+        Label::Int(val) => Ok(val),
+        Label::Text(_) => Err(CoseError::UnexpectedHeaderValue),
+    }
+}
+
+/// Remove a key parameter from `params` and read it as a byte string.
+fn take_bytes(
+    params: &mut BTreeMap<i64, &CborType>,
+    label: i64,
+) -> Result<Option<Vec<u8>>, CoseError> {
+    match params.remove(&label) {
+        Some(value) => Ok(Some(get_bytes(value)?)),
+        None => Ok(None),
+    }
+}
+
+/// Check the size of a coordinate or key of a curve.
+fn check_size(value: &Option<Vec<u8>>, size: usize) -> Result<(), CoseError> {
+    match *value {
+        Some(ref value) if value.len() != size => Err(CoseError::MalformedInput),
+        _ => Ok(()),
+    }
+}
+
+impl CoseKey {
+    /// Create a key with the given parameters and no optional common parameters.
+    pub fn new(params: KeyParameters) -> CoseKey {
+        CoseKey {
+            params,
+            kid: None,
+            alg: None,
+            key_ops: Vec::new(),
+            base_iv: None,
+            rest: BTreeMap::new(),
+        }
+    }
+
+    /// The type of the key.
+    pub fn kty(&self) -> KeyType {
+        match self.params {
+            KeyParameters::Ec2 { .. } => KeyType::EC2,
+            KeyParameters::Okp { .. } => KeyType::OKP,
+            KeyParameters::Rsa { .. } => KeyType::RSA,
+            KeyParameters::Symmetric { .. } => KeyType::Symmetric,
+        }
+    }
+
+    /// The curve of EC2 and OKP keys.
+    pub fn crv(&self) -> Option<Curve> {
+        match self.params {
+            KeyParameters::Ec2 { crv, .. } | KeyParameters::Okp { crv, .. } => Some(crv),
+            KeyParameters::Rsa { .. } | KeyParameters::Symmetric { .. } => None,
+        }
+    }
+
+    /// The signature algorithm of the key, if its `alg` is one from the algorithm registry.
+    pub fn signature_algorithm(&self) -> Option<SignatureAlgorithm> {
+        match self.alg {
+            Some(Label::Int(id)) => algorithm::from_id(id),
+            _ => None,
+        }
+    }
+
+    /// The MAC algorithm of the key, if its `alg` is one from the algorithm registry.
+    pub fn mac_algorithm(&self) -> Option<MacAlgorithm> {
+        match self.alg {
+            Some(Label::Int(id)) => algorithm::mac_from_id(id),
+            _ => None,
+        }
+    }
+
+    /// Check that the key has the parameters its type requires, that they have the right size,
+    /// and that a signature algorithm in `alg` fits the key. MAC algorithms need symmetric keys.
+    pub fn validate(&self) -> Result<(), CoseError> {
+        match self.params {
+            KeyParameters::Ec2 { crv, ref x, ref y, ref d } => {
+                if crv.key_type() != KeyType::EC2 {
+                    return Err(CoseError::MalformedInput);
+                }
+                // Either both coordinates of the public key are present or neither is, in which
+                // case this has to be a private key.
+                match (x, y, d) {
+                    (&Some(_), &Some(_), _) | (&None, &None, &Some(_)) => {}
+                    _ => return Err(CoseError::MalformedInput),
+                }
+                check_size(x, crv.key_size())?;
+                check_size(y, crv.key_size())?;
+                if let Some(ref d) = *d {
+                    if d.is_empty() || d.len() > crv.key_size() {
+                        return Err(CoseError::MalformedInput);
+                    }
+                }
+            }
+            KeyParameters::Okp { crv, ref x, ref d } => {
+                if crv.key_type() != KeyType::OKP || (x.is_none() && d.is_none()) {
+                    return Err(CoseError::MalformedInput);
+                }
+                check_size(x, crv.key_size())?;
+                check_size(d, crv.key_size())?;
+            }
+            KeyParameters::Rsa { ref n, ref e, ref private } => {
+                let mut values = vec![n, e];
+                if let Some(ref private) = *private {
+                    values.extend_from_slice(&[&private.d,
+                                               &private.p,
+                                               &private.q,
+                                               &private.dp,
+                                               &private.dq,
+                                               &private.qinv]);
+                }
+                if values.iter().any(|value| value.is_empty()) {
+                    return Err(CoseError::MalformedInput);
+                }
+            }
+            KeyParameters::Symmetric { ref k } => {
+                if k.is_empty() {
+                    return Err(CoseError::MalformedInput);
+                }
+            }
+        }
+        if let Some(algorithm) = self.signature_algorithm() {
+            if !self.fits_algorithm(algorithm) {
+                return Err(CoseError::MalformedInput);
+            }
+        }
+        if self.mac_algorithm().is_some() && self.kty() != KeyType::Symmetric {
+            return Err(CoseError::MalformedInput);
+        }
+        Ok(())
+    }
+
+    /// Returns true if the key type and curve of the key are the ones of the signature algorithm.
+    pub fn fits_algorithm(&self, algorithm: SignatureAlgorithm) -> bool {
+        let info = algorithm.info();
+        if info.key_type != self.kty() {
+            return false;
+        }
+        match (info.curve, self.crv()) {
+            (Some(curve), crv) => crv == Some(curve),
+            // EdDSA works with both signature curves.
+            (None, Some(crv)) => crv == Curve::Ed25519 || crv == Curve::Ed448,
+            (None, None) => true,
+        }
+    }
+
+    /// Parse a decoded COSE_Key map and validate the key.
+    pub fn from_map(map: &BTreeMap<CborType, CborType>) -> Result<CoseKey, CoseError> {
+        let kty = match map.get(&CborType::Integer(COSE_KEY_KTY as u64)) {
+            Some(kty) => KeyType::from_id(get_int(kty)?).ok_or(CoseError::UnexpectedHeaderValue)?,
+            None => return Err(CoseError::MalformedInput),
+        };
+        let mut kid = None;
+        let mut alg = None;
+        let mut key_ops = Vec::new();
+        let mut base_iv = None;
+        // The parameters of the key type, which have negative labels, and all others.
+        let mut params: BTreeMap<i64, &CborType> = BTreeMap::new();
+        let mut rest = BTreeMap::new();
+        for (key, value) in map {
+            let label = Label::from_cbor(key)?;
+            match label {
+                Label::Int(COSE_KEY_KTY) => {}
+                Label::Int(COSE_KEY_KID) => kid = Some(get_bytes(value)?),
+                Label::Int(COSE_KEY_ALG) => alg = Some(Label::from_cbor(value)?),
+                Label::Int(COSE_KEY_KEY_OPS) => {
+                    let operations = match *value {
+                        CborType::Array(ref operations) => operations,
+                        _ => return Err(CoseError::UnexpectedType),
+                    };
+                    if operations.is_empty() {
+                        return Err(CoseError::MalformedInput);
+                    }
+                    for operation in operations {
+                        key_ops.push(KeyOperation::from_label(Label::from_cbor(operation)?));
+                    }
+                }
+                Label::Int(COSE_KEY_BASE_IV) => base_iv = Some(get_bytes(value)?),
+                Label::Int(val) if val < 0 => {
+                    params.insert(val, value);
+                }
+                _ => {
+                    rest.insert(label, value.clone());
+                }
+            }
+        }
+
+        let key_params = match kty {
+            KeyType::EC2 | KeyType::OKP => {
+                let crv = match params.remove(&COSE_KEY_CRV) {
+                    Some(crv) => Curve::from_id(get_int(crv)?),
+                    None => return Err(CoseError::MalformedInput),
+                };
+                let crv = match crv {
+                    Some(crv) if crv.key_type() == kty => crv,
+                    _ => return Err(CoseError::UnexpectedHeaderValue),
+                };
+                if kty == KeyType::EC2 {
+                    KeyParameters::Ec2 {
+                        crv,
+                        x: take_bytes(&mut params, COSE_KEY_X)?,
+                        y: take_bytes(&mut params, COSE_KEY_Y)?,
+                        d: take_bytes(&mut params, COSE_KEY_D)?,
+                    }
+                } else {
+                    KeyParameters::Okp {
+                        crv,
+                        x: take_bytes(&mut params, COSE_KEY_X)?,
+                        d: take_bytes(&mut params, COSE_KEY_D)?,
+                    }
+                }
+            }
+            KeyType::RSA => {
+                if params.range(COSE_KEY_RSA_T_I..COSE_KEY_RSA_OTHER + 1).next().is_some() {
+                    return Err(CoseError::Unimplemented);
+                }
+                let n = take_bytes(&mut params, COSE_KEY_RSA_N)?.ok_or(CoseError::MalformedInput)?;
+                let e = take_bytes(&mut params, COSE_KEY_RSA_E)?.ok_or(CoseError::MalformedInput)?;
+                let private = match take_bytes(&mut params, COSE_KEY_RSA_D)? {
+                    Some(d) => {
+                        let mut take_required = |label: i64| -> Result<Vec<u8>, CoseError> {
+                            take_bytes(&mut params, label)?.ok_or(CoseError::MalformedInput)
+                        };
+                        Some(RsaPrivateKey {
+                            d,
+                            p: take_required(COSE_KEY_RSA_P)?,
+                            q: take_required(COSE_KEY_RSA_Q)?,
+                            dp: take_required(COSE_KEY_RSA_DP)?,
+                            dq: take_required(COSE_KEY_RSA_DQ)?,
+                            qinv: take_required(COSE_KEY_RSA_QINV)?,
+                        })
+                    }
+                    None => None,
+                };
+                KeyParameters::Rsa { n, e, private }
+            }
+            KeyType::Symmetric => {
+                KeyParameters::Symmetric {
+                    k: take_bytes(&mut params, COSE_KEY_K)?.ok_or(CoseError::MalformedInput)?,
+                }
+            }
+        };
+        // The private parameters of RSA keys come as a whole, starting with d.
+        if kty == KeyType::RSA && params.keys().any(|&label| label >= COSE_KEY_RSA_QINV) {
+            return Err(CoseError::MalformedInput);
+        }
+        for (label, value) in params {
+            rest.insert(Label::Int(label), value.clone());
+        }
+
+        let key = CoseKey {
+            params: key_params,
+            kid,
+            alg,
+            key_ops,
+            base_iv,
+            rest,
+        };
+        key.validate()?;
+        Ok(key)
+    }
+
+    /// Decode and validate a COSE_Key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<CoseKey, CoseError> {
+        match decode(bytes) {
+            Ok(CborType::Map(map)) => CoseKey::from_map(&map),
+            Ok(_) => Err(CoseError::UnexpectedType),
+            Err(_) => Err(CoseError::DecodingFailure),
+        }
+    }
+
+    /// Validate the key and encode it as a map. This is the inverse of `from_map`.
+    pub fn to_map(&self) -> Result<BTreeMap<CborType, CborType>, CoseError> {
+        self.validate()?;
+        let mut map: BTreeMap<CborType, CborType> = BTreeMap::new();
+        for (label, value) in &self.rest {
+            map.insert(label.to_cbor(), value.clone());
+        }
+        let kty = CborType::Integer(self.kty().id() as u64);
+        map.insert(CborType::Integer(COSE_KEY_KTY as u64), kty);
+        if let Some(ref kid) = self.kid {
+            map.insert(CborType::Integer(COSE_KEY_KID as u64), CborType::Bytes(kid.clone()));
+        }
+        if let Some(ref alg) = self.alg {
+            map.insert(CborType::Integer(COSE_KEY_ALG as u64), alg.to_cbor());
+        }
+        if !self.key_ops.is_empty() {
+            let operations = self.key_ops.iter().map(|op| op.to_label().to_cbor()).collect();
+            map.insert(CborType::Integer(COSE_KEY_KEY_OPS as u64), CborType::Array(operations));
+        }
+        if let Some(ref base_iv) = self.base_iv {
+            let value = CborType::Bytes(base_iv.clone());
+            map.insert(CborType::Integer(COSE_KEY_BASE_IV as u64), value);
+        }
+
+        let mut params: Vec<(i64, &Vec<u8>)> = Vec::new();
+        match self.params {
+            KeyParameters::Ec2 { crv, ref x, ref y, ref d } => {
+                map.insert(Label::Int(COSE_KEY_CRV).to_cbor(), CborType::Integer(crv.id() as u64));
+                params.extend(x.iter().map(|x| (COSE_KEY_X, x)));
+                params.extend(y.iter().map(|y| (COSE_KEY_Y, y)));
+                params.extend(d.iter().map(|d| (COSE_KEY_D, d)));
+            }
+            KeyParameters::Okp { crv, ref x, ref d } => {
+                map.insert(Label::Int(COSE_KEY_CRV).to_cbor(), CborType::Integer(crv.id() as u64));
+                params.extend(x.iter().map(|x| (COSE_KEY_X, x)));
+                params.extend(d.iter().map(|d| (COSE_KEY_D, d)));
+            }
+            KeyParameters::Rsa { ref n, ref e, ref private } => {
+                params.extend_from_slice(&[(COSE_KEY_RSA_N, n), (COSE_KEY_RSA_E, e)]);
+                if let Some(ref private) = *private {
+                    params.extend_from_slice(&[(COSE_KEY_RSA_D, &private.d),
+                                               (COSE_KEY_RSA_P, &private.p),
+                                               (COSE_KEY_RSA_Q, &private.q),
+                                               (COSE_KEY_RSA_DP, &private.dp),
+                                               (COSE_KEY_RSA_DQ, &private.dq),
+                                               (COSE_KEY_RSA_QINV, &private.qinv)]);
+                }
+            }
+            KeyParameters::Symmetric { ref k } => params.push((COSE_KEY_K, k)),
+        }
+        for (label, value) in params {
+            map.insert(Label::Int(label).to_cbor(), CborType::Bytes(value.clone()));
+        }
+        Ok(map)
+    }
+
+    /// Validate and encode the key.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CoseError> {
+        Ok(CborType::Map(self.to_map()?).serialize())
+    }
+
+    /// The input of the COSE Key Thumbprint, see RFC 9679 section 3. This is the deterministic
+    /// encoding of a map with only the required parameters of the key type: kty and the public
+    /// key, i.e. crv and x (and y for EC2 keys), n and e for RSA keys, or k for symmetric keys.
+    /// Private keys without the public key have no thumbprint and fail with `MalformedInput`.
+    pub fn thumbprint_input(&self) -> Result<Vec<u8>, CoseError> {
+        self.validate()?;
+        let mut map: BTreeMap<CborType, CborType> = BTreeMap::new();
+        let kty = CborType::Integer(self.kty().id() as u64);
+        map.insert(CborType::Integer(COSE_KEY_KTY as u64), kty);
+        let mut params: Vec<(i64, &Vec<u8>)> = Vec::new();
+        match self.params {
+            KeyParameters::Ec2 { crv, ref x, ref y, .. } => {
+                map.insert(Label::Int(COSE_KEY_CRV).to_cbor(), CborType::Integer(crv.id() as u64));
+                match (x.as_ref(), y.as_ref()) {
+                    (Some(x), Some(y)) => {
+                        params.extend_from_slice(&[(COSE_KEY_X, x), (COSE_KEY_Y, y)])
+                    }
+                    _ => return Err(CoseError::MalformedInput),
+                }
+            }
+            KeyParameters::Okp { crv, ref x, .. } => {
+                map.insert(Label::Int(COSE_KEY_CRV).to_cbor(), CborType::Integer(crv.id() as u64));
+                params.push((COSE_KEY_X, x.as_ref().ok_or(CoseError::MalformedInput)?));
+            }
+            KeyParameters::Rsa { ref n, ref e, .. } => {
+                params.extend_from_slice(&[(COSE_KEY_RSA_N, n), (COSE_KEY_RSA_E, e)]);
+            }
+            KeyParameters::Symmetric { ref k } => params.push((COSE_KEY_K, k)),
+        }
+        for (label, value) in params {
+            map.insert(Label::Int(label).to_cbor(), CborType::Bytes(value.clone()));
+        }
+        // The labels all encode to a single byte, so the canonical order of the encoder is the
+        // bytewise lexicographic order that deterministic encoding requires.
+        Ok(CborType::Map(map).serialize())
+    }
+
+    /// Compute the COSE Key Thumbprint (RFC 9679) with the given hash function, e.g. SHA-256.
+    pub fn thumbprint<F>(&self, hash: F) -> Result<Vec<u8>, CoseError>
+    where
+        F: FnOnce(&[u8]) -> Vec<u8>,
+    {
+        Ok(hash(&self.thumbprint_input()?))
+    }
+
+    /// Set the kid of the key to its thumbprint, which identifies the key by its content.
+    pub fn set_thumbprint_kid<F>(&mut self, hash: F) -> Result<(), CoseError>
+    where
+        F: FnOnce(&[u8]) -> Vec<u8>,
+    {
+        self.kid = Some(self.thumbprint(hash)?);
+        Ok(())
+    }
+}
+
+/// A COSE_KeySet, a non-empty array of COSE_Key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoseKeySet {
+    pub keys: Vec<CoseKey>,
+}
+
+impl CoseKeySet {
+    /// Decode a key set and validate all of its keys.
+    pub fn from_bytes(bytes: &[u8]) -> Result<CoseKeySet, CoseError> {
+        let keys = match decode(bytes) {
+            Ok(CborType::Array(keys)) => keys,
+            Ok(_) => return Err(CoseError::UnexpectedType),
+            Err(_) => return Err(CoseError::DecodingFailure),
+        };
+        if keys.is_empty() {
+            return Err(CoseError::MalformedInput);
+        }
+        let mut key_set = CoseKeySet { keys: Vec::new() };
+        for key in keys {
+            match key {
+                CborType::Map(ref map) => key_set.keys.push(CoseKey::from_map(map)?),
+                _ => return Err(CoseError::UnexpectedType),
+            }
+        }
+        Ok(key_set)
+    }
+
+    /// Validate and encode the key set.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CoseError> {
+        if self.keys.is_empty() {
+            return Err(CoseError::MalformedInput);
+        }
+        let mut keys = Vec::new();
+        for key in &self.keys {
+            keys.push(CborType::Map(key.to_map()?));
+        }
+        Ok(CborType::Array(keys).serialize())
+    }
+
+    /// Find the keys with the given key identifier.
+    pub fn find_by_kid(&self, kid: &[u8]) -> Vec<&CoseKey> {
+        self.keys
+            .iter()
+            .filter(|key| key.kid.as_deref() == Some(kid))
+            .collect()
+    }
+}
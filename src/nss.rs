@@ -0,0 +1,574 @@
+//! Signing and verification with [NSS](https://github.com/nss-dev/nss/). This is enabled with the
+//! `nss` feature.
+//!
+//! NSS has to be initialized by the caller, e.g. with `NSS_NoDB_Init`. Public keys are read from
+//! DER encoded certificates and private keys from PKCS#8. ES256K, EdDSA and HMAC are not
+//! supported.
+
+use std::marker::PhantomData;
+use std::{mem, ptr, slice};
+use std::os::raw;
+use std::os::raw::c_char;
+use {CoseError, SignatureAlgorithm, Signer, Verifier};
+use algorithm;
+use algorithm::{Curve, SignatureScheme};
+
+type SECItemType = raw::c_uint; // TODO: actually an enum - is this the right size?
+const SI_BUFFER: SECItemType = 0; // called siBuffer in NSS
+
+/// A SECItem pointing to data that is only read by NSS. It borrows the data, so it can't outlive
+/// it.
+#[repr(C)]
+struct SECItem<'a> {
+    typ: SECItemType,
+    data: *const u8,
+    len: raw::c_uint,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> SECItem<'a> {
+    fn maybe_new(data: &'a [u8]) -> Result<SECItem<'a>, NSSError> {
+        if data.len() > u32::MAX as usize {
+            return Err(NSSError::InputTooLarge);
+        }
+        Ok(SECItem {
+            typ: SI_BUFFER,
+            data: data.as_ptr(),
+            len: data.len() as u32,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Many NSS APIs take constant data input as SECItems. Some, however, output data as SECItems.
+/// To represent this, we define another type of mutable SECItem.
+#[repr(C)]
+struct SECItemMut<'a> {
+    typ: SECItemType,
+    data: *mut u8,
+    len: raw::c_uint,
+    _marker: PhantomData<&'a mut Vec<u8>>,
+}
+
+impl<'a> SECItemMut<'a> {
+    /// Given a mutable reference to a Vec<u8> that has a particular allocated capacity, create a
+    /// SECItemMut that points to the vec and has the same capacity.
+    /// The input vec is not expected to have any actual contents, and in any case is cleared.
+    fn maybe_from_empty_preallocated_vec(vec: &'a mut Vec<u8>) -> Result<SECItemMut<'a>, NSSError> {
+        if vec.capacity() > u32::MAX as usize {
+            return Err(NSSError::InputTooLarge);
+        }
+        vec.clear();
+        Ok(SECItemMut {
+            typ: SI_BUFFER,
+            data: vec.as_mut_ptr(),
+            len: vec.capacity() as u32,
+            _marker: PhantomData,
+        })
+    }
+}
+
+#[repr(C)]
+struct CkRsaPkcsPssParams {
+    // Called CK_RSA_PKCS_PSS_PARAMS in NSS
+    hash_alg: CkMechanismType, // Called hashAlg in NSS
+    mgf: CkRsaPkcsMgfType,
+    s_len: raw::c_ulong, // Called sLen in NSS
+}
+
+impl CkRsaPkcsPssParams {
+    /// The PSS parameters of a signature algorithm, or `None` if it doesn't use PSS. The salt is
+    /// as long as the hash and MGF1 uses the same hash.
+    fn from_algorithm(signature_algorithm: &SignatureAlgorithm) -> Option<CkRsaPkcsPssParams> {
+        let info = signature_algorithm.info();
+        if info.scheme != SignatureScheme::RsaPss {
+            return None;
+        }
+        let (hash_alg, mgf, s_len) = match info.hash {
+            Some(algorithm::HashAlgorithm::SHA256) => (CKM_SHA256, CKG_MGF1_SHA256, SHA256_LENGTH),
+            Some(algorithm::HashAlgorithm::SHA384) => (CKM_SHA384, CKG_MGF1_SHA384, SHA384_LENGTH),
+            Some(algorithm::HashAlgorithm::SHA512) => (CKM_SHA512, CKG_MGF1_SHA512, SHA512_LENGTH),
+            None => return None,
+        };
+        Some(CkRsaPkcsPssParams {
+            hash_alg,
+            mgf,
+            s_len: s_len as raw::c_ulong,
+        })
+    }
+
+    fn get_params_item<'a>(&'a self) -> Result<SECItem<'a>, NSSError> {
+        // This isn't entirely NSS' fault, but it mostly is.
+        let params_ptr: *const CkRsaPkcsPssParams = self;
+        let params = unsafe {
+            slice::from_raw_parts(params_ptr as *const u8, mem::size_of::<CkRsaPkcsPssParams>())
+        };
+        SECItem::maybe_new(params)
+    }
+}
+
+type CkMechanismType = raw::c_ulong; // called CK_MECHANISM_TYPE in NSS
+const CKM_ECDSA: CkMechanismType = 0x0000_1041;
+const CKM_RSA_PKCS: CkMechanismType = 0x0000_0001;
+const CKM_RSA_PKCS_PSS: CkMechanismType = 0x0000_000D;
+const CKM_SHA256: CkMechanismType = 0x0000_0250;
+const CKM_SHA384: CkMechanismType = 0x0000_0260;
+const CKM_SHA512: CkMechanismType = 0x0000_0270;
+
+type CkRsaPkcsMgfType = raw::c_ulong; // called CK_RSA_PKCS_MGF_TYPE in NSS
+const CKG_MGF1_SHA256: CkRsaPkcsMgfType = 0x0000_0002;
+const CKG_MGF1_SHA384: CkRsaPkcsMgfType = 0x0000_0003;
+const CKG_MGF1_SHA512: CkRsaPkcsMgfType = 0x0000_0004;
+
+type SECStatus = raw::c_int; // TODO: enum - right size?
+const SEC_SUCCESS: SECStatus = 0; // Called SECSuccess in NSS
+const SEC_FAILURE: SECStatus = -1; // Called SECFailure in NSS
+
+type KeyType = raw::c_uint; // called KeyType in NSS
+const RSA_KEY: KeyType = 1; // called rsaKey in NSS
+const EC_KEY: KeyType = 6; // called ecKey in NSS
+const RSA_PSS_KEY: KeyType = 7; // called rsaPssKey in NSS
+
+enum SECKEYPublicKey {}
+enum SECKEYPrivateKey {}
+enum PK11SlotInfo {}
+enum CERTCertificate {}
+enum CERTCertDBHandle {}
+
+const SHA256_LENGTH: usize = 32;
+const SHA384_LENGTH: usize = 48;
+const SHA512_LENGTH: usize = 64;
+
+// TODO: ugh this will probably have a platform-specific name...
+#[link(name = "nss3")]
+extern "C" {
+    fn PK11_HashBuf(
+        hashAlg: HashAlgorithm,
+        out: *mut u8,
+        data_in: *const u8, // called "in" in NSS
+        len: raw::c_int,
+    ) -> SECStatus;
+    fn PK11_VerifyWithMechanism(
+        key: *const SECKEYPublicKey,
+        mechanism: CkMechanismType,
+        param: *const SECItem,
+        sig: *const SECItem,
+        hash: *const SECItem,
+        wincx: *const raw::c_void,
+    ) -> SECStatus;
+
+    fn SECKEY_DestroyPublicKey(pubk: *mut SECKEYPublicKey);
+    fn SECKEY_PublicKeyStrengthInBits(pubk: *const SECKEYPublicKey) -> raw::c_uint;
+    fn SECKEY_GetPublicKeyType(pubk: *const SECKEYPublicKey) -> KeyType;
+    fn SECKEY_SignatureLen(pubk: *const SECKEYPublicKey) -> raw::c_uint;
+    fn SECKEY_DestroyPrivateKey(privk: *mut SECKEYPrivateKey);
+    fn SECKEY_GetPrivateKeyType(privKey: *const SECKEYPrivateKey) -> KeyType;
+
+    fn CERT_GetDefaultCertDB() -> *const CERTCertDBHandle;
+    fn CERT_DestroyCertificate(cert: *mut CERTCertificate);
+    fn CERT_NewTempCertificate(
+        handle: *const CERTCertDBHandle,
+        derCert: *const SECItem,
+        nickname: *const c_char,
+        isperm: bool,
+        copyDER: bool,
+    ) -> *mut CERTCertificate;
+    fn CERT_ExtractPublicKey(cert: *const CERTCertificate) -> *mut SECKEYPublicKey;
+
+    fn PK11_ImportDERPrivateKeyInfoAndReturnKey(
+        slot: *mut PK11SlotInfo,
+        derPKI: *const SECItem,
+        nickname: *const SECItem,
+        publicValue: *const SECItem,
+        isPerm: bool,
+        isPrivate: bool,
+        keyUsage: u32,
+        privk: *mut *mut SECKEYPrivateKey,
+        wincx: *const u8,
+    ) -> SECStatus;
+    fn PK11_GetInternalSlot() -> *mut PK11SlotInfo;
+    fn PK11_FreeSlot(slot: *mut PK11SlotInfo);
+    fn PK11_SignatureLen(key: *const SECKEYPrivateKey) -> raw::c_int;
+    fn PK11_SignWithMechanism(
+        key: *const SECKEYPrivateKey,
+        mech: CkMechanismType,
+        param: *const SECItem,
+        sig: *mut SECItemMut,
+        hash: *const SECItem,
+    ) -> SECStatus;
+}
+
+/// An error type describing errors that may be encountered during verification.
+#[derive(Debug, PartialEq)]
+pub enum NSSError {
+    ImportCertError,
+    DecodingPKCS8Failed,
+    InputTooLarge,
+    LibraryFailure,
+    SignatureVerificationFailed,
+    SigningFailed,
+    ExtractPublicKeyFailed,
+    UnsupportedAlgorithm,
+    KeyTooSmall,
+    KeyMismatch,
+}
+
+impl From<NSSError> for CoseError {
+    fn from(error: NSSError) -> CoseError {
+        match error {
+            NSSError::ImportCertError |
+            NSSError::DecodingPKCS8Failed |
+            NSSError::ExtractPublicKeyFailed => CoseError::DecodingFailure,
+            NSSError::InputTooLarge => CoseError::InvalidArgument,
+            NSSError::LibraryFailure => CoseError::LibraryFailure,
+            NSSError::SignatureVerificationFailed => CoseError::VerificationFailed,
+            NSSError::SigningFailed => CoseError::SigningFailed,
+            NSSError::UnsupportedAlgorithm => CoseError::UnknownSignatureScheme,
+            NSSError::KeyTooSmall | NSSError::KeyMismatch => CoseError::InvalidArgument,
+        }
+    }
+}
+
+/// A reference to a PKCS#11 slot that is freed when dropped.
+struct Slot(*mut PK11SlotInfo);
+
+impl Slot {
+    fn internal() -> Result<Slot, NSSError> {
+        let slot = unsafe { PK11_GetInternalSlot() };
+        if slot.is_null() {
+            return Err(NSSError::LibraryFailure);
+        }
+        Ok(Slot(slot))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        unsafe { PK11_FreeSlot(self.0) };
+    }
+}
+
+/// A temporary certificate that is destroyed when dropped.
+struct Certificate(*mut CERTCertificate);
+
+impl Certificate {
+    /// Import a DER encoded certificate into NSS.
+    fn from_der(cert: &[u8]) -> Result<Certificate, NSSError> {
+        let der_cert = SECItem::maybe_new(cert)?;
+        let db_handle = unsafe { CERT_GetDefaultCertDB() };
+        if db_handle.is_null() {
+            // TODO #28
+            return Err(NSSError::LibraryFailure);
+        }
+        let nss_cert =
+            unsafe { CERT_NewTempCertificate(db_handle, &der_cert, ptr::null(), false, true) };
+        if nss_cert.is_null() {
+            return Err(NSSError::ImportCertError);
+        }
+        Ok(Certificate(nss_cert))
+    }
+
+    fn public_key(&self) -> Result<PublicKey, NSSError> {
+        let key = unsafe { CERT_ExtractPublicKey(self.0) };
+        if key.is_null() {
+            return Err(NSSError::ExtractPublicKeyFailed);
+        }
+        Ok(PublicKey(key))
+    }
+}
+
+impl Drop for Certificate {
+    fn drop(&mut self) {
+        unsafe { CERT_DestroyCertificate(self.0) };
+    }
+}
+
+/// A public key that is destroyed when dropped.
+struct PublicKey(*mut SECKEYPublicKey);
+
+impl Drop for PublicKey {
+    fn drop(&mut self) {
+        unsafe { SECKEY_DestroyPublicKey(self.0) };
+    }
+}
+
+/// A private key that is destroyed when dropped.
+struct PrivateKey(*mut SECKEYPrivateKey);
+
+impl PrivateKey {
+    /// Import a PKCS#8 encoded private key into the given slot. The key is not stored
+    /// permanently.
+    fn from_pkcs8(slot: &Slot, pkcs8: &[u8]) -> Result<PrivateKey, NSSError> {
+        let pkcs8item = SECItem::maybe_new(pkcs8)?;
+        let mut key: *mut SECKEYPrivateKey = ptr::null_mut();
+        let ku_all = 0xFF;
+        let rv = unsafe {
+            PK11_ImportDERPrivateKeyInfoAndReturnKey(
+                slot.0,
+                &pkcs8item,
+                ptr::null(),
+                ptr::null(),
+                false,
+                false,
+                ku_all,
+                &mut key,
+                ptr::null(),
+            )
+        };
+        if rv != SEC_SUCCESS || key.is_null() {
+            return Err(NSSError::DecodingPKCS8Failed);
+        }
+        Ok(PrivateKey(key))
+    }
+}
+
+impl Drop for PrivateKey {
+    fn drop(&mut self) {
+        unsafe { SECKEY_DestroyPrivateKey(self.0) };
+    }
+}
+
+// https://searchfox.org/nss/rev/990c2e793aa731cd66238c6c4f00b9473943bc66/lib/util/secoidt.h#274
+#[derive(Debug, PartialEq, Clone)]
+#[repr(C)]
+enum HashAlgorithm {
+    SHA256 = 191,
+    SHA384 = 192,
+    SHA512 = 193,
+}
+
+fn hash(payload: &[u8], signature_algorithm: &SignatureAlgorithm) -> Result<Vec<u8>, NSSError> {
+    if payload.len() > raw::c_int::MAX as usize {
+        return Err(NSSError::InputTooLarge);
+    }
+    let (hash_algorithm, digest_length) = match signature_algorithm.info().hash {
+        Some(algorithm::HashAlgorithm::SHA256) => (HashAlgorithm::SHA256, SHA256_LENGTH),
+        Some(algorithm::HashAlgorithm::SHA384) => (HashAlgorithm::SHA384, SHA384_LENGTH),
+        Some(algorithm::HashAlgorithm::SHA512) => (HashAlgorithm::SHA512, SHA512_LENGTH),
+        None => return Err(NSSError::UnsupportedAlgorithm),
+    };
+    let mut hash_buf = vec![0; digest_length];
+    let len: raw::c_int = payload.len() as raw::c_int;
+    let hash_result =
+        unsafe { PK11_HashBuf(hash_algorithm, hash_buf.as_mut_ptr(), payload.as_ptr(), len) };
+    if hash_result != SEC_SUCCESS {
+        return Err(NSSError::LibraryFailure);
+    }
+    Ok(hash_buf)
+}
+
+// The DER encoded DigestInfo of RFC 8017 section 9.2 without the hash, which follows it.
+const SHA256_DIGEST_INFO_PREFIX: [u8; 19] = [0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48,
+                                             0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05, 0x00, 0x04,
+                                             0x20];
+const SHA384_DIGEST_INFO_PREFIX: [u8; 19] = [0x30, 0x41, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48,
+                                             0x01, 0x65, 0x03, 0x04, 0x02, 0x02, 0x05, 0x00, 0x04,
+                                             0x30];
+const SHA512_DIGEST_INFO_PREFIX: [u8; 19] = [0x30, 0x51, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48,
+                                             0x01, 0x65, 0x03, 0x04, 0x02, 0x03, 0x05, 0x00, 0x04,
+                                             0x40];
+
+/// Hash the payload into the input of the signature mechanism. CKM_RSA_PKCS signs its input as
+/// is, so for RSASSA-PKCS1-v1_5 the hash is wrapped in a DigestInfo.
+fn hash_for_signature(
+    payload: &[u8],
+    signature_algorithm: &SignatureAlgorithm,
+) -> Result<Vec<u8>, NSSError> {
+    let hash_buf = hash(payload, signature_algorithm)?;
+    let info = signature_algorithm.info();
+    if info.scheme != SignatureScheme::RsaPkcs1v15 {
+        return Ok(hash_buf);
+    }
+    let prefix: &[u8] = match info.hash {
+        Some(algorithm::HashAlgorithm::SHA256) => &SHA256_DIGEST_INFO_PREFIX,
+        Some(algorithm::HashAlgorithm::SHA384) => &SHA384_DIGEST_INFO_PREFIX,
+        Some(algorithm::HashAlgorithm::SHA512) => &SHA512_DIGEST_INFO_PREFIX,
+        None => return Err(NSSError::UnsupportedAlgorithm),
+    };
+    let mut digest_info = prefix.to_vec();
+    digest_info.extend_from_slice(&hash_buf);
+    Ok(digest_info)
+}
+
+/// Get the mechanism for a signature algorithm. ES256K and EdDSA are not supported.
+fn get_mechanism(signature_algorithm: &SignatureAlgorithm) -> Result<CkMechanismType, NSSError> {
+    let info = signature_algorithm.info();
+    match info.scheme {
+        SignatureScheme::Ecdsa if info.curve == Some(Curve::Secp256k1) => {
+            Err(NSSError::UnsupportedAlgorithm)
+        }
+        SignatureScheme::Ecdsa => Ok(CKM_ECDSA),
+        SignatureScheme::RsaPss => Ok(CKM_RSA_PKCS_PSS),
+        SignatureScheme::RsaPkcs1v15 => Ok(CKM_RSA_PKCS),
+        SignatureScheme::EdDSA => Err(NSSError::UnsupportedAlgorithm),
+    }
+}
+
+/// Given a signature algorithm, the bytes of a DER encoded certificate, a payload, and a signature
+/// over the payload, returns a result based on the outcome of decoding the certificate and
+/// running the signature verification algorithm on the signed data.
+fn verify_with_cert(
+    signature_algorithm: &SignatureAlgorithm,
+    cert: &[u8],
+    payload: &[u8],
+    signature: &[u8],
+) -> Result<(), NSSError> {
+    let mechanism = get_mechanism(signature_algorithm)?;
+    let hash_buf = hash_for_signature(payload, signature_algorithm)?;
+    let hash_item = SECItem::maybe_new(hash_buf.as_slice())?;
+
+    // Import DER cert into NSS.
+    let nss_cert = Certificate::from_der(cert)?;
+    let key = nss_cert.public_key()?;
+    let key_type = unsafe { SECKEY_GetPublicKeyType(key.0) };
+    let signature_len = unsafe { SECKEY_SignatureLen(key.0) };
+    if !key_fits(signature_algorithm, key_type, signature_len as usize) {
+        return Err(NSSError::SignatureVerificationFailed);
+    }
+    if let Some(min_key_size) = signature_algorithm.info().min_key_size {
+        let key_size = unsafe { SECKEY_PublicKeyStrengthInBits(key.0) };
+        if (key_size as usize) < min_key_size {
+            return Err(NSSError::SignatureVerificationFailed);
+        }
+    }
+
+    let signature_item = SECItem::maybe_new(signature)?;
+    let rsa_pss_params = CkRsaPkcsPssParams::from_algorithm(signature_algorithm);
+    let rsa_pss_params_item = match rsa_pss_params {
+        Some(ref params) => Some(params.get_params_item()?),
+        None => None,
+    };
+    let params_item: *const SECItem = match rsa_pss_params_item {
+        Some(ref item) => item,
+        None => ptr::null(),
+    };
+    let null_cx_ptr: *const raw::c_void = ptr::null();
+    let result = unsafe {
+        PK11_VerifyWithMechanism(
+            key.0,
+            mechanism,
+            params_item,
+            &signature_item,
+            &hash_item,
+            null_cx_ptr,
+        )
+    };
+    match result {
+        SEC_SUCCESS => Ok(()),
+        SEC_FAILURE => Err(NSSError::SignatureVerificationFailed),
+        _ => Err(NSSError::LibraryFailure),
+    }
+}
+
+/// Returns true if a key of the given type is of the type the signature algorithm uses. NSS
+/// doesn't tell the curve of EC keys directly, but it follows from the length of their
+/// signatures, which hold two coordinates.
+fn key_fits(
+    signature_algorithm: &SignatureAlgorithm,
+    key_type: KeyType,
+    signature_len: usize,
+) -> bool {
+    let info = signature_algorithm.info();
+    match info.scheme {
+        SignatureScheme::Ecdsa => {
+            match info.curve {
+                Some(curve) => key_type == EC_KEY && signature_len == 2 * curve.key_size(),
+                None => false,
+            }
+        }
+        SignatureScheme::RsaPss => key_type == RSA_KEY || key_type == RSA_PSS_KEY,
+        SignatureScheme::RsaPkcs1v15 => key_type == RSA_KEY,
+        SignatureScheme::EdDSA => false,
+    }
+}
+
+/// Sign the payload with the key. Fails with `KeyMismatch` if the key doesn't fit the algorithm
+/// and with `KeyTooSmall` if it is too small for it.
+fn sign_with_key(
+    signature_algorithm: &SignatureAlgorithm,
+    key: &PrivateKey,
+    payload: &[u8],
+) -> Result<Vec<u8>, NSSError> {
+    let mechanism = get_mechanism(signature_algorithm)?;
+    let rsa_pss_params = CkRsaPkcsPssParams::from_algorithm(signature_algorithm);
+    let rsa_pss_params_item = match rsa_pss_params {
+        Some(ref params) => Some(params.get_params_item()?),
+        None => None,
+    };
+    let params_item: *const SECItem = match rsa_pss_params_item {
+        Some(ref item) => item,
+        None => ptr::null(),
+    };
+    let signature_len = unsafe { PK11_SignatureLen(key.0) };
+    if signature_len <= 0 {
+        return Err(NSSError::LibraryFailure);
+    }
+    let signature_len = signature_len as usize;
+    let key_type = unsafe { SECKEY_GetPrivateKeyType(key.0) };
+    if !key_fits(signature_algorithm, key_type, signature_len) {
+        return Err(NSSError::KeyMismatch);
+    }
+    // RSA signatures are as long as the modulus.
+    if let Some(min_key_size) = signature_algorithm.info().min_key_size {
+        if signature_len * 8 < min_key_size {
+            return Err(NSSError::KeyTooSmall);
+        }
+    }
+    // Allocate enough space for the signature.
+    let mut signature: Vec<u8> = Vec::with_capacity(signature_len);
+    let hash_buf = hash_for_signature(payload, signature_algorithm)?;
+    let hash_item = SECItem::maybe_new(hash_buf.as_slice())?;
+    {
+        // Get a mutable SECItem on the preallocated signature buffer. PK11_SignWithMechanism will
+        // fill the SECItem's buf with the bytes of the signature.
+        let mut signature_item = SECItemMut::maybe_from_empty_preallocated_vec(&mut signature)?;
+        let rv = unsafe {
+            PK11_SignWithMechanism(key.0, mechanism, params_item, &mut signature_item, &hash_item)
+        };
+        if rv != SEC_SUCCESS || signature_item.len as usize != signature_len {
+            return Err(NSSError::SigningFailed);
+        }
+    }
+    unsafe {
+        // Now that the bytes of the signature have been filled out, set its length.
+        signature.set_len(signature_len);
+    }
+    Ok(signature)
+}
+
+/// A `Signer` holding a private key that was imported into NSS.
+pub struct NssSigner {
+    key: PrivateKey,
+}
+
+impl NssSigner {
+    /// Import a PKCS#8 encoded private key into the internal slot.
+    pub fn from_pkcs8(pkcs8: &[u8]) -> Result<NssSigner, CoseError> {
+        let slot = Slot::internal()?;
+        let key = PrivateKey::from_pkcs8(&slot, pkcs8)?;
+        Ok(NssSigner { key })
+    }
+}
+
+impl Signer for NssSigner {
+    /// Sign with the key. Returns `InvalidArgument` if the algorithm doesn't fit the key.
+    fn sign(&self, algorithm: SignatureAlgorithm, to_sign: &[u8]) -> Result<Vec<u8>, CoseError> {
+        Ok(sign_with_key(&algorithm, &self.key, to_sign)?)
+    }
+}
+
+/// A `Verifier` that reads the public key from a DER encoded certificate.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NssVerifier;
+
+impl Verifier for NssVerifier {
+    fn verify(
+        &self,
+        algorithm: SignatureAlgorithm,
+        key: &[u8],
+        to_verify: &[u8],
+        signature: &[u8],
+    ) -> Result<(), CoseError> {
+        Ok(verify_with_cert(&algorithm, key, to_verify, signature)?)
+    }
+}
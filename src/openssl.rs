@@ -0,0 +1,247 @@
+//! Signing and verification with the [OpenSSL](https://www.openssl.org/) EVP interface. This is
+//! enabled with the `openssl` feature.
+//!
+//! Public keys are read from DER encoded certificates and private keys from PKCS#8. HMACs are
+//! computed with the raw symmetric key.
+
+use {CoseError, Mac, MacAlgorithm, SignatureAlgorithm, Signer, Verifier};
+use algorithm::{Curve, HashAlgorithm, SignatureScheme};
+use ossl::bn::{BigNum, BigNumContext, BigNumRef};
+use ossl::ec::EcGroup;
+use ossl::ecdsa::EcdsaSig;
+use ossl::error::ErrorStack;
+use ossl::hash::MessageDigest;
+use ossl::nid::Nid;
+use ossl::pkey::{HasPublic, Id, PKey, PKeyRef, Private};
+use ossl::rsa::Padding;
+use ossl::sign;
+use ossl::sign::RsaPssSaltlen;
+use ossl::x509::X509;
+
+/// The type of key a signature algorithm uses.
+enum KeyType {
+    /// An EC key on the given curve. ECDSA signatures are the concatenation of r and s, each
+    /// padded to the given size.
+    Ec(Nid, usize),
+    /// An RSA key, used with the given padding. With PSS, the salt is as long as the digest and
+    /// MGF1 uses the same digest.
+    Rsa(Padding),
+    /// An Ed25519 or Ed448 key. If the type is given, the key has to be of that type.
+    Ed(Option<Id>),
+}
+
+/// The OpenSSL digest of a hash algorithm.
+fn message_digest(hash: HashAlgorithm) -> MessageDigest {
+    match hash {
+        HashAlgorithm::SHA256 => MessageDigest::sha256(),
+        HashAlgorithm::SHA384 => MessageDigest::sha384(),
+        HashAlgorithm::SHA512 => MessageDigest::sha512(),
+    }
+}
+
+/// Get the digest and key type of a signature algorithm from the algorithm registry. EdDSA has
+/// no separate digest.
+fn get_parameters(algorithm: SignatureAlgorithm) -> (Option<MessageDigest>, KeyType) {
+    let info = algorithm.info();
+    let digest = info.hash.map(message_digest);
+    let key_type = match (info.scheme, info.curve) {
+        (SignatureScheme::Ecdsa, Some(Curve::P256)) => KeyType::Ec(Nid::X9_62_PRIME256V1, 32),
+        (SignatureScheme::Ecdsa, Some(Curve::P384)) => KeyType::Ec(Nid::SECP384R1, 48),
+        (SignatureScheme::Ecdsa, Some(Curve::P521)) => KeyType::Ec(Nid::SECP521R1, 66),
+        (SignatureScheme::Ecdsa, Some(Curve::Secp256k1)) => KeyType::Ec(Nid::SECP256K1, 32),
+        (SignatureScheme::RsaPss, _) => KeyType::Rsa(Padding::PKCS1_PSS),
+        (SignatureScheme::RsaPkcs1v15, _) => KeyType::Rsa(Padding::PKCS1),
+        (_, Some(Curve::Ed25519)) => KeyType::Ed(Some(Id::ED25519)),
+        (_, Some(Curve::Ed448)) => KeyType::Ed(Some(Id::ED448)),
+        _ => KeyType::Ed(None),
+    };
+    (digest, key_type)
+}
+
+/// Returns `s` if it is at most half the order `n` of the curve's group and `n - s` otherwise.
+/// Both are valid for the same ECDSA signature, see `SignatureAlgorithm` for when the low one is
+/// required.
+fn low_s(curve: Nid, s: &BigNumRef) -> Result<BigNum, ErrorStack> {
+    let group = EcGroup::from_curve_name(curve)?;
+    let mut order = BigNum::new()?;
+    let mut ctx = BigNumContext::new()?;
+    group.order(&mut order, &mut ctx)?;
+    let mut half_order = BigNum::new()?;
+    half_order.rshift1(&order)?;
+    if *s <= *half_order {
+        return s.to_owned();
+    }
+    let mut result = BigNum::new()?;
+    result.checked_sub(&order, s)?;
+    Ok(result)
+}
+
+/// Returns true if signatures on the curve must have a low S value.
+fn requires_low_s(curve: Nid) -> bool {
+    curve == Nid::SECP256K1
+}
+
+/// Returns true if the key is of the type the signature algorithm uses and large enough for it.
+fn key_fits<T: HasPublic>(
+    algorithm: SignatureAlgorithm,
+    key_type: &KeyType,
+    key: &PKeyRef<T>,
+) -> bool {
+    if let Some(min_key_size) = algorithm.info().min_key_size {
+        if (key.bits() as usize) < min_key_size {
+            return false;
+        }
+    }
+    match *key_type {
+        KeyType::Ec(curve, _) => {
+            match key.ec_key() {
+                Ok(ec_key) => ec_key.group().curve_name() == Some(curve),
+                Err(_) => false,
+            }
+        }
+        KeyType::Rsa(_) => key.id() == Id::RSA,
+        KeyType::Ed(Some(id)) => key.id() == id,
+        KeyType::Ed(None) => key.id() == Id::ED25519 || key.id() == Id::ED448,
+    }
+}
+
+/// A `Signer` holding a private key.
+pub struct OpensslSigner {
+    key: PKey<Private>,
+}
+
+impl OpensslSigner {
+    /// Read a PKCS#8 encoded private key. EC keys on P-256, P-384, P-521 and secp256k1, RSA keys,
+    /// and Ed25519 and Ed448 keys are supported.
+    pub fn from_pkcs8(pkcs8: &[u8]) -> Result<OpensslSigner, CoseError> {
+        let key = PKey::private_key_from_pkcs8(pkcs8).map_err(|_| CoseError::DecodingFailure)?;
+        Ok(OpensslSigner { key })
+    }
+}
+
+impl Signer for OpensslSigner {
+    /// Sign with the key. Returns `InvalidArgument` if the algorithm doesn't fit the key.
+    fn sign(&self, algorithm: SignatureAlgorithm, to_sign: &[u8]) -> Result<Vec<u8>, CoseError> {
+        let (digest, key_type) = get_parameters(algorithm);
+        if !key_fits(algorithm, &key_type, &self.key) {
+            return Err(CoseError::InvalidArgument);
+        }
+        let mut signer = match digest {
+            Some(digest) => sign::Signer::new(digest, &self.key),
+            None => sign::Signer::new_without_digest(&self.key),
+        }.map_err(|_| CoseError::LibraryFailure)?;
+        if let KeyType::Rsa(padding) = key_type {
+            signer.set_rsa_padding(padding).map_err(|_| CoseError::LibraryFailure)?;
+        }
+        if let (KeyType::Rsa(Padding::PKCS1_PSS), Some(digest)) = (&key_type, digest) {
+            signer
+                .set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)
+                .and_then(|_| signer.set_rsa_mgf1_md(digest))
+                .map_err(|_| CoseError::LibraryFailure)?;
+        }
+        let signature =
+            signer.sign_oneshot_to_vec(to_sign).map_err(|_| CoseError::SigningFailed)?;
+        match key_type {
+            KeyType::Ec(curve, size) => {
+                // OpenSSL produces DER encoded ECDSA signatures.
+                let signature =
+                    EcdsaSig::from_der(&signature).map_err(|_| CoseError::SigningFailed)?;
+                let mut r = signature
+                    .r()
+                    .to_vec_padded(size as i32)
+                    .map_err(|_| CoseError::SigningFailed)?;
+                let s = if requires_low_s(curve) {
+                    low_s(curve, signature.s()).and_then(|s| s.to_vec_padded(size as i32))
+                } else {
+                    signature.s().to_vec_padded(size as i32)
+                }.map_err(|_| CoseError::SigningFailed)?;
+                r.extend_from_slice(&s);
+                Ok(r)
+            }
+            KeyType::Rsa(_) | KeyType::Ed(_) => Ok(signature),
+        }
+    }
+}
+
+/// A `Verifier` that reads the public key from a DER encoded certificate.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OpensslVerifier;
+
+impl Verifier for OpensslVerifier {
+    /// Verify with the public key of the certificate `key`. A signature that doesn't match, or a
+    /// key that doesn't fit the algorithm, fails with `VerificationFailed`.
+    fn verify(
+        &self,
+        algorithm: SignatureAlgorithm,
+        key: &[u8],
+        to_verify: &[u8],
+        signature: &[u8],
+    ) -> Result<(), CoseError> {
+        let cert = X509::from_der(key).map_err(|_| CoseError::DecodingFailure)?;
+        let public_key = cert.public_key().map_err(|_| CoseError::DecodingFailure)?;
+        let (digest, key_type) = get_parameters(algorithm);
+        if !key_fits(algorithm, &key_type, &public_key) {
+            return Err(CoseError::VerificationFailed);
+        }
+        let signature = match key_type {
+            KeyType::Ec(curve, size) => {
+                // OpenSSL expects DER encoded ECDSA signatures.
+                if signature.len() != 2 * size {
+                    return Err(CoseError::VerificationFailed);
+                }
+                let r = BigNum::from_slice(&signature[..size])
+                    .map_err(|_| CoseError::LibraryFailure)?;
+                let s = BigNum::from_slice(&signature[size..])
+                    .map_err(|_| CoseError::LibraryFailure)?;
+                if requires_low_s(curve) &&
+                    low_s(curve, &s).map_err(|_| CoseError::LibraryFailure)? != s
+                {
+                    return Err(CoseError::VerificationFailed);
+                }
+                EcdsaSig::from_private_components(r, s)
+                    .and_then(|signature| signature.to_der())
+                    .map_err(|_| CoseError::LibraryFailure)?
+            }
+            KeyType::Rsa(_) | KeyType::Ed(_) => signature.to_vec(),
+        };
+        let mut verifier = match digest {
+            Some(digest) => sign::Verifier::new(digest, &public_key),
+            None => sign::Verifier::new_without_digest(&public_key),
+        }.map_err(|_| CoseError::LibraryFailure)?;
+        if let KeyType::Rsa(padding) = key_type {
+            verifier.set_rsa_padding(padding).map_err(|_| CoseError::LibraryFailure)?;
+        }
+        if let (KeyType::Rsa(Padding::PKCS1_PSS), Some(digest)) = (&key_type, digest) {
+            verifier
+                .set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)
+                .and_then(|_| verifier.set_rsa_mgf1_md(digest))
+                .map_err(|_| CoseError::LibraryFailure)?;
+        }
+        match verifier.verify_oneshot(&signature, to_verify) {
+            Ok(true) => Ok(()),
+            _ => Err(CoseError::VerificationFailed),
+        }
+    }
+}
+
+/// A `Mac` holding a symmetric key.
+pub struct OpensslMac {
+    key: PKey<Private>,
+}
+
+impl OpensslMac {
+    /// Use the given symmetric key, e.g. the k parameter of a COSE key.
+    pub fn new(key: &[u8]) -> Result<OpensslMac, CoseError> {
+        let key = PKey::hmac(key).map_err(|_| CoseError::LibraryFailure)?;
+        Ok(OpensslMac { key })
+    }
+}
+
+impl Mac for OpensslMac {
+    fn mac(&self, algorithm: MacAlgorithm, to_mac: &[u8]) -> Result<Vec<u8>, CoseError> {
+        let digest = message_digest(algorithm.info().hash);
+        sign::Signer::new(digest, &self.key)
+            .and_then(|mut signer| signer.sign_oneshot_to_vec(to_mac))
+            .map_err(|_| CoseError::LibraryFailure)
+    }
+}
@@ -0,0 +1,278 @@
+//! Signing and verification in pure Rust with the [RustCrypto](https://github.com/RustCrypto)
+//! crates. This needs no system libraries and is enabled with the `rustcrypto` feature.
+//!
+//! Public keys are read from DER encoded certificates and private keys from PKCS#8. HMACs are
+//! computed with the raw symmetric key. Ed448 is not supported.
+
+use {CoseError, Mac, MacAlgorithm, SignatureAlgorithm, Signer, Verifier};
+use algorithm::HashAlgorithm;
+use algorithm::SignatureScheme;
+use hmac::{Hmac, Mac as HmacMac};
+use hmac::digest::KeyInit;
+use p256::ecdsa::signature::{Signer as EcdsaSigner, Verifier as EcdsaVerifier};
+use p256::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rand_core::OsRng;
+use rsa::{Pkcs1v15Sign, Pss, RsaPrivateKey, RsaPublicKey};
+use rsa::traits::PublicKeyParts;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use sha2::digest::DynDigest;
+use sha2::digest::const_oid::AssociatedOid;
+use x509_cert::Certificate;
+use x509_cert::der::{Decode, Encode};
+
+enum PrivateKey {
+    P256(p256::ecdsa::SigningKey),
+    P384(p384::ecdsa::SigningKey),
+    P521(p521::ecdsa::SigningKey),
+    K256(k256::ecdsa::SigningKey),
+    Rsa(RsaPrivateKey),
+    Ed25519(ed25519_dalek::SigningKey),
+}
+
+/// Sign the digest of `to_sign` with an RSA key, using the padding of the algorithm. Keys that
+/// are too small for the algorithm are rejected.
+fn rsa_sign<D>(
+    algorithm: SignatureAlgorithm,
+    key: &RsaPrivateKey,
+    to_sign: &[u8],
+) -> Result<Vec<u8>, CoseError>
+where
+    D: 'static + Digest + DynDigest + AssociatedOid + Send + Sync,
+{
+    let info = algorithm.info();
+    if key.n().bits() < info.min_key_size.unwrap_or(0) {
+        return Err(CoseError::InvalidArgument);
+    }
+    let digest = D::digest(to_sign);
+    match info.scheme {
+        SignatureScheme::RsaPss => key.sign_with_rng(&mut OsRng, Pss::new::<D>(), &digest),
+        SignatureScheme::RsaPkcs1v15 => key.sign(Pkcs1v15Sign::new::<D>(), &digest),
+        _ => return Err(CoseError::InvalidArgument),
+    }.map_err(|_| CoseError::SigningFailed)
+}
+
+/// Verify an RSA signature over the digest of `to_verify` with the DER encoded
+/// SubjectPublicKeyInfo `public_key`, using the padding of the algorithm. Keys that are too small
+/// for the algorithm are rejected.
+fn rsa_verify<D>(
+    algorithm: SignatureAlgorithm,
+    public_key: &[u8],
+    to_verify: &[u8],
+    signature: &[u8],
+) -> Result<bool, CoseError>
+where
+    D: 'static + Digest + DynDigest + AssociatedOid + Send + Sync,
+{
+    let info = algorithm.info();
+    let key = RsaPublicKey::from_public_key_der(public_key)
+        .map_err(|_| CoseError::VerificationFailed)?;
+    if key.n().bits() < info.min_key_size.unwrap_or(0) {
+        return Ok(false);
+    }
+    let digest = D::digest(to_verify);
+    let result = match info.scheme {
+        SignatureScheme::RsaPss => key.verify(Pss::new::<D>(), &digest, signature),
+        SignatureScheme::RsaPkcs1v15 => key.verify(Pkcs1v15Sign::new::<D>(), &digest, signature),
+        _ => return Ok(false),
+    };
+    Ok(result.is_ok())
+}
+
+/// A `Signer` holding a private key.
+pub struct RustCryptoSigner {
+    key: PrivateKey,
+}
+
+impl RustCryptoSigner {
+    /// Read a PKCS#8 encoded private key. EC keys on P-256, P-384, P-521 and secp256k1, RSA keys,
+    /// and Ed25519 keys are supported.
+    pub fn from_pkcs8(pkcs8: &[u8]) -> Result<RustCryptoSigner, CoseError> {
+        let key = if let Ok(key) = p256::SecretKey::from_pkcs8_der(pkcs8) {
+            PrivateKey::P256(key.into())
+        } else if let Ok(key) = p384::SecretKey::from_pkcs8_der(pkcs8) {
+            PrivateKey::P384(key.into())
+        } else if let Ok(key) = p521::SecretKey::from_pkcs8_der(pkcs8) {
+            let key = p521::ecdsa::SigningKey::from_bytes(&key.to_bytes())
+                .map_err(|_| CoseError::DecodingFailure)?;
+            PrivateKey::P521(key)
+        } else if let Ok(key) = k256::SecretKey::from_pkcs8_der(pkcs8) {
+            PrivateKey::K256(key.into())
+        } else if let Ok(key) = RsaPrivateKey::from_pkcs8_der(pkcs8) {
+            PrivateKey::Rsa(key)
+        } else if let Ok(key) = ed25519_dalek::SigningKey::from_pkcs8_der(pkcs8) {
+            PrivateKey::Ed25519(key)
+        } else {
+            return Err(CoseError::DecodingFailure);
+        };
+        Ok(RustCryptoSigner { key })
+    }
+}
+
+impl Signer for RustCryptoSigner {
+    /// Sign with the key. Returns `InvalidArgument` if the algorithm doesn't fit the key.
+    fn sign(&self, algorithm: SignatureAlgorithm, to_sign: &[u8]) -> Result<Vec<u8>, CoseError> {
+        match (algorithm, &self.key) {
+            (SignatureAlgorithm::ES256, PrivateKey::P256(key)) => {
+                let signature: p256::ecdsa::Signature = key.sign(to_sign);
+                Ok(signature.to_vec())
+            }
+            (SignatureAlgorithm::ES384, PrivateKey::P384(key)) => {
+                let signature: p384::ecdsa::Signature = key.sign(to_sign);
+                Ok(signature.to_vec())
+            }
+            (SignatureAlgorithm::ES512, PrivateKey::P521(key)) => {
+                let signature: p521::ecdsa::Signature = key.sign(to_sign);
+                Ok(signature.to_vec())
+            }
+            (SignatureAlgorithm::ES256K, PrivateKey::K256(key)) => {
+                // The signature is normalized to the low S value.
+                let signature: k256::ecdsa::Signature = key.sign(to_sign);
+                Ok(signature.to_vec())
+            }
+            (SignatureAlgorithm::PS256, PrivateKey::Rsa(key)) => {
+                rsa_sign::<Sha256>(algorithm, key, to_sign)
+            }
+            (SignatureAlgorithm::PS384, PrivateKey::Rsa(key)) => {
+                rsa_sign::<Sha384>(algorithm, key, to_sign)
+            }
+            (SignatureAlgorithm::PS512, PrivateKey::Rsa(key)) => {
+                rsa_sign::<Sha512>(algorithm, key, to_sign)
+            }
+            (SignatureAlgorithm::RS256, PrivateKey::Rsa(key)) => {
+                rsa_sign::<Sha256>(algorithm, key, to_sign)
+            }
+            (SignatureAlgorithm::RS384, PrivateKey::Rsa(key)) => {
+                rsa_sign::<Sha384>(algorithm, key, to_sign)
+            }
+            (SignatureAlgorithm::RS512, PrivateKey::Rsa(key)) => {
+                rsa_sign::<Sha512>(algorithm, key, to_sign)
+            }
+            (SignatureAlgorithm::EdDSA, PrivateKey::Ed25519(key)) |
+            (SignatureAlgorithm::Ed25519, PrivateKey::Ed25519(key)) => {
+                let signature: ed25519_dalek::Signature = key.sign(to_sign);
+                Ok(signature.to_vec())
+            }
+            (SignatureAlgorithm::Ed448, _) => Err(CoseError::UnknownSignatureScheme),
+            _ => Err(CoseError::InvalidArgument),
+        }
+    }
+}
+
+/// A `Verifier` that reads the public key from a DER encoded certificate.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RustCryptoVerifier;
+
+/// Get the DER encoded SubjectPublicKeyInfo of a DER encoded certificate.
+fn get_public_key_der(cert: &[u8]) -> Result<Vec<u8>, CoseError> {
+    let cert = Certificate::from_der(cert).map_err(|_| CoseError::DecodingFailure)?;
+    cert.tbs_certificate
+        .subject_public_key_info
+        .to_der()
+        .map_err(|_| CoseError::DecodingFailure)
+}
+
+impl Verifier for RustCryptoVerifier {
+    /// Verify with the public key of the certificate `key`. A signature that doesn't match, or a
+    /// key that doesn't fit the algorithm, fails with `VerificationFailed`.
+    fn verify(
+        &self,
+        algorithm: SignatureAlgorithm,
+        key: &[u8],
+        to_verify: &[u8],
+        signature: &[u8],
+    ) -> Result<(), CoseError> {
+        let public_key = get_public_key_der(key)?;
+        let result = match algorithm {
+            SignatureAlgorithm::ES256 => {
+                let key = p256::ecdsa::VerifyingKey::from_public_key_der(&public_key)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                let signature = p256::ecdsa::Signature::from_slice(signature)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                key.verify(to_verify, &signature).is_ok()
+            }
+            SignatureAlgorithm::ES384 => {
+                let key = p384::ecdsa::VerifyingKey::from_public_key_der(&public_key)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                let signature = p384::ecdsa::Signature::from_slice(signature)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                key.verify(to_verify, &signature).is_ok()
+            }
+            SignatureAlgorithm::ES512 => {
+                let key = p521::PublicKey::from_public_key_der(&public_key)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                let key = p521::ecdsa::VerifyingKey::from_affine(*key.as_affine())
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                let signature = p521::ecdsa::Signature::from_slice(signature)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                key.verify(to_verify, &signature).is_ok()
+            }
+            SignatureAlgorithm::ES256K => {
+                // Signatures with a high S value are rejected.
+                let key = k256::ecdsa::VerifyingKey::from_public_key_der(&public_key)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                let signature = k256::ecdsa::Signature::from_slice(signature)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                key.verify(to_verify, &signature).is_ok()
+            }
+            SignatureAlgorithm::PS256 => {
+                rsa_verify::<Sha256>(algorithm, &public_key, to_verify, signature)?
+            }
+            SignatureAlgorithm::PS384 => {
+                rsa_verify::<Sha384>(algorithm, &public_key, to_verify, signature)?
+            }
+            SignatureAlgorithm::PS512 => {
+                rsa_verify::<Sha512>(algorithm, &public_key, to_verify, signature)?
+            }
+            SignatureAlgorithm::RS256 => {
+                rsa_verify::<Sha256>(algorithm, &public_key, to_verify, signature)?
+            }
+            SignatureAlgorithm::RS384 => {
+                rsa_verify::<Sha384>(algorithm, &public_key, to_verify, signature)?
+            }
+            SignatureAlgorithm::RS512 => {
+                rsa_verify::<Sha512>(algorithm, &public_key, to_verify, signature)?
+            }
+            SignatureAlgorithm::EdDSA | SignatureAlgorithm::Ed25519 => {
+                let key = ed25519_dalek::VerifyingKey::from_public_key_der(&public_key)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                let signature = ed25519_dalek::Signature::from_slice(signature)
+                    .map_err(|_| CoseError::VerificationFailed)?;
+                key.verify(to_verify, &signature).is_ok()
+            }
+            SignatureAlgorithm::Ed448 => return Err(CoseError::UnknownSignatureScheme),
+        };
+        if !result {
+            return Err(CoseError::VerificationFailed);
+        }
+        Ok(())
+    }
+}
+
+/// A `Mac` holding a symmetric key.
+pub struct RustCryptoMac {
+    key: Vec<u8>,
+}
+
+impl RustCryptoMac {
+    /// Use the given symmetric key, e.g. the k parameter of a COSE key.
+    pub fn new(key: &[u8]) -> RustCryptoMac {
+        RustCryptoMac { key: key.to_vec() }
+    }
+}
+
+/// Compute the MAC `M` of `to_mac`, e.g. `Hmac<Sha256>`.
+fn hmac<M: HmacMac + KeyInit>(key: &[u8], to_mac: &[u8]) -> Result<Vec<u8>, CoseError> {
+    let mut mac = <M as HmacMac>::new_from_slice(key).map_err(|_| CoseError::LibraryFailure)?;
+    mac.update(to_mac);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+impl Mac for RustCryptoMac {
+    fn mac(&self, algorithm: MacAlgorithm, to_mac: &[u8]) -> Result<Vec<u8>, CoseError> {
+        match algorithm.info().hash {
+            HashAlgorithm::SHA256 => hmac::<Hmac<Sha256>>(&self.key, to_mac),
+            HashAlgorithm::SHA384 => hmac::<Hmac<Sha384>>(&self.key, to_mac),
+            HashAlgorithm::SHA512 => hmac::<Hmac<Sha512>>(&self.key, to_mac),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use {MacAlgorithm, SignatureAlgorithm};
+use algorithm::{ALGORITHMS, Curve, HashAlgorithm, KeyType, MAC_ALGORITHMS, SignatureScheme, from_id,
+                from_name, mac_from_id, mac_from_name};
+
+#[test]
+fn test_algorithm_lookup() {
+    for &algorithm in &ALGORITHMS {
+        let info = algorithm.info();
+        assert_eq!(info.algorithm, algorithm);
+        assert_eq!(from_id(info.id), Some(algorithm));
+        assert_eq!(from_name(info.name), Some(algorithm));
+        let count = ALGORITHMS.iter().filter(|other| other.id() == info.id).count();
+        assert_eq!(count, 1);
+    }
+    assert_eq!(from_id(-7), Some(SignatureAlgorithm::ES256));
+    assert_eq!(from_id(-257), Some(SignatureAlgorithm::RS256));
+    assert_eq!(from_name("ES256K"), Some(SignatureAlgorithm::ES256K));
+    assert_eq!(SignatureAlgorithm::Ed448.id(), -53);
+    assert_eq!(SignatureAlgorithm::PS384.name(), "PS384");
+    assert_eq!(from_id(0), None);
+    assert_eq!(from_id(-65535), None);
+    assert_eq!(from_name("es256"), None);
+}
+
+#[test]
+fn test_algorithm_metadata() {
+    let info = SignatureAlgorithm::ES384.info();
+    assert_eq!(info.scheme, SignatureScheme::Ecdsa);
+    assert_eq!(info.hash, Some(HashAlgorithm::SHA384));
+    assert_eq!(info.key_type, KeyType::EC2);
+    assert_eq!(info.curve, Some(Curve::P384));
+    assert!(!info.deprecated);
+    assert_eq!(info.min_key_size, None);
+
+    let info = SignatureAlgorithm::RS512.info();
+    assert_eq!(info.scheme, SignatureScheme::RsaPkcs1v15);
+    assert_eq!(info.hash, Some(HashAlgorithm::SHA512));
+    assert_eq!(info.key_type, KeyType::RSA);
+    assert_eq!(info.curve, None);
+    assert_eq!(info.min_key_size, Some(2048));
+    assert_eq!(SignatureAlgorithm::PS256.info().scheme, SignatureScheme::RsaPss);
+
+    // The polymorphic EdDSA is deprecated in favor of the fully specified algorithms.
+    let info = SignatureAlgorithm::EdDSA.info();
+    assert_eq!(info.hash, None);
+    assert_eq!(info.key_type, KeyType::OKP);
+    assert_eq!(info.curve, None);
+    assert!(info.deprecated);
+    let info = SignatureAlgorithm::Ed25519.info();
+    assert_eq!(info.curve, Some(Curve::Ed25519));
+    assert!(!info.deprecated);
+}
+
+#[test]
+fn test_mac_algorithm_lookup() {
+    for &algorithm in &MAC_ALGORITHMS {
+        let info = algorithm.info();
+        assert_eq!(info.algorithm, algorithm);
+        assert_eq!(mac_from_id(info.id), Some(algorithm));
+        assert_eq!(mac_from_name(info.name), Some(algorithm));
+        // The tag is at most as long as the hash.
+        assert!(info.tag_length <= info.hash.size());
+    }
+    assert_eq!(mac_from_id(4), Some(MacAlgorithm::HMAC256_64));
+    assert_eq!(mac_from_name("HMAC 384/384"), Some(MacAlgorithm::HMAC384_384));
+    assert_eq!(MacAlgorithm::HMAC512_512.id(), 7);
+    assert_eq!(MacAlgorithm::HMAC256_64.info().tag_length, 8);
+    assert_eq!(MacAlgorithm::HMAC256_256.info().hash, HashAlgorithm::SHA256);
+    // Signature algorithms are not MAC algorithms.
+    assert_eq!(mac_from_id(-7), None);
+    assert_eq!(mac_from_name("ES256"), None);
+}
@@ -1,6 +1,12 @@
 use test_setup as test;
 use {CoseError, SignatureAlgorithm};
-use decoder::{COSE_HEADER_ALG, COSE_HEADER_KID, COSE_SIGN_TAG, COSE_TYPE_ES256, decode_signature};
+use decoder::{COSE_HEADER_ALG, COSE_HEADER_KID, COSE_SIGN1_TAG, COSE_SIGN_TAG, COSE_TYPE_ES256,
+              DecodeOptions, decode_sign, decode_sign1, decode_sign1_with_options,
+              decode_sign_with_options, decode_signature};
+use header::{COSE_HEADER_CONTENT_TYPE, COSE_HEADER_COUNTER_SIGNATURE, COSE_HEADER_CRIT,
+             COSE_HEADER_IV, COSE_HEADER_X5BAG, COSE_HEADER_X5CHAIN, COSE_HEADER_X5T,
+             CertificateProfile, ContentType, Label};
+use util::{SigContext, SigStructure, get_sig_struct_bytes};
 use cbor::CborType;
 use std::collections::BTreeMap;
 
@@ -66,16 +72,16 @@ fn make_minimally_valid_cose_signature_protected_header() -> Vec<u8> {
 
 // This tests the minimally-valid COSE_Sign structure according to this implementation.
 // The structure must be a CBOR array of length 4 tagged with the integer 98.
-// The COSE_Sign protected header must have the `kid` integer key and no others. The value for `kid`
-// must be an array (although it may be empty). Each element of the array must be of type bytes.
-// The COSE_Sign unprotected header must be an empty map.
-// The COSE_Sign payload must be nil.
+// The COSE_Sign protected header must have the `kid` integer key. The value for `kid` must be an
+// array (although it may be empty). Each element of the array must be of type bytes.
+// The COSE_Sign unprotected header must be a map.
+// The COSE_Sign payload must be nil (or bytes if there is no detached payload).
 // The COSE_Sign signatures must be an array with at least one COSE_Signature.
 // Each COSE_Signature must be an array of length 3.
-// Each COSE_Signature protected header must have the `alg` and `kid` integer keys and no others.
+// Each COSE_Signature protected header must have the `alg` and `kid` integer keys.
 // The value for `alg` must be a valid algorithm identifier. The value for `kid` must be bytes,
 // although it may be empty.
-// Each COSE_Signature unprotected header must be an empty map.
+// Each COSE_Signature unprotected header must be a map.
 // Each COSE_Signature signature must be of type bytes (although it may be empty).
 #[test]
 fn test_cose_sign_minimally_valid() {
@@ -148,7 +154,7 @@ fn test_cose_sign_protected_header_empty() {
                       CborType::Null,
                       CborType::Array(vec![signature])];
     let bytes = wrap_tag_and_encode_array(values);
-    test_cose_format_error(&bytes, CoseError::MalformedInput);
+    test_cose_format_error(&bytes, CoseError::MissingHeader);
 }
 
 #[test]
@@ -185,8 +191,8 @@ fn test_cose_sign_protected_header_kid_wrong_type() {
 fn test_cose_sign_protected_header_extra_header_key() {
     let body_protected_header = encode_test_protected_header(
         vec![CborType::Integer(COSE_HEADER_KID),
-             CborType::Integer(2)],
-        vec![CborType::Bytes(Vec::new()),
+             CborType::Integer(100)],
+        vec![CborType::Array(Vec::new()),
              CborType::Integer(2)],
     );
     let signature_protected_header = make_minimally_valid_cose_signature_protected_header();
@@ -196,7 +202,8 @@ fn test_cose_sign_protected_header_extra_header_key() {
                       CborType::Null,
                       CborType::Array(vec![signature])];
     let bytes = wrap_tag_and_encode_array(values);
-    test_cose_format_error(&bytes, CoseError::MalformedInput);
+    let cose_sign = decode_sign(&bytes, Some(&[0])).unwrap();
+    assert_eq!(cose_sign.protected_header.get(&Label::Int(100)), Some(&CborType::Integer(2)));
 }
 
 #[test]
@@ -219,6 +226,26 @@ fn test_cose_sign_unprotected_header_not_empty() {
     let signature = build_test_cose_signature(signature_protected_header);
     let mut unprotected_header_map: BTreeMap<CborType, CborType> = BTreeMap::new();
     unprotected_header_map.insert(CborType::Integer(0), CborType::SignedInteger(-1));
+    unprotected_header_map.insert(CborType::Integer(COSE_HEADER_IV), CborType::Bytes(vec![1]));
+    let values = vec![CborType::Bytes(body_protected_header),
+                      CborType::Map(unprotected_header_map),
+                      CborType::Null,
+                      CborType::Array(vec![signature])];
+    let bytes = wrap_tag_and_encode_array(values);
+    let cose_sign = decode_sign(&bytes, Some(&[0])).unwrap();
+    let header = &cose_sign.unprotected_header;
+    assert_eq!(header.iv, Some(vec![1]));
+    assert_eq!(header.get(&Label::Int(0)), Some(&CborType::SignedInteger(-1)));
+    assert_eq!(cose_sign.protected_header.iv, None);
+}
+
+#[test]
+fn test_cose_sign_header_label_in_both_buckets() {
+    let body_protected_header = make_minimally_valid_cose_sign_protected_header();
+    let signature_protected_header = make_minimally_valid_cose_signature_protected_header();
+    let signature = build_test_cose_signature(signature_protected_header);
+    let mut unprotected_header_map: BTreeMap<CborType, CborType> = BTreeMap::new();
+    unprotected_header_map.insert(CborType::Integer(COSE_HEADER_KID), CborType::Bytes(vec![1]));
     let values = vec![CborType::Bytes(body_protected_header),
                       CborType::Map(unprotected_header_map),
                       CborType::Null,
@@ -240,6 +267,47 @@ fn test_cose_sign_payload_not_null() {
     test_cose_format_error(&bytes, CoseError::UnexpectedType);
 }
 
+// Helper function to build a minimally-valid COSE_Sign with the given payload.
+fn build_test_cose_sign_with_payload(payload: CborType) -> Vec<u8> {
+    let body_protected_header = make_minimally_valid_cose_sign_protected_header();
+    let signature_protected_header = make_minimally_valid_cose_signature_protected_header();
+    let signature = build_test_cose_signature(signature_protected_header);
+    let values = vec![CborType::Bytes(body_protected_header),
+                      CborType::Map(BTreeMap::new()),
+                      payload,
+                      CborType::Array(vec![signature])];
+    wrap_tag_and_encode_array(values)
+}
+
+#[test]
+fn test_cose_sign_embedded_payload() {
+    let bytes = build_test_cose_sign_with_payload(CborType::Bytes(b"embedded".to_vec()));
+    let cose_sign = decode_sign(&bytes, None).unwrap();
+    assert_eq!(cose_sign.payload, b"embedded".to_vec());
+    assert_eq!(cose_sign.signatures.len(), 1);
+
+    // The Sig_structure covers the embedded payload just like a detached one.
+    let detached_bytes = build_test_cose_sign_with_payload(CborType::Null);
+    let detached = decode_sign(&detached_bytes, Some(b"embedded")).unwrap();
+    assert_eq!(detached.payload, cose_sign.payload);
+    assert_eq!(detached.signatures[0].to_verify, cose_sign.signatures[0].to_verify);
+}
+
+#[test]
+fn test_cose_sign_embedded_and_detached_payload() {
+    let bytes = build_test_cose_sign_with_payload(CborType::Bytes(b"embedded".to_vec()));
+    let result = decode_sign(&bytes, Some(b"detached"));
+    assert_eq!(result.err(), Some(CoseError::InvalidArgument));
+    test_cose_format_error(&bytes, CoseError::InvalidArgument);
+}
+
+#[test]
+fn test_cose_sign_no_payload() {
+    let bytes = build_test_cose_sign_with_payload(CborType::Null);
+    let result = decode_sign(&bytes, None);
+    assert_eq!(result.err(), Some(CoseError::InvalidArgument));
+}
+
 #[test]
 fn test_cose_signatures_not_array() {
     let body_protected_header = make_minimally_valid_cose_sign_protected_header();
@@ -286,7 +354,7 @@ fn test_cose_signature_protected_header_empty() {
                       CborType::Null,
                       CborType::Array(vec![signature])];
     let bytes = wrap_tag_and_encode_array(values);
-    test_cose_format_error(&bytes, CoseError::MalformedInput);
+    test_cose_format_error(&bytes, CoseError::MissingHeader);
 }
 
 #[test]
@@ -324,7 +392,7 @@ fn test_cose_signature_protected_header_bad_encoding() {
 fn test_cose_signature_protected_header_missing_alg() {
     let body_protected_header = make_minimally_valid_cose_sign_protected_header();
     let signature_protected_header = encode_test_protected_header(
-        vec![CborType::Integer(2),
+        vec![CborType::Integer(100),
              CborType::Integer(COSE_HEADER_KID)],
         vec![CborType::SignedInteger(COSE_TYPE_ES256),
              CborType::Bytes(Vec::new())],
@@ -343,7 +411,7 @@ fn test_cose_signature_protected_header_missing_kid() {
     let body_protected_header = make_minimally_valid_cose_sign_protected_header();
     let signature_protected_header = encode_test_protected_header(
         vec![CborType::Integer(COSE_HEADER_ALG),
-             CborType::Integer(3)],
+             CborType::Integer(100)],
         vec![CborType::SignedInteger(COSE_TYPE_ES256),
              CborType::Bytes(Vec::new())],
     );
@@ -371,7 +439,7 @@ fn test_cose_signature_protected_header_wrong_key_types() {
                       CborType::Null,
                       CborType::Array(vec![signature])];
     let bytes = wrap_tag_and_encode_array(values);
-    test_cose_format_error(&bytes, CoseError::MissingHeader);
+    test_cose_format_error(&bytes, CoseError::UnexpectedType);
 }
 
 #[test]
@@ -434,7 +502,34 @@ fn test_cose_signature_protected_header_extra_key() {
     let signature_protected_header = encode_test_protected_header(
         vec![CborType::Integer(COSE_HEADER_ALG),
              CborType::Integer(COSE_HEADER_KID),
+             CborType::Integer(COSE_HEADER_CONTENT_TYPE),
+             CborType::Integer(100)],
+        vec![CborType::SignedInteger(COSE_TYPE_ES256),
+             CborType::Bytes(Vec::new()),
+             CborType::Integer(50),
              CborType::Integer(5)],
+    );
+    let signature = build_test_cose_signature(signature_protected_header);
+    let values = vec![CborType::Bytes(body_protected_header),
+                      CborType::Map(BTreeMap::new()),
+                      CborType::Null,
+                      CborType::Array(vec![signature])];
+    let bytes = wrap_tag_and_encode_array(values);
+    let payload = vec![0];
+    let decoded = decode_signature(&bytes, &payload).unwrap();
+    let header = &decoded[0].protected_header;
+    assert_eq!(header.alg, Some(Label::Int(COSE_TYPE_ES256)));
+    assert_eq!(header.content_type, Some(ContentType::Uint(50)));
+    assert_eq!(header.get(&Label::Int(100)), Some(&CborType::Integer(5)));
+}
+
+#[test]
+fn test_cose_signature_protected_header_wrong_value_type() {
+    let body_protected_header = make_minimally_valid_cose_sign_protected_header();
+    let signature_protected_header = encode_test_protected_header(
+        vec![CborType::Integer(COSE_HEADER_ALG),
+             CborType::Integer(COSE_HEADER_KID),
+             CborType::Integer(COSE_HEADER_IV)],
         vec![CborType::SignedInteger(COSE_TYPE_ES256),
              CborType::Bytes(Vec::new()),
              CborType::Integer(5)],
@@ -445,7 +540,7 @@ fn test_cose_signature_protected_header_extra_key() {
                       CborType::Null,
                       CborType::Array(vec![signature])];
     let bytes = wrap_tag_and_encode_array(values);
-    test_cose_format_error(&bytes, CoseError::MalformedInput);
+    test_cose_format_error(&bytes, CoseError::UnexpectedType);
 }
 
 #[test]
@@ -477,6 +572,26 @@ fn test_cose_signature_unprotected_header_not_empty() {
                       CborType::Null,
                       CborType::Array(vec![signature])];
     let bytes = wrap_tag_and_encode_array(values);
+    let payload = vec![0];
+    let decoded = decode_signature(&bytes, &payload).unwrap();
+    let header = &decoded[0].unprotected_header;
+    assert_eq!(header.get(&Label::Int(0)), Some(&CborType::SignedInteger(-1)));
+}
+
+#[test]
+fn test_cose_signature_header_label_in_both_buckets() {
+    let body_protected_header = make_minimally_valid_cose_sign_protected_header();
+    let signature_protected_header = make_minimally_valid_cose_signature_protected_header();
+    let mut unprotected_header_map: BTreeMap<CborType, CborType> = BTreeMap::new();
+    unprotected_header_map.insert(CborType::Integer(COSE_HEADER_ALG), CborType::SignedInteger(-7));
+    let signature = CborType::Array(vec![CborType::Bytes(signature_protected_header),
+         CborType::Map(unprotected_header_map),
+         CborType::Bytes(Vec::new())]);
+    let values = vec![CborType::Bytes(body_protected_header),
+                      CborType::Map(BTreeMap::new()),
+                      CborType::Null,
+                      CborType::Array(vec![signature])];
+    let bytes = wrap_tag_and_encode_array(values);
     test_cose_format_error(&bytes, CoseError::MalformedInput);
 }
 
@@ -494,3 +609,422 @@ fn test_cose_signature_signature_wrong_type() {
     let bytes = wrap_tag_and_encode_array(values);
     test_cose_format_error(&bytes, CoseError::UnexpectedType);
 }
+
+#[test]
+fn test_cose_sign1_decode() {
+    let cose_sign1 = decode_sign1(&test::COSE_SIGN1_BYTES, None).unwrap();
+    assert_eq!(cose_sign1.signature_type, SignatureAlgorithm::ES256);
+    assert_eq!(cose_sign1.signature, test::SIGN1_SIGNATURE_BYTES.to_vec());
+    assert_eq!(cose_sign1.payload, b"This is the content.".to_vec());
+    assert_eq!(cose_sign1.to_verify, test::COSE_SIGN1_TO_VERIFY.to_vec());
+}
+
+// Helper function to build a COSE_Sign1 with an ES256 protected header around the given payload.
+fn build_test_cose_sign1(payload: CborType) -> Vec<CborType> {
+    let protected_header = encode_test_protected_header(
+        vec![CborType::Integer(COSE_HEADER_ALG)],
+        vec![CborType::SignedInteger(COSE_TYPE_ES256)],
+    );
+    vec![CborType::Bytes(protected_header),
+         CborType::Map(BTreeMap::new()),
+         payload,
+         CborType::Bytes(Vec::new())]
+}
+
+fn test_cose_sign1_format_error(bytes: &[u8], payload: Option<&[u8]>, expected_error: CoseError) {
+    let result = decode_sign1(bytes, payload);
+    assert!(result.is_err());
+    assert_eq!(result.err(), Some(expected_error));
+}
+
+#[test]
+fn test_cose_sign1_unprotected_header() {
+    let cose_sign1 = decode_sign1(&test::COSE_SIGN1_BYTES, None).unwrap();
+    assert_eq!(cose_sign1.protected_header.kid, None);
+    assert_eq!(cose_sign1.unprotected_header.kid, Some(b"11".to_vec()));
+
+    // The alg must not be repeated in the unprotected header.
+    let mut values = build_test_cose_sign1(CborType::Bytes(Vec::new()));
+    let mut unprotected_header_map: BTreeMap<CborType, CborType> = BTreeMap::new();
+    unprotected_header_map.insert(CborType::Integer(COSE_HEADER_ALG), CborType::SignedInteger(-7));
+    values[1] = CborType::Map(unprotected_header_map);
+    let bytes = CborType::Array(values).serialize();
+    test_cose_sign1_format_error(&bytes, None, CoseError::MalformedInput);
+}
+
+#[test]
+fn test_cose_sign1_detached_payload() {
+    let values = build_test_cose_sign1(CborType::Null);
+    let bytes = CborType::Tag(COSE_SIGN1_TAG, Box::new(CborType::Array(values))).serialize();
+    let cose_sign1 = decode_sign1(&bytes, Some(b"detached")).unwrap();
+    assert_eq!(cose_sign1.payload, b"detached".to_vec());
+}
+
+#[test]
+fn test_cose_sign1_untagged() {
+    let values = build_test_cose_sign1(CborType::Bytes(vec![1, 2, 3]));
+    let bytes = CborType::Array(values).serialize();
+    let cose_sign1 = decode_sign1(&bytes, None).unwrap();
+    assert_eq!(cose_sign1.signature_type, SignatureAlgorithm::ES256);
+    assert_eq!(cose_sign1.payload, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_cose_sign1_wrong_tag() {
+    let values = build_test_cose_sign1(CborType::Bytes(Vec::new()));
+    let bytes = CborType::Tag(COSE_SIGN_TAG, Box::new(CborType::Array(values))).serialize();
+    test_cose_sign1_format_error(&bytes, None, CoseError::UnexpectedTag);
+}
+
+#[test]
+fn test_cose_sign1_wrong_size() {
+    let mut values = build_test_cose_sign1(CborType::Bytes(Vec::new()));
+    values.pop();
+    let bytes = CborType::Array(values).serialize();
+    test_cose_sign1_format_error(&bytes, None, CoseError::MalformedInput);
+}
+
+#[test]
+fn test_cose_sign1_missing_alg() {
+    let mut values = build_test_cose_sign1(CborType::Bytes(Vec::new()));
+    values[0] = CborType::Bytes(Vec::new());
+    let bytes = CborType::Array(values).serialize();
+    test_cose_sign1_format_error(&bytes, None, CoseError::MissingHeader);
+}
+
+#[test]
+fn test_cose_sign1_payload_both_or_neither() {
+    let values = build_test_cose_sign1(CborType::Bytes(vec![0]));
+    let bytes = CborType::Array(values).serialize();
+    test_cose_sign1_format_error(&bytes, Some(&[0]), CoseError::InvalidArgument);
+
+    let values = build_test_cose_sign1(CborType::Null);
+    let bytes = CborType::Array(values).serialize();
+    test_cose_sign1_format_error(&bytes, None, CoseError::InvalidArgument);
+}
+
+#[test]
+fn test_cose_sign1_payload_wrong_type() {
+    let values = build_test_cose_sign1(CborType::Integer(0));
+    let bytes = CborType::Array(values).serialize();
+    test_cose_sign1_format_error(&bytes, None, CoseError::UnexpectedType);
+}
+
+// Helper function to build a COSE_Sign whose signature protected header has the given critical
+// labels and extra header parameters.
+fn build_test_cose_sign_with_crit(
+    crit: Vec<CborType>,
+    extra: Vec<(CborType, CborType)>,
+) -> Vec<u8> {
+    let body_protected_header = make_minimally_valid_cose_sign_protected_header();
+    let mut keys = vec![CborType::Integer(COSE_HEADER_ALG),
+                        CborType::Integer(COSE_HEADER_KID),
+                        CborType::Integer(COSE_HEADER_CRIT)];
+    let mut values = vec![CborType::SignedInteger(COSE_TYPE_ES256),
+                          CborType::Bytes(Vec::new()),
+                          CborType::Array(crit)];
+    for (key, value) in extra {
+        keys.push(key);
+        values.push(value);
+    }
+    let signature_protected_header = encode_test_protected_header(keys, values);
+    let signature = build_test_cose_signature(signature_protected_header);
+    let values = vec![CborType::Bytes(body_protected_header),
+                      CborType::Map(BTreeMap::new()),
+                      CborType::Null,
+                      CborType::Array(vec![signature])];
+    wrap_tag_and_encode_array(values)
+}
+
+#[test]
+fn test_cose_crit_common_header() {
+    let crit = vec![CborType::Integer(COSE_HEADER_ALG)];
+    let bytes = build_test_cose_sign_with_crit(crit, Vec::new());
+    let cose_sign = decode_sign(&bytes, Some(&[0])).unwrap();
+    assert_eq!(cose_sign.signatures[0].protected_header.crit, vec![Label::Int(1)]);
+}
+
+#[test]
+fn test_cose_crit_missing_common_header() {
+    // Critical header parameters have to be in the protected header, even the understood ones.
+    let crit = vec![CborType::Integer(COSE_HEADER_CONTENT_TYPE)];
+    let bytes = build_test_cose_sign_with_crit(crit, Vec::new());
+    test_cose_format_error(&bytes, CoseError::MissingHeader);
+
+    let crit = vec![CborType::Integer(COSE_HEADER_CONTENT_TYPE)];
+    let extra = vec![(CborType::Integer(COSE_HEADER_CONTENT_TYPE), CborType::Integer(0))];
+    let bytes = build_test_cose_sign_with_crit(crit, extra);
+    assert!(decode_sign(&bytes, Some(&[0])).is_ok());
+}
+
+#[test]
+fn test_cose_crit_legacy_kid() {
+    // The body kid of the legacy layout holds the certificates and can be critical.
+    let body_protected_header = encode_test_protected_header(
+        vec![CborType::Integer(COSE_HEADER_KID), CborType::Integer(COSE_HEADER_CRIT)],
+        vec![CborType::Array(Vec::new()),
+             CborType::Array(vec![CborType::Integer(COSE_HEADER_KID)])],
+    );
+    let signature_protected_header = make_minimally_valid_cose_signature_protected_header();
+    let signature = build_test_cose_signature(signature_protected_header);
+    let values = vec![CborType::Bytes(body_protected_header),
+                      CborType::Map(BTreeMap::new()),
+                      CborType::Null,
+                      CborType::Array(vec![signature])];
+    let bytes = wrap_tag_and_encode_array(values);
+    let cose_sign = decode_sign(&bytes, Some(&[0])).unwrap();
+    assert_eq!(cose_sign.protected_header.crit, vec![Label::Int(4)]);
+    assert_eq!(cose_sign.protected_header.kid, None);
+    assert!(decode_signature(&bytes, &[0]).is_ok());
+}
+
+#[test]
+fn test_cose_crit_counter_signature() {
+    // Counter signatures aren't processed, so they need a handler when they are critical.
+    let crit = vec![CborType::Integer(COSE_HEADER_COUNTER_SIGNATURE)];
+    let extra = vec![(CborType::Integer(COSE_HEADER_COUNTER_SIGNATURE),
+                      CborType::Array(Vec::new()))];
+    let bytes = build_test_cose_sign_with_crit(crit, extra);
+    test_cose_format_error(&bytes, CoseError::UnknownCriticalHeader);
+
+    let options = DecodeOptions::new().critical_header_handler(Label::Int(7), |_| Ok(()));
+    assert!(decode_sign_with_options(&bytes, Some(&[0]), &options).is_ok());
+}
+
+#[test]
+fn test_cose_crit_unknown_header() {
+    let bytes = build_test_cose_sign_with_crit(
+        vec![CborType::Integer(100)],
+        vec![(CborType::Integer(100), CborType::Integer(1))],
+    );
+    test_cose_format_error(&bytes, CoseError::UnknownCriticalHeader);
+}
+
+#[test]
+fn test_cose_crit_registered_handler() {
+    let bytes = build_test_cose_sign_with_crit(
+        vec![CborType::Integer(100)],
+        vec![(CborType::Integer(100), CborType::Integer(1))],
+    );
+    let options = DecodeOptions::new().critical_header_handler(Label::Int(100), |value| {
+        match *value {
+            CborType::Integer(1) => Ok(()),
+            _ => Err(CoseError::UnexpectedHeaderValue),
+        }
+    });
+    assert!(decode_sign_with_options(&bytes, Some(&[0]), &options).is_ok());
+
+    let bytes = build_test_cose_sign_with_crit(
+        vec![CborType::Integer(100)],
+        vec![(CborType::Integer(100), CborType::Integer(2))],
+    );
+    let result = decode_sign_with_options(&bytes, Some(&[0]), &options);
+    assert_eq!(result.err(), Some(CoseError::UnexpectedHeaderValue));
+
+    // A critical header parameter has to be present.
+    let bytes = build_test_cose_sign_with_crit(vec![CborType::Integer(100)], Vec::new());
+    let result = decode_sign_with_options(&bytes, Some(&[0]), &options);
+    assert_eq!(result.err(), Some(CoseError::MissingHeader));
+}
+
+#[test]
+fn test_cose_crit_unprotected() {
+    let body_protected_header = make_minimally_valid_cose_sign_protected_header();
+    let signature_protected_header = make_minimally_valid_cose_signature_protected_header();
+    let signature = build_test_cose_signature(signature_protected_header);
+    let mut unprotected_header_map: BTreeMap<CborType, CborType> = BTreeMap::new();
+    unprotected_header_map.insert(
+        CborType::Integer(COSE_HEADER_CRIT),
+        CborType::Array(vec![CborType::Integer(COSE_HEADER_ALG)]),
+    );
+    let values = vec![CborType::Bytes(body_protected_header),
+                      CborType::Map(unprotected_header_map),
+                      CborType::Null,
+                      CborType::Array(vec![signature])];
+    let bytes = wrap_tag_and_encode_array(values);
+    test_cose_format_error(&bytes, CoseError::MalformedInput);
+}
+
+#[test]
+fn test_cose_sign1_crit_unknown_header() {
+    let protected_header = encode_test_protected_header(
+        vec![CborType::Integer(COSE_HEADER_ALG),
+             CborType::Integer(COSE_HEADER_CRIT),
+             CborType::SignedInteger(-100)],
+        vec![CborType::SignedInteger(COSE_TYPE_ES256),
+             CborType::Array(vec![CborType::SignedInteger(-100)]),
+             CborType::Bytes(Vec::new())],
+    );
+    let values = vec![CborType::Bytes(protected_header),
+                      CborType::Map(BTreeMap::new()),
+                      CborType::Bytes(Vec::new()),
+                      CborType::Bytes(Vec::new())];
+    let bytes = CborType::Array(values).serialize();
+    test_cose_sign1_format_error(&bytes, None, CoseError::UnknownCriticalHeader);
+
+    let options = DecodeOptions::new().critical_header_handler(Label::Int(-100), |_| Ok(()));
+    assert!(decode_sign1_with_options(&bytes, None, &options).is_ok());
+}
+
+#[test]
+fn test_cose_sign_external_aad() {
+    let body_protected_header = make_minimally_valid_cose_sign_protected_header();
+    let signature_protected_header = make_minimally_valid_cose_signature_protected_header();
+    let bytes = build_test_cose_sign_with_payload(CborType::Null);
+    let payload = b"payload";
+
+    // By default there is no external_aad, which is encoded as an empty bstr.
+    let cose_sign = decode_sign(&bytes, Some(payload)).unwrap();
+    let expected = SigStructure::new(SigContext::Signature, &body_protected_header, payload)
+        .sign_protected(&signature_protected_header)
+        .to_bytes()
+        .unwrap();
+    assert_eq!(cose_sign.signatures[0].to_verify, expected);
+
+    let options = DecodeOptions::new().external_aad(b"aad");
+    let cose_sign = decode_sign_with_options(&bytes, Some(payload), &options).unwrap();
+    let expected = SigStructure::new(SigContext::Signature, &body_protected_header, payload)
+        .sign_protected(&signature_protected_header)
+        .external_aad(b"aad")
+        .to_bytes()
+        .unwrap();
+    assert_eq!(cose_sign.signatures[0].to_verify, expected);
+
+    // decode_signature keeps using nil for compatibility with existing signatures.
+    let cose_signatures = decode_signature(&bytes, payload).unwrap();
+    let expected = get_sig_struct_bytes(
+        CborType::Bytes(body_protected_header),
+        CborType::Bytes(signature_protected_header),
+        payload,
+    );
+    assert_eq!(cose_signatures[0].to_verify, expected);
+
+    // A nil external_aad can't bind any data.
+    let options = DecodeOptions::new().nil_external_aad().external_aad(b"aad");
+    let result = decode_sign_with_options(&bytes, Some(payload), &options);
+    assert_eq!(result.err(), Some(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_cose_sign1_external_aad() {
+    let options = DecodeOptions::new().external_aad(&[0x11, 0xaa, 0x22, 0xbb]);
+    let cose_sign1 = decode_sign1_with_options(&test::COSE_SIGN1_BYTES, None, &options).unwrap();
+    let payload = b"This is the content.";
+    let expected = SigStructure::new(SigContext::Signature1, &[0xa1, 0x01, 0x26], payload)
+        .external_aad(&[0x11, 0xaa, 0x22, 0xbb])
+        .to_bytes()
+        .unwrap();
+    assert_eq!(cose_sign1.to_verify, expected);
+}
+
+#[test]
+fn test_cose_decode_xpi_signature() {
+    let cose_signatures = decode_signature(&test::XPI_SIGNATURE, &test::XPI_PAYLOAD).unwrap();
+    assert_eq!(cose_signatures.len(), 1);
+    assert_eq!(cose_signatures[0].signature_type, SignatureAlgorithm::ES256);
+    assert_eq!(cose_signatures[0].signature.len(), 64);
+    assert!(cose_signatures[0].certs.is_empty());
+}
+
+#[test]
+fn test_cose_allowed_algorithms() {
+    let options = DecodeOptions::new()
+        .allowed_algorithms(&[SignatureAlgorithm::ES256, SignatureAlgorithm::Ed25519]);
+    let cose_sign1 = decode_sign1_with_options(&test::COSE_SIGN1_BYTES, None, &options).unwrap();
+    assert_eq!(cose_sign1.signature_type, SignatureAlgorithm::ES256);
+    let result = decode_sign_with_options(&test::XPI_SIGNATURE, Some(&test::XPI_PAYLOAD), &options);
+    assert!(result.is_ok());
+
+    let options = DecodeOptions::new().allowed_algorithms(&[SignatureAlgorithm::ES384]);
+    let result = decode_sign1_with_options(&test::COSE_SIGN1_BYTES, None, &options);
+    assert_eq!(result.err(), Some(CoseError::UnexpectedHeaderValue));
+    let result = decode_sign_with_options(&test::XPI_SIGNATURE, Some(&test::XPI_PAYLOAD), &options);
+    assert_eq!(result.err(), Some(CoseError::UnexpectedHeaderValue));
+
+    // Nothing is accepted with an empty allow-list.
+    let options = DecodeOptions::new().allowed_algorithms(&[]);
+    let result = decode_sign1_with_options(&test::COSE_SIGN1_BYTES, None, &options);
+    assert_eq!(result.err(), Some(CoseError::UnexpectedHeaderValue));
+}
+
+// Helper function to build a COSE_Sign with an empty protected body header, the given unprotected
+// body header, and a signature with the given protected header parameters besides the alg.
+fn build_test_x509_cose_sign(
+    body_unprotected: Vec<(CborType, CborType)>,
+    signature_protected: Vec<(CborType, CborType)>,
+) -> Vec<u8> {
+    let mut keys = vec![CborType::Integer(COSE_HEADER_ALG)];
+    let mut values = vec![CborType::SignedInteger(COSE_TYPE_ES256)];
+    for (key, value) in signature_protected {
+        keys.push(key);
+        values.push(value);
+    }
+    let signature = build_test_cose_signature(encode_test_protected_header(keys, values));
+    let values = vec![CborType::Bytes(Vec::new()),
+                      CborType::Map(body_unprotected.into_iter().collect()),
+                      CborType::Null,
+                      CborType::Array(vec![signature])];
+    wrap_tag_and_encode_array(values)
+}
+
+#[test]
+fn test_cose_x509_profile() {
+    let options = DecodeOptions::new().certificate_profile(CertificateProfile::X509);
+    let chain = CborType::Array(vec![CborType::Bytes(vec![1]), CborType::Bytes(vec![2])]);
+
+    // The x5chain of the signature is critical, the body has another chain and a bag.
+    let bytes = build_test_x509_cose_sign(
+        vec![(CborType::Integer(COSE_HEADER_X5CHAIN), CborType::Bytes(vec![5])),
+             (CborType::Integer(COSE_HEADER_X5BAG), chain.clone())],
+        vec![(CborType::Integer(COSE_HEADER_CRIT),
+              CborType::Array(vec![CborType::Integer(COSE_HEADER_X5CHAIN)])),
+             (CborType::Integer(COSE_HEADER_X5CHAIN), CborType::Bytes(vec![3]))],
+    );
+    let cose_sign = decode_sign_with_options(&bytes, Some(&[0]), &options).unwrap();
+    assert_eq!(cose_sign.signatures[0].signer_cert, vec![3]);
+    assert_eq!(cose_sign.signatures[0].certs, vec![vec![1], vec![2]]);
+
+    // The chain of the body is used if the signature has none.
+    let bytes = build_test_x509_cose_sign(
+        vec![(CborType::Integer(COSE_HEADER_X5CHAIN), chain.clone())],
+        Vec::new(),
+    );
+    let cose_sign = decode_sign_with_options(&bytes, Some(&[0]), &options).unwrap();
+    assert_eq!(cose_sign.signatures[0].signer_cert, vec![1]);
+    assert_eq!(cose_sign.signatures[0].certs, vec![vec![2]]);
+    // The legacy layout needs the kid with the certificates.
+    test_cose_format_error(&bytes, CoseError::MissingHeader);
+
+    // Without an x5chain there is no signing certificate.
+    let bytes = build_test_x509_cose_sign(
+        vec![(CborType::Integer(COSE_HEADER_X5BAG), chain)],
+        vec![(CborType::Integer(COSE_HEADER_KID), CborType::Bytes(vec![1]))],
+    );
+    let result = decode_sign_with_options(&bytes, Some(&[0]), &options);
+    assert_eq!(result.err(), Some(CoseError::MissingHeader));
+
+    // The kid of the legacy layout is not a bstr.
+    let result = decode_sign_with_options(&test::COSE_SIGNATURE_BYTES, Some(&[0]), &options);
+    assert_eq!(result.err(), Some(CoseError::UnexpectedType));
+}
+
+#[test]
+fn test_cose_x509_crit_x5t() {
+    // The x5t isn't checked against the signing certificate, so it can't be critical without a
+    // handler.
+    let x5t = CborType::Array(vec![CborType::SignedInteger(-16), CborType::Bytes(vec![0; 32])]);
+    let bytes = build_test_x509_cose_sign(
+        Vec::new(),
+        vec![(CborType::Integer(COSE_HEADER_CRIT),
+              CborType::Array(vec![CborType::Integer(COSE_HEADER_X5T)])),
+             (CborType::Integer(COSE_HEADER_X5CHAIN), CborType::Bytes(vec![3])),
+             (CborType::Integer(COSE_HEADER_X5T), x5t)],
+    );
+    let options = DecodeOptions::new().certificate_profile(CertificateProfile::X509);
+    let result = decode_sign_with_options(&bytes, Some(&[0]), &options);
+    assert_eq!(result.err(), Some(CoseError::UnknownCriticalHeader));
+
+    let options = options.critical_header_handler(Label::Int(34), |_| Ok(()));
+    let cose_sign = decode_sign_with_options(&bytes, Some(&[0]), &options).unwrap();
+    assert!(cose_sign.signatures[0].protected_header.x5t.is_some());
+}
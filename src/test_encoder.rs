@@ -0,0 +1,432 @@
+use test_setup as test;
+use {CoseError, SignatureAlgorithm, Signer, Verifier};
+use cbor::CborType;
+use decoder::{DecodeOptions, decode_sign, decode_sign_with_options, decode_sign1,
+              decode_sign1_with_options, decode_signature};
+use encoder::{CoseSign1Builder, CoseSignBuilder};
+use header::{CertificateProfile, ContentType, Header, Label};
+
+// A signing function for tests that "signs" by returning the bytes it was given. This lets the
+// tests check that the decoder rebuilds the same Sig_structure.
+fn sign_with_to_sign(to_sign: &[u8]) -> Result<Vec<u8>, CoseError> {
+    Ok(to_sign.to_vec())
+}
+
+// A `Signer` and `Verifier` for tests. The "signature" is the key followed by the signed bytes.
+struct TestKey(Vec<u8>);
+
+impl Signer for TestKey {
+    fn sign(&self, _: SignatureAlgorithm, to_sign: &[u8]) -> Result<Vec<u8>, CoseError> {
+        let mut signature = self.0.clone();
+        signature.extend_from_slice(to_sign);
+        Ok(signature)
+    }
+}
+
+struct TestVerifier;
+
+impl Verifier for TestVerifier {
+    fn verify(
+        &self,
+        algorithm: SignatureAlgorithm,
+        key: &[u8],
+        to_verify: &[u8],
+        signature: &[u8],
+    ) -> Result<(), CoseError> {
+        if algorithm == SignatureAlgorithm::PS256 {
+            return Err(CoseError::UnknownSignatureScheme);
+        }
+        let expected = TestKey(key.to_vec()).sign(algorithm, to_verify)?;
+        if signature != &expected[..] {
+            return Err(CoseError::VerificationFailed);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_cose_sign_builder_reproduces_test_signature() {
+    let payload = b"This is the content.";
+    let decoded = decode_signature(&test::COSE_SIGNATURE_BYTES, payload).unwrap();
+    let ee_cert = decoded[0].signer_cert.clone();
+    let cose_sign = CoseSignBuilder::new(payload)
+        .detached()
+        .nil_external_aad()
+        .certs(&[&test::P256_ROOT, &test::P256_INT])
+        .signer(SignatureAlgorithm::ES256, &ee_cert, |_| Ok(test::SIGNATURE_BYTES.to_vec()))
+        .build()
+        .unwrap();
+    assert_eq!(cose_sign, test::COSE_SIGNATURE_BYTES.to_vec());
+}
+
+#[test]
+fn test_cose_sign_builder_x509_profile() {
+    let payload = b"This is the content.";
+    let protected_header = Header {
+        kid: Some(vec![0x31, 0x31]),
+        ..Default::default()
+    };
+    let cose_sign = CoseSignBuilder::new(payload)
+        .certificate_profile(CertificateProfile::X509)
+        .protected_header(protected_header.clone())
+        .certs(&[&test::P256_INT])
+        .signer(SignatureAlgorithm::ES256, &[1, 2, 3], sign_with_to_sign)
+        .signer(SignatureAlgorithm::ES384, &[4, 5, 6], sign_with_to_sign)
+        .build()
+        .unwrap();
+    let options = DecodeOptions::new().certificate_profile(CertificateProfile::X509);
+    let cose_sign = decode_sign_with_options(&cose_sign, None, &options).unwrap();
+    assert_eq!(cose_sign.protected_header, protected_header);
+    let signature = &cose_sign.signatures[0];
+    assert_eq!(signature.signer_cert, vec![1, 2, 3]);
+    assert_eq!(signature.certs, vec![test::P256_INT.to_vec()]);
+    assert_eq!(signature.protected_header.x5chain, vec![vec![1, 2, 3], test::P256_INT.to_vec()]);
+    assert_eq!(signature.protected_header.kid, None);
+    assert_eq!(cose_sign.signatures[1].signer_cert, vec![4, 5, 6]);
+    for signature in &cose_sign.signatures {
+        assert_eq!(signature.signature, signature.to_verify);
+    }
+
+    // Without intermediate certificates the x5chain is a single bstr.
+    let cose_sign = CoseSignBuilder::new(payload)
+        .certificate_profile(CertificateProfile::X509)
+        .signer(SignatureAlgorithm::ES256, &[1, 2, 3], sign_with_to_sign)
+        .build()
+        .unwrap();
+    let decoded = decode_sign_with_options(&cose_sign, None, &options).unwrap();
+    assert_eq!(decoded.signatures[0].signer_cert, vec![1, 2, 3]);
+    assert!(decoded.signatures[0].certs.is_empty());
+    assert!(cose_sign.windows(5).any(|bytes| bytes == [0x18, 0x21, 0x43, 0x01, 0x02]));
+
+    // The legacy layout needs the kid with the certificates.
+    assert_eq!(decode_sign(&cose_sign, None).err(), Some(CoseError::MissingHeader));
+}
+
+#[test]
+fn test_cose_sign_builder_x509_bag() {
+    // Certificates in an x5bag of the body are available to all signers.
+    let payload = b"This is the content.";
+    let unprotected_header = Header {
+        x5bag: vec![test::P256_ROOT.to_vec(), test::P256_INT.to_vec()],
+        ..Default::default()
+    };
+    let cose_sign = CoseSignBuilder::new(payload)
+        .certificate_profile(CertificateProfile::X509)
+        .unprotected_header(unprotected_header)
+        .certs(&[&test::P256_INT])
+        .signer(SignatureAlgorithm::ES256, &[1, 2, 3], sign_with_to_sign)
+        .build()
+        .unwrap();
+    let options = DecodeOptions::new().certificate_profile(CertificateProfile::X509);
+    let cose_sign = decode_sign_with_options(&cose_sign, None, &options).unwrap();
+    assert_eq!(cose_sign.unprotected_header.x5bag.len(), 2);
+    assert_eq!(cose_sign.signatures[0].certs,
+               vec![test::P256_INT.to_vec(), test::P256_ROOT.to_vec()]);
+}
+
+#[test]
+fn test_cose_sign_builder_decode_signature() {
+    let payload = b"This is the content.";
+    let cose_sign = CoseSignBuilder::new(payload)
+        .detached()
+        .nil_external_aad()
+        .certs(&[&test::P256_ROOT, &test::P256_INT])
+        .signer(SignatureAlgorithm::ES256, &[1, 2, 3], sign_with_to_sign)
+        .signer(SignatureAlgorithm::PS256, &[4, 5, 6], sign_with_to_sign)
+        .build()
+        .unwrap();
+    let cose_signatures = decode_signature(&cose_sign, payload).unwrap();
+    assert_eq!(cose_signatures.len(), 2);
+    assert_eq!(cose_signatures[0].signature_type, SignatureAlgorithm::ES256);
+    assert_eq!(cose_signatures[0].signer_cert, vec![1, 2, 3]);
+    assert_eq!(cose_signatures[1].signature_type, SignatureAlgorithm::PS256);
+    assert_eq!(cose_signatures[1].signer_cert, vec![4, 5, 6]);
+    for cose_signature in cose_signatures {
+        assert_eq!(cose_signature.certs, vec![test::P256_ROOT.to_vec(), test::P256_INT.to_vec()]);
+        assert_eq!(cose_signature.signature, cose_signature.to_verify);
+    }
+}
+
+#[test]
+fn test_cose_sign_builder_embedded_payload() {
+    let payload = b"This is the content.";
+    let protected_header = Header {
+        content_type: Some(ContentType::Uint(0)),
+        ..Default::default()
+    };
+    let mut unprotected_header = Header::default();
+    unprotected_header.rest.insert(Label::Int(-70000), CborType::Bytes(vec![7]));
+    let cose_sign = CoseSignBuilder::new(payload)
+        .protected_header(protected_header.clone())
+        .unprotected_header(unprotected_header.clone())
+        .signer(SignatureAlgorithm::ES384, &[1], sign_with_to_sign)
+        .build()
+        .unwrap();
+
+    assert_eq!(decode_sign(&cose_sign, Some(payload)).err(), Some(CoseError::InvalidArgument));
+    let cose_sign = decode_sign(&cose_sign, None).unwrap();
+    assert_eq!(cose_sign.payload, payload.to_vec());
+    assert_eq!(cose_sign.protected_header, protected_header);
+    assert_eq!(cose_sign.unprotected_header, unprotected_header);
+    assert!(cose_sign.signatures[0].certs.is_empty());
+    assert_eq!(cose_sign.signatures[0].signature, cose_sign.signatures[0].to_verify);
+}
+
+#[test]
+fn test_cose_sign_builder_external_aad() {
+    let payload = b"This is the content.";
+    let cose_sign = CoseSignBuilder::new(payload)
+        .detached()
+        .external_aad(b"aad")
+        .signer(SignatureAlgorithm::ES512, &[1], sign_with_to_sign)
+        .build()
+        .unwrap();
+
+    let options = DecodeOptions::new().external_aad(b"aad");
+    let decoded = decode_sign_with_options(&cose_sign, Some(payload), &options).unwrap();
+    assert_eq!(decoded.signatures[0].signature, decoded.signatures[0].to_verify);
+    let decoded = decode_sign(&cose_sign, Some(payload)).unwrap();
+    assert_ne!(decoded.signatures[0].signature, decoded.signatures[0].to_verify);
+
+    // A nil external_aad has no room for external_aad.
+    let builder = CoseSignBuilder::new(payload)
+        .nil_external_aad()
+        .external_aad(b"aad")
+        .signer(SignatureAlgorithm::ES512, &[1], sign_with_to_sign);
+    assert_eq!(builder.build(), Err(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_cose_sign_builder_no_signers() {
+    let result = CoseSignBuilder::new(b"payload").build();
+    assert_eq!(result, Err(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_cose_sign_builder_invalid_headers() {
+    let signer_cert = [1];
+    let builder = |protected_header: Header, unprotected_header: Header| {
+        CoseSignBuilder::new(b"payload")
+            .protected_header(protected_header)
+            .unprotected_header(unprotected_header)
+            .signer(SignatureAlgorithm::ES256, &signer_cert, sign_with_to_sign)
+            .build()
+    };
+
+    // The kid is taken by the certificates.
+    let header = Header {
+        kid: Some(vec![1]),
+        ..Default::default()
+    };
+    assert_eq!(builder(header.clone(), Header::default()), Err(CoseError::InvalidArgument));
+    assert_eq!(builder(Header::default(), header), Err(CoseError::InvalidArgument));
+
+    // A label can't be in both buckets.
+    let header = Header {
+        content_type: Some(ContentType::Uint(0)),
+        ..Default::default()
+    };
+    assert_eq!(builder(header.clone(), header), Err(CoseError::InvalidArgument));
+
+    // crit has to be protected.
+    let header = Header {
+        crit: vec![Label::Int(3)],
+        ..Default::default()
+    };
+    assert_eq!(builder(Header::default(), header), Err(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_cose_sign_builder_signing_fails() {
+    let result = CoseSignBuilder::new(b"payload")
+        .signer(SignatureAlgorithm::ES256, &[1], |_| Err(CoseError::SigningFailed))
+        .build();
+    assert_eq!(result, Err(CoseError::SigningFailed));
+}
+
+#[test]
+fn test_cose_sign1_builder_rfc_example() {
+    // RFC 8152 Appendix C.2.1.
+    let unprotected_header = Header {
+        kid: Some(b"11".to_vec()),
+        ..Default::default()
+    };
+    let cose_sign1 = CoseSign1Builder::new(b"This is the content.")
+        .unprotected_header(unprotected_header)
+        .signer(SignatureAlgorithm::ES256, |to_sign| {
+            assert_eq!(to_sign, &test::COSE_SIGN1_TO_VERIFY[..]);
+            Ok(test::SIGN1_SIGNATURE_BYTES.to_vec())
+        })
+        .build()
+        .unwrap();
+    assert_eq!(cose_sign1, test::COSE_SIGN1_BYTES.to_vec());
+}
+
+#[test]
+fn test_cose_sign1_builder_untagged_detached() {
+    let payload = b"This is the content.";
+    let protected_header = Header {
+        content_type: Some(ContentType::Uint(0)),
+        ..Default::default()
+    };
+    let cose_sign1 = CoseSign1Builder::new(payload)
+        .detached()
+        .untagged()
+        .protected_header(protected_header)
+        .external_aad(b"aad")
+        .signer(SignatureAlgorithm::PS256, sign_with_to_sign)
+        .build()
+        .unwrap();
+    assert_eq!(cose_sign1[0], 0x84);
+
+    assert_eq!(decode_sign1(&cose_sign1, None).err(), Some(CoseError::InvalidArgument));
+    let options = DecodeOptions::new().external_aad(b"aad");
+    let decoded = decode_sign1_with_options(&cose_sign1, Some(payload), &options).unwrap();
+    assert_eq!(decoded.signature_type, SignatureAlgorithm::PS256);
+    assert_eq!(decoded.payload, payload.to_vec());
+    assert_eq!(decoded.protected_header.content_type, Some(ContentType::Uint(0)));
+    assert_eq!(decoded.signature, decoded.to_verify);
+}
+
+#[test]
+fn test_cose_sign1_builder_invalid_arguments() {
+    let result = CoseSign1Builder::new(b"payload").build();
+    assert_eq!(result, Err(CoseError::InvalidArgument));
+
+    // The alg is set from the signer.
+    let header = Header {
+        alg: Some(Label::Int(-7)),
+        ..Default::default()
+    };
+    let result = CoseSign1Builder::new(b"payload")
+        .protected_header(header)
+        .signer(SignatureAlgorithm::ES256, sign_with_to_sign)
+        .build();
+    assert_eq!(result, Err(CoseError::InvalidArgument));
+
+    // A label can't be in both buckets.
+    let header = Header {
+        kid: Some(vec![1]),
+        ..Default::default()
+    };
+    let result = CoseSign1Builder::new(b"payload")
+        .protected_header(header.clone())
+        .unprotected_header(header)
+        .signer(SignatureAlgorithm::ES256, sign_with_to_sign)
+        .build();
+    assert_eq!(result, Err(CoseError::InvalidArgument));
+
+    let result = CoseSign1Builder::new(b"payload")
+        .signer(SignatureAlgorithm::ES256, |_| Err(CoseError::SigningFailed))
+        .build();
+    assert_eq!(result, Err(CoseError::SigningFailed));
+}
+
+#[test]
+fn test_cose_sign_sign_with_verify_with() {
+    let payload = b"This is the content.";
+    let p256_key = TestKey(vec![1]);
+    let p384_key = TestKey(vec![2]);
+    let cose_sign = CoseSignBuilder::new(payload)
+        .sign_with(SignatureAlgorithm::ES256, &[1], &p256_key)
+        .sign_with(SignatureAlgorithm::ES384, &[2], &p384_key)
+        .build()
+        .unwrap();
+    let decoded = decode_sign(&cose_sign, None).unwrap();
+    assert_eq!(decoded.verify_with(&TestVerifier), Ok(()));
+
+    // The second signer signs with the wrong key.
+    let cose_sign = CoseSignBuilder::new(payload)
+        .sign_with(SignatureAlgorithm::ES256, &[1], &p256_key)
+        .sign_with(SignatureAlgorithm::ES384, &[3], &p384_key)
+        .build()
+        .unwrap();
+    let decoded = decode_sign(&cose_sign, None).unwrap();
+    assert_eq!(decoded.signatures[0].verify_with(&TestVerifier), Ok(()));
+    assert_eq!(decoded.verify_with(&TestVerifier), Err(CoseError::VerificationFailed));
+
+    // The verifier doesn't support the algorithm.
+    let cose_sign = CoseSignBuilder::new(payload)
+        .sign_with(SignatureAlgorithm::PS256, &[1], &p256_key)
+        .build()
+        .unwrap();
+    let decoded = decode_sign(&cose_sign, None).unwrap();
+    assert_eq!(decoded.verify_with(&TestVerifier), Err(CoseError::UnknownSignatureScheme));
+}
+
+#[test]
+fn test_cose_sign_builder_nil_external_aad_verify_with_decode_signature() {
+    // Signatures built with nil external_aad verify after decode_signature.
+    let payload = b"This is the content.";
+    let cose_sign = CoseSignBuilder::new(payload)
+        .detached()
+        .nil_external_aad()
+        .certs(&[&test::P256_INT])
+        .sign_with(SignatureAlgorithm::ES256, &[1], &TestKey(vec![1]))
+        .sign_with(SignatureAlgorithm::ES384, &[2], &TestKey(vec![2]))
+        .build()
+        .unwrap();
+    let cose_signatures = decode_signature(&cose_sign, payload).unwrap();
+    assert_eq!(cose_signatures.len(), 2);
+    for cose_signature in &cose_signatures {
+        assert_eq!(cose_signature.verify_with(&TestVerifier), Ok(()));
+    }
+    let options = DecodeOptions::new().nil_external_aad();
+    let cose_sign = decode_sign_with_options(&cose_sign, Some(payload), &options).unwrap();
+    assert_eq!(cose_sign.verify_with(&TestVerifier), Ok(()));
+}
+
+#[test]
+fn test_cose_sign1_sign_with_verify_with() {
+    let payload = b"This is the content.";
+    let key = TestKey(vec![1]);
+    let cose_sign1 = CoseSign1Builder::new(payload)
+        .sign_with(SignatureAlgorithm::ES512, &key)
+        .build()
+        .unwrap();
+    let decoded = decode_sign1(&cose_sign1, None).unwrap();
+    assert_eq!(decoded.verify_with(&[1], &TestVerifier), Ok(()));
+    assert_eq!(decoded.verify_with(&[2], &TestVerifier), Err(CoseError::VerificationFailed));
+}
+
+#[test]
+fn test_cose_sign1_builder_eddsa_algorithms() {
+    let algorithms = [(SignatureAlgorithm::EdDSA, -8),
+                      (SignatureAlgorithm::Ed25519, -19),
+                      (SignatureAlgorithm::Ed448, -53)];
+    for &(algorithm, alg) in &algorithms {
+        let cose_sign1 = CoseSign1Builder::new(b"payload")
+            .signer(algorithm, sign_with_to_sign)
+            .build()
+            .unwrap();
+        let decoded = decode_sign1(&cose_sign1, None).unwrap();
+        assert_eq!(decoded.protected_header.alg, Some(Label::Int(alg)));
+        assert_eq!(decoded.signature_type, algorithm);
+    }
+}
+
+#[test]
+fn test_cose_sign1_builder_rfc8812_algorithms() {
+    let algorithms = [(SignatureAlgorithm::ES256K, -47),
+                      (SignatureAlgorithm::PS384, -38),
+                      (SignatureAlgorithm::PS512, -39),
+                      (SignatureAlgorithm::RS256, -257),
+                      (SignatureAlgorithm::RS384, -258),
+                      (SignatureAlgorithm::RS512, -259)];
+    for &(algorithm, alg) in &algorithms {
+        let cose_sign1 = CoseSign1Builder::new(b"payload")
+            .signer(algorithm, sign_with_to_sign)
+            .build()
+            .unwrap();
+        let decoded = decode_sign1(&cose_sign1, None).unwrap();
+        assert_eq!(decoded.protected_header.alg, Some(Label::Int(alg)));
+        assert_eq!(decoded.signature_type, algorithm);
+    }
+
+    // RS256 is -257, which takes two bytes: 0x39 0x01 0x00.
+    let cose_sign1 = CoseSign1Builder::new(b"payload")
+        .signer(SignatureAlgorithm::RS256, sign_with_to_sign)
+        .build()
+        .unwrap();
+    assert_eq!(cose_sign1[..8], [0xd2, 0x84, 0x45, 0xa1, 0x01, 0x39, 0x01, 0x00]);
+}
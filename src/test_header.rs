@@ -0,0 +1,164 @@
+use CoseError;
+use header::{COSE_HEADER_ALG, COSE_HEADER_COUNTER_SIGNATURE, COSE_HEADER_CONTENT_TYPE,
+             COSE_HEADER_CRIT, COSE_HEADER_IV, COSE_HEADER_KID, COSE_HEADER_PARTIAL_IV,
+             COSE_HEADER_X5BAG, COSE_HEADER_X5CHAIN, COSE_HEADER_X5T, COSE_HEADER_X5U, CertHash,
+             ContentType, Header, Label};
+use cbor::CborType;
+use std::collections::BTreeMap;
+
+// Helper function to build a header map from the given key value pairs.
+fn build_header_map(entries: Vec<(CborType, CborType)>) -> BTreeMap<CborType, CborType> {
+    let mut map: BTreeMap<CborType, CborType> = BTreeMap::new();
+    for (key, value) in entries {
+        map.insert(key, value);
+    }
+    map
+}
+
+#[test]
+fn test_header_common_parameters() {
+    let counter_signature = CborType::Array(vec![CborType::Bytes(Vec::new()),
+                                                 CborType::Map(BTreeMap::new()),
+                                                 CborType::Bytes(vec![1])]);
+    let map = build_header_map(vec![
+        (CborType::Integer(COSE_HEADER_ALG), CborType::SignedInteger(-7)),
+        (CborType::Integer(COSE_HEADER_CRIT), CborType::Array(vec![CborType::Integer(100)])),
+        (CborType::Integer(COSE_HEADER_CONTENT_TYPE), CborType::String("text/plain".to_owned())),
+        (CborType::Integer(COSE_HEADER_KID), CborType::Bytes(vec![0x31, 0x31])),
+        (CborType::Integer(COSE_HEADER_PARTIAL_IV), CborType::Bytes(vec![0x61])),
+        (CborType::Integer(COSE_HEADER_COUNTER_SIGNATURE), counter_signature.clone()),
+        (CborType::Integer(100), CborType::Null),
+    ]);
+    let header = Header::from_map(&map).unwrap();
+    assert_eq!(header.alg, Some(Label::Int(-7)));
+    assert_eq!(header.crit, vec![Label::Int(100)]);
+    assert_eq!(header.content_type, Some(ContentType::Text("text/plain".to_owned())));
+    assert_eq!(header.kid, Some(vec![0x31, 0x31]));
+    assert_eq!(header.iv, None);
+    assert_eq!(header.partial_iv, Some(vec![0x61]));
+    assert_eq!(header.counter_signature, Some(counter_signature));
+    assert_eq!(header.rest.len(), 1);
+    assert_eq!(header.get(&Label::Int(100)), Some(&CborType::Null));
+}
+
+#[test]
+fn test_header_text_labels() {
+    let map = build_header_map(vec![
+        (CborType::Integer(COSE_HEADER_ALG), CborType::String("ES256".to_owned())),
+        (CborType::String("reserved".to_owned()), CborType::Bytes(vec![0])),
+        (CborType::SignedInteger(-70000), CborType::Integer(1)),
+    ]);
+    let header = Header::from_map(&map).unwrap();
+    assert_eq!(header.alg, Some(Label::Text("ES256".to_owned())));
+    assert_eq!(header.get(&Label::Text("reserved".to_owned())), Some(&CborType::Bytes(vec![0])));
+    assert_eq!(header.get(&Label::Int(-70000)), Some(&CborType::Integer(1)));
+}
+
+#[test]
+fn test_header_empty() {
+    let header = Header::from_map(&BTreeMap::new()).unwrap();
+    assert_eq!(header, Header::default());
+}
+
+#[test]
+fn test_header_invalid_label() {
+    let map = build_header_map(vec![(CborType::Bytes(vec![1]), CborType::Integer(1))]);
+    assert_eq!(Header::from_map(&map), Err(CoseError::UnexpectedType));
+}
+
+#[test]
+fn test_header_wrong_value_types() {
+    let wrong_values = vec![
+        (COSE_HEADER_ALG, CborType::Bytes(Vec::new())),
+        (COSE_HEADER_CRIT, CborType::Integer(1)),
+        (COSE_HEADER_CONTENT_TYPE, CborType::SignedInteger(-1)),
+        (COSE_HEADER_KID, CborType::String("kid".to_owned())),
+        (COSE_HEADER_IV, CborType::Null),
+        (COSE_HEADER_PARTIAL_IV, CborType::Integer(0)),
+        (COSE_HEADER_COUNTER_SIGNATURE, CborType::Bytes(Vec::new())),
+    ];
+    for (label, value) in wrong_values {
+        let map = build_header_map(vec![(CborType::Integer(label), value)]);
+        assert_eq!(Header::from_map(&map), Err(CoseError::UnexpectedType));
+    }
+}
+
+#[test]
+fn test_header_empty_crit() {
+    let map = build_header_map(vec![
+        (CborType::Integer(COSE_HEADER_CRIT), CborType::Array(Vec::new())),
+    ]);
+    assert_eq!(Header::from_map(&map), Err(CoseError::MalformedInput));
+}
+
+#[test]
+fn test_header_iv_and_partial_iv() {
+    let map = build_header_map(vec![
+        (CborType::Integer(COSE_HEADER_IV), CborType::Bytes(vec![1])),
+        (CborType::Integer(COSE_HEADER_PARTIAL_IV), CborType::Bytes(vec![1])),
+    ]);
+    assert_eq!(Header::from_map(&map), Err(CoseError::MalformedInput));
+}
+
+#[test]
+fn test_header_to_map() {
+    let map = build_header_map(vec![
+        (CborType::Integer(COSE_HEADER_ALG), CborType::SignedInteger(-7)),
+        (CborType::Integer(COSE_HEADER_CRIT), CborType::Array(vec![CborType::Integer(100)])),
+        (CborType::Integer(COSE_HEADER_CONTENT_TYPE), CborType::Integer(0)),
+        (CborType::Integer(COSE_HEADER_KID), CborType::Bytes(vec![0x31, 0x31])),
+        (CborType::Integer(COSE_HEADER_IV), CborType::Bytes(vec![0x61])),
+        (CborType::Integer(100), CborType::Null),
+        (CborType::SignedInteger(-100), CborType::Bytes(vec![0x62])),
+        (CborType::String("x".to_owned()), CborType::Integer(1)),
+    ]);
+    let header = Header::from_map(&map).unwrap();
+    assert_eq!(header.to_map(), map);
+    assert_eq!(Header::from_map(&header.to_map()).unwrap(), header);
+    assert!(Header::default().to_map().is_empty());
+}
+
+#[test]
+fn test_header_x509_parameters() {
+    let certs = CborType::Array(vec![CborType::Bytes(vec![1]), CborType::Bytes(vec![2])]);
+    let cert_hash = CborType::Array(vec![CborType::SignedInteger(-16), CborType::Bytes(vec![3])]);
+    let map = build_header_map(vec![
+        (CborType::Integer(COSE_HEADER_X5BAG), CborType::Bytes(vec![4])),
+        (CborType::Integer(COSE_HEADER_X5CHAIN), certs),
+        (CborType::Integer(COSE_HEADER_X5T), cert_hash),
+        (CborType::Integer(COSE_HEADER_X5U), CborType::String("https://example.com".to_owned())),
+    ]);
+    let header = Header::from_map(&map).unwrap();
+    assert_eq!(header.x5bag, vec![vec![4]]);
+    assert_eq!(header.x5chain, vec![vec![1], vec![2]]);
+    assert_eq!(header.x5t,
+               Some(CertHash {
+                   alg: Label::Int(-16),
+                   hash: vec![3],
+               }));
+    assert_eq!(header.x5u, Some("https://example.com".to_owned()));
+    assert!(header.rest.is_empty());
+    // A single certificate is encoded as a bstr, more as an array.
+    assert_eq!(header.to_map(), map);
+
+    // Arrays have at least two certificates.
+    for value in &[CborType::Array(vec![]), CborType::Array(vec![CborType::Bytes(vec![1])])] {
+        let map = build_header_map(vec![(CborType::Integer(COSE_HEADER_X5CHAIN), value.clone())]);
+        assert_eq!(Header::from_map(&map), Err(CoseError::MalformedInput));
+    }
+    let cert_hash = CborType::Array(vec![CborType::SignedInteger(-16)]);
+    let map = build_header_map(vec![(CborType::Integer(COSE_HEADER_X5T), cert_hash)]);
+    assert_eq!(Header::from_map(&map), Err(CoseError::MalformedInput));
+
+    let wrong_types = vec![
+        (COSE_HEADER_X5BAG, CborType::Integer(1)),
+        (COSE_HEADER_X5CHAIN, CborType::Array(vec![CborType::Bytes(vec![1]), CborType::Null])),
+        (COSE_HEADER_X5T, CborType::Bytes(vec![1])),
+        (COSE_HEADER_X5T, CborType::Array(vec![CborType::Null, CborType::Bytes(vec![1])])),
+        (COSE_HEADER_X5U, CborType::Bytes(vec![1])),
+    ];
+    for (label, value) in wrong_types {
+        let map = build_header_map(vec![(CborType::Integer(label), value)]);
+        assert_eq!(Header::from_map(&map), Err(CoseError::UnexpectedType));
+    }
+}
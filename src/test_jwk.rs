@@ -0,0 +1,233 @@
+use test_setup as test;
+use CoseError;
+use algorithm::Curve;
+use header::Label;
+use jwk::{decode_jwk, decode_jwk_set, encode_jwk, encode_jwk_set};
+use key::{CoseKey, CoseKeySet, KeyOperation, KeyParameters, RsaPrivateKey};
+use cbor::CborType;
+
+// The keys of example C.7 from RFC 9052 as JWKs.
+const EC2_P256_JWK: &str = "{\"crv\":\"P-256\",\
+                            \"d\":\"r_kHyZ-a06rmxM3yESK84r1otSg-aQcVStkRhA-iCM8\",\
+                            \"kid\":\"meriadoc.brandybuck@buckland.example\",\"kty\":\"EC\",\
+                            \"x\":\"Ze2loSV3wrroKUN_4zhwGhCqo3Xhu1td4QjeQ5wIVR0\",\
+                            \"y\":\"HlLtdXARY_f55A3fnzQbPcm6hgr34Mp8p-nuzQCE0Zw\"}";
+const SYMMETRIC_JWK: &str = "{\"k\":\"hJtXIZ2uSN5kbQfbtTNWbpdmhkV8FJG-Onbc6mxCcYg\",\
+                             \"kid\":\"our-secret\",\"kty\":\"oct\"}";
+
+#[test]
+fn test_jwk_ec2() {
+    let key = CoseKey::from_bytes(&test::COSE_KEY_EC2_P256).unwrap();
+    assert_eq!(encode_jwk(&key).unwrap(), EC2_P256_JWK);
+    assert_eq!(decode_jwk(EC2_P256_JWK).unwrap(), key);
+}
+
+#[test]
+fn test_jwk_symmetric() {
+    let key = CoseKey::from_bytes(&test::COSE_KEY_SYMMETRIC).unwrap();
+    assert_eq!(encode_jwk(&key).unwrap(), SYMMETRIC_JWK);
+    assert_eq!(decode_jwk(SYMMETRIC_JWK).unwrap(), key);
+}
+
+#[test]
+fn test_jwk_mac_algorithms() {
+    let key = CoseKey::from_bytes(&test::COSE_KEY_SYMMETRIC).unwrap();
+    for &(id, name) in &[(5, "HS256"), (6, "HS384"), (7, "HS512")] {
+        let mut key = key.clone();
+        key.alg = Some(Label::Int(id));
+        let jwk = encode_jwk(&key).unwrap();
+        assert!(jwk.starts_with(&format!("{{\"alg\":\"{}\",\"k\":", name)), "{}", jwk);
+        assert_eq!(decode_jwk(&jwk).unwrap(), key);
+    }
+    // HMAC algorithms need an oct key.
+    let jwk = EC2_P256_JWK.replace("{", "{\"alg\":\"HS256\",");
+    assert_eq!(decode_jwk(&jwk), Err(CoseError::MalformedInput));
+}
+
+#[test]
+fn test_jwk_okp() {
+    // The Ed25519 key of RFC 8037 appendix A.1, with whitespace.
+    let jwk = "{\"kty\":\"OKP\",\"crv\":\"Ed25519\",\n\
+               \"d\":\"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A\",\n\
+               \"x\":\"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo\" }";
+    let key = decode_jwk(jwk).unwrap();
+    assert_eq!(key.crv(), Some(Curve::Ed25519));
+    match key.params {
+        KeyParameters::Okp { ref d, .. } => {
+            assert_eq!(d.as_ref().unwrap()[..4], [0x9d, 0x61, 0xb1, 0x9d]);
+        }
+        _ => panic!("expected an OKP key"),
+    }
+
+    // The public key has alg and key_ops.
+    let key = CoseKey::from_bytes(&test::COSE_KEY_OKP_ED25519).unwrap();
+    let jwk = encode_jwk(&key).unwrap();
+    assert_eq!(jwk,
+               "{\"alg\":\"EdDSA\",\"crv\":\"Ed25519\",\"key_ops\":[\"sign\",\"verify\"],\
+                \"kty\":\"OKP\",\"x\":\"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo\"}");
+    let decoded = decode_jwk(&jwk).unwrap();
+    assert_eq!(decoded.alg, Some(Label::Int(-8)));
+    assert_eq!(decoded.key_ops, vec![KeyOperation::Sign, KeyOperation::Verify]);
+    assert_eq!(decoded, key);
+}
+
+#[test]
+fn test_jwk_rsa() {
+    let mut key = CoseKey::new(KeyParameters::Rsa {
+        n: vec![0xc5; 256],
+        e: vec![0x01, 0x00, 0x01],
+        private: None,
+    });
+    key.alg = Some(Label::Int(-257));
+    let jwk = encode_jwk(&key).unwrap();
+    assert!(jwk.starts_with("{\"alg\":\"RS256\",\"e\":\"AQAB\",\"kty\":\"RSA\",\"n\":\"xcXF"));
+    assert_eq!(decode_jwk(&jwk).unwrap(), key);
+
+    key.params = KeyParameters::Rsa {
+        n: vec![0xc5; 256],
+        e: vec![0x01, 0x00, 0x01],
+        private: Some(RsaPrivateKey {
+            d: vec![1; 256],
+            p: vec![2; 128],
+            q: vec![3; 128],
+            dp: vec![4; 128],
+            dq: vec![5; 128],
+            qinv: vec![6; 128],
+        }),
+    };
+    let jwk = encode_jwk(&key).unwrap();
+    assert!(jwk.contains("\"qi\":\"BgYG"));
+    assert_eq!(decode_jwk(&jwk).unwrap(), key);
+
+    // JWK allows private keys with only d, COSE doesn't.
+    let jwk = "{\"kty\":\"RSA\",\"n\":\"xcXF\",\"e\":\"AQAB\",\"d\":\"AQEB\"}";
+    assert_eq!(decode_jwk(jwk), Err(CoseError::UnmappableParameter));
+    let jwk = "{\"kty\":\"RSA\",\"n\":\"xcXF\",\"e\":\"AQAB\",\"oth\":[]}";
+    assert_eq!(decode_jwk(jwk), Err(CoseError::Unimplemented));
+}
+
+#[test]
+fn test_jwk_set() {
+    let key_set = CoseKeySet::from_bytes(&test::COSE_KEY_SET).unwrap();
+    let jwk_set = encode_jwk_set(&key_set).unwrap();
+    assert!(jwk_set.starts_with("{\"keys\":[{\"crv\":\"P-256\","));
+    assert_eq!(decode_jwk_set(&jwk_set).unwrap(), key_set);
+
+    assert_eq!(decode_jwk_set("{\"keys\":[]}"), Err(CoseError::MalformedInput));
+    assert_eq!(decode_jwk_set("{}"), Err(CoseError::MalformedInput));
+    assert_eq!(decode_jwk_set("{\"keys\":{}}"), Err(CoseError::UnexpectedType));
+    assert_eq!(decode_jwk_set("{\"keys\":[1]}"), Err(CoseError::UnexpectedType));
+    let jwk_set = format!("{{\"keys\":[{}],\"x\":1}}", SYMMETRIC_JWK);
+    assert_eq!(decode_jwk_set(&jwk_set), Err(CoseError::UnmappableParameter));
+    assert_eq!(encode_jwk_set(&CoseKeySet { keys: vec![] }), Err(CoseError::MalformedInput));
+}
+
+#[test]
+fn test_jwk_unmappable_parameters() {
+    // The EC key of RFC 7517 appendix A.1 has a use member.
+    let jwk = "{\"kty\":\"EC\",\"crv\":\"P-256\",\
+               \"x\":\"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4\",\
+               \"y\":\"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM\",\
+               \"use\":\"enc\",\"kid\":\"1\"}";
+    assert_eq!(decode_jwk(jwk), Err(CoseError::UnmappableParameter));
+    assert!(decode_jwk(&jwk.replace(",\"use\":\"enc\"", "")).is_ok());
+
+    // Algorithms and key operations without a COSE counterpart.
+    let jwk = "{\"kty\":\"oct\",\"alg\":\"A128KW\",\"k\":\"GawgguFyGrWKav7AX4VKUg\"}";
+    assert_eq!(decode_jwk(jwk), Err(CoseError::UnmappableParameter));
+    let jwk = "{\"kty\":\"oct\",\"key_ops\":[\"sign\",\"foo\"],\"k\":\"GawgguFyGrWKav7AX4VKUg\"}";
+    assert_eq!(decode_jwk(jwk), Err(CoseError::UnmappableParameter));
+
+    // COSE parameters without a JWK counterpart.
+    let key = CoseKey::from_bytes(&test::COSE_KEY_SYMMETRIC).unwrap();
+    let mut unmappable = vec![key.clone(), key.clone(), key.clone(), key.clone(), key.clone()];
+    unmappable[0].base_iv = Some(vec![1; 8]);
+    unmappable[1].rest.insert(Label::Int(-100), CborType::Integer(1));
+    unmappable[2].key_ops = vec![KeyOperation::MacCreate];
+    // HMAC 256/64.
+    unmappable[3].alg = Some(Label::Int(4));
+    unmappable[4].kid = Some(vec![0xff]);
+    for key in &unmappable {
+        assert_eq!(encode_jwk(key), Err(CoseError::UnmappableParameter));
+    }
+
+    // JWKs always have the public key.
+    let key = CoseKey::new(KeyParameters::Okp {
+        crv: Curve::Ed25519,
+        x: None,
+        d: Some(vec![1; 32]),
+    });
+    assert_eq!(encode_jwk(&key), Err(CoseError::MalformedInput));
+}
+
+#[test]
+fn test_jwk_invalid() {
+    let invalid_json = ["",
+                        "{",
+                        "{\"kty\":\"oct\"",
+                        "{\"kty\" \"oct\"}",
+                        "{kty:\"oct\"}",
+                        "{\"kty\":\"oct\",}",
+                        "{\"kty\":\"oct\",\"kty\":\"oct\"}",
+                        "{\"a\":\"\\x\"}",
+                        "{\"a\":\"\\ud800\"}",
+                        "{\"a\":tru}",
+                        "{\"a\":-}",
+                        "{\"a\":01}",
+                        "{\"a\":-01}",
+                        "{\"a\":1.}",
+                        "{\"a\":.5}",
+                        "{\"a\":1.e5}",
+                        "{\"a\":1e}",
+                        "{\"a\":1e+}",
+                        "{\"a\":1-2}",
+                        "{\"a\":--1}",
+                        "{\"a\":+1}",
+                        "{} {}"];
+    for jwk in &invalid_json {
+        assert_eq!(decode_jwk(jwk), Err(CoseError::DecodingFailure), "{}", jwk);
+    }
+    // Valid numbers are parsed, but have no COSE_Key counterpart.
+    for number in &["0", "-0", "10", "1.5", "-0.25e+3", "2E-7"] {
+        let jwk = format!("{{\"kty\":\"oct\",\"k\":\"AQ\",\"a\":{}}}", number);
+        assert_eq!(decode_jwk(&jwk), Err(CoseError::UnmappableParameter), "{}", number);
+    }
+    // Deeply nested JSON fails rather than overflowing the stack.
+    let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert_eq!(decode_jwk(&nested(16)), Err(CoseError::UnexpectedType));
+    assert_eq!(decode_jwk(&nested(17)), Err(CoseError::DecodingFailure));
+    assert_eq!(decode_jwk(&"[".repeat(1_000_000)), Err(CoseError::DecodingFailure));
+    assert_eq!(decode_jwk_set(&"{\"keys\":".repeat(100_000)), Err(CoseError::DecodingFailure));
+    // Invalid base64url, padding isn't allowed.
+    for k in &["GawgguFyGrWKav7AX4VKUg==", "Gaw+", "G", "GaB"] {
+        let jwk = format!("{{\"kty\":\"oct\",\"k\":\"{}\"}}", k);
+        assert_eq!(decode_jwk(&jwk), Err(CoseError::DecodingFailure), "{}", k);
+    }
+
+    assert_eq!(decode_jwk("[]"), Err(CoseError::UnexpectedType));
+    assert_eq!(decode_jwk("{\"kty\":1}"), Err(CoseError::UnexpectedType));
+    assert_eq!(decode_jwk("{\"kty\":\"oct\",\"k\":true}"), Err(CoseError::UnexpectedType));
+    assert_eq!(decode_jwk("{\"kty\":\"oct\",\"k\":\"AQ\",\"key_ops\":\"sign\"}"),
+               Err(CoseError::UnexpectedType));
+    assert_eq!(decode_jwk("{\"kty\":\"oct\",\"k\":\"AQ\",\"key_ops\":[]}"),
+               Err(CoseError::MalformedInput));
+    assert_eq!(decode_jwk("{\"kty\":\"foo\"}"), Err(CoseError::UnexpectedHeaderValue));
+    assert_eq!(decode_jwk("{\"kty\":\"OKP\",\"crv\":\"P-256\",\"x\":\"AQ\"}"),
+               Err(CoseError::UnexpectedHeaderValue));
+    assert_eq!(decode_jwk("{\"kty\":\"OKP\",\"crv\":\"X25519\",\"x\":\"AQ\"}"),
+               Err(CoseError::MalformedInput));
+    assert_eq!(decode_jwk("{\"kty\":\"OKP\",\"crv\":\"Ed1\",\"x\":\"AQ\"}"),
+               Err(CoseError::UnexpectedHeaderValue));
+    assert_eq!(decode_jwk("{\"kty\":\"EC\",\"crv\":\"P-256\",\"d\":\"AQ\"}"),
+               Err(CoseError::MalformedInput));
+    assert_eq!(decode_jwk("{\"kid\":\"1\"}"), Err(CoseError::MalformedInput));
+
+    // Escaped characters and other JSON values.
+    let jwk = "{\"kty\":\"oct\",\"k\":\"AQ\",\"kid\":\"\\\"\\u00e9\\ud83d\\ude00\\/\\n\"}";
+    let key = decode_jwk(jwk).unwrap();
+    assert_eq!(key.kid, Some("\"\u{e9}\u{1f600}/\n".as_bytes().to_vec()));
+    assert_eq!(encode_jwk(&key).unwrap(),
+               "{\"k\":\"AQ\",\"kid\":\"\\\"\u{e9}\u{1f600}/\\n\",\"kty\":\"oct\"}");
+    let jwk = "{\"kty\":\"oct\",\"k\":\"AQ\",\"ext\":[true,false,null,-1.5e3,{}]}";
+    assert_eq!(decode_jwk(jwk), Err(CoseError::UnmappableParameter));
+}
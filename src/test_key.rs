@@ -0,0 +1,340 @@
+use test_setup as test;
+use CoseError;
+use algorithm::{Curve, KeyType};
+use cbor::CborType;
+use header::Label;
+use key::{COSE_KEY_ALG, COSE_KEY_CRV, COSE_KEY_D, COSE_KEY_KEY_OPS, COSE_KEY_KID, COSE_KEY_KTY,
+          COSE_KEY_RSA_P, COSE_KEY_RSA_T_I, COSE_KEY_X, COSE_KEY_Y, CoseKey, CoseKeySet,
+          KeyOperation, KeyParameters, RsaPrivateKey};
+use std::collections::BTreeMap;
+
+// Helper function to build a key map from the given label value pairs.
+fn build_key_map(entries: Vec<(i64, CborType)>) -> BTreeMap<CborType, CborType> {
+    let mut map: BTreeMap<CborType, CborType> = BTreeMap::new();
+    for (label, value) in entries {
+        map.insert(Label::Int(label).to_cbor(), value);
+    }
+    map
+}
+
+fn ec2_p256_map() -> Vec<(i64, CborType)> {
+    vec![
+        (COSE_KEY_KTY, CborType::Integer(2)),
+        (COSE_KEY_CRV, CborType::Integer(1)),
+        (COSE_KEY_X, CborType::Bytes(vec![1; 32])),
+        (COSE_KEY_Y, CborType::Bytes(vec![2; 32])),
+    ]
+}
+
+fn rsa_key() -> CoseKey {
+    let mut key = CoseKey::new(KeyParameters::Rsa {
+        n: vec![0xc5; 256],
+        e: vec![0x01, 0x00, 0x01],
+        private: Some(RsaPrivateKey {
+            d: vec![1; 256],
+            p: vec![2; 128],
+            q: vec![3; 128],
+            dp: vec![4; 128],
+            dq: vec![5; 128],
+            qinv: vec![6; 128],
+        }),
+    });
+    key.alg = Some(Label::Int(-37));
+    key
+}
+
+#[test]
+fn test_key_ec2() {
+    let key = CoseKey::from_bytes(&test::COSE_KEY_EC2_P256).unwrap();
+    assert_eq!(key.kty(), KeyType::EC2);
+    assert_eq!(key.crv(), Some(Curve::P256));
+    assert_eq!(key.kid, Some(b"meriadoc.brandybuck@buckland.example".to_vec()));
+    assert_eq!(key.alg, None);
+    assert!(key.key_ops.is_empty());
+    match key.params {
+        KeyParameters::Ec2 { ref x, ref y, ref d, .. } => {
+            assert_eq!(x.as_ref().unwrap()[..4], [0x65, 0xed, 0xa5, 0xa1]);
+            assert_eq!(y.as_ref().unwrap()[..4], [0x1e, 0x52, 0xed, 0x75]);
+            assert_eq!(d.as_ref().unwrap()[..4], [0xaf, 0xf9, 0x07, 0xc9]);
+        }
+        _ => panic!("expected an EC2 key"),
+    }
+    assert_eq!(key.to_bytes().unwrap(), test::COSE_KEY_EC2_P256.to_vec());
+}
+
+#[test]
+fn test_key_okp() {
+    let key = CoseKey::from_bytes(&test::COSE_KEY_OKP_ED25519).unwrap();
+    assert_eq!(key.kty(), KeyType::OKP);
+    assert_eq!(key.crv(), Some(Curve::Ed25519));
+    assert_eq!(key.alg, Some(Label::Int(-8)));
+    assert_eq!(key.key_ops, vec![KeyOperation::Sign, KeyOperation::Verify]);
+    assert_eq!(key.to_bytes().unwrap(), test::COSE_KEY_OKP_ED25519.to_vec());
+}
+
+#[test]
+fn test_key_symmetric() {
+    let key = CoseKey::from_bytes(&test::COSE_KEY_SYMMETRIC).unwrap();
+    assert_eq!(key.kty(), KeyType::Symmetric);
+    assert_eq!(key.crv(), None);
+    assert_eq!(key.kid, Some(b"our-secret".to_vec()));
+    match key.params {
+        KeyParameters::Symmetric { ref k } => assert_eq!(k.len(), 32),
+        _ => panic!("expected a symmetric key"),
+    }
+    assert_eq!(key.to_bytes().unwrap(), test::COSE_KEY_SYMMETRIC.to_vec());
+}
+
+#[test]
+fn test_key_rsa() {
+    let key = rsa_key();
+    let bytes = key.to_bytes().unwrap();
+    assert_eq!(CoseKey::from_bytes(&bytes).unwrap(), key);
+
+    // A public key.
+    let mut public_key = key.clone();
+    if let KeyParameters::Rsa { ref mut private, .. } = public_key.params {
+        *private = None;
+    }
+    let bytes = public_key.to_bytes().unwrap();
+    assert_eq!(CoseKey::from_bytes(&bytes).unwrap(), public_key);
+
+    // The private parameters are incomplete.
+    let mut map = public_key.to_map().unwrap();
+    map.insert(Label::Int(COSE_KEY_RSA_P).to_cbor(), CborType::Bytes(vec![2; 128]));
+    assert_eq!(CoseKey::from_map(&map), Err(CoseError::MalformedInput));
+    let mut map = key.to_map().unwrap();
+    map.remove(&Label::Int(COSE_KEY_RSA_P).to_cbor());
+    assert_eq!(CoseKey::from_map(&map), Err(CoseError::MalformedInput));
+
+    // Multi-prime keys are not supported.
+    let mut map = key.to_map().unwrap();
+    map.insert(Label::Int(COSE_KEY_RSA_T_I).to_cbor(), CborType::Bytes(vec![7; 128]));
+    assert_eq!(CoseKey::from_map(&map), Err(CoseError::Unimplemented));
+}
+
+#[test]
+fn test_key_set() {
+    let key_set = CoseKeySet::from_bytes(&test::COSE_KEY_SET).unwrap();
+    assert_eq!(key_set.keys.len(), 3);
+    assert_eq!(key_set.keys[0], CoseKey::from_bytes(&test::COSE_KEY_EC2_P256).unwrap());
+    assert_eq!(key_set.keys[1], CoseKey::from_bytes(&test::COSE_KEY_SYMMETRIC).unwrap());
+    assert_eq!(key_set.keys[2], CoseKey::from_bytes(&test::COSE_KEY_OKP_ED25519).unwrap());
+    let found = key_set.find_by_kid(b"our-secret");
+    assert_eq!(found, vec![&key_set.keys[1]]);
+    assert!(key_set.find_by_kid(b"11").is_empty());
+    assert_eq!(key_set.to_bytes().unwrap(), test::COSE_KEY_SET.to_vec());
+
+    // Key sets must not be empty and contain only keys.
+    assert_eq!(CoseKeySet::from_bytes(&[0x80]), Err(CoseError::MalformedInput));
+    assert_eq!(CoseKeySet { keys: vec![] }.to_bytes(), Err(CoseError::MalformedInput));
+    assert_eq!(CoseKeySet::from_bytes(&[0x81, 0x01]), Err(CoseError::UnexpectedType));
+    assert_eq!(CoseKeySet::from_bytes(&[0xa0]), Err(CoseError::UnexpectedType));
+    assert_eq!(CoseKeySet::from_bytes(&[0x82]), Err(CoseError::DecodingFailure));
+}
+
+#[test]
+fn test_key_other_parameters() {
+    let mut entries = ec2_p256_map();
+    entries.push((-100, CborType::Bytes(vec![1])));
+    entries.push((100, CborType::Integer(1)));
+    let key = CoseKey::from_map(&build_key_map(entries.clone())).unwrap();
+    assert_eq!(key.rest.len(), 2);
+    assert_eq!(key.rest.get(&Label::Int(-100)), Some(&CborType::Bytes(vec![1])));
+    assert_eq!(key.to_map().unwrap(), build_key_map(entries));
+
+    let mut entries = ec2_p256_map();
+    entries.push((COSE_KEY_KEY_OPS, CborType::Array(vec![CborType::Integer(10),
+                                                          CborType::Integer(100)])));
+    let key = CoseKey::from_map(&build_key_map(entries)).unwrap();
+    assert_eq!(key.key_ops, vec![KeyOperation::MacVerify, KeyOperation::Other(Label::Int(100))]);
+}
+
+#[test]
+fn test_key_invalid_common_parameters() {
+    // The key type is required and must be known.
+    let mut entries = ec2_p256_map();
+    entries.remove(0);
+    assert_eq!(CoseKey::from_map(&build_key_map(entries)), Err(CoseError::MalformedInput));
+    let mut entries = ec2_p256_map();
+    entries[0].1 = CborType::Integer(7);
+    assert_eq!(CoseKey::from_map(&build_key_map(entries)), Err(CoseError::UnexpectedHeaderValue));
+
+    // The common parameters must have the right type.
+    for &(label, ref value) in &[(COSE_KEY_KID, CborType::Integer(1)),
+                                 (COSE_KEY_ALG, CborType::Bytes(vec![1])),
+                                 (COSE_KEY_KEY_OPS, CborType::Integer(1)),
+                                 (COSE_KEY_X, CborType::Integer(1))] {
+        let mut entries = ec2_p256_map();
+        entries.push((label, value.clone()));
+        assert_eq!(CoseKey::from_map(&build_key_map(entries)), Err(CoseError::UnexpectedType));
+    }
+
+    // Key operations must not be empty.
+    let mut entries = ec2_p256_map();
+    entries.push((COSE_KEY_KEY_OPS, CborType::Array(vec![])));
+    assert_eq!(CoseKey::from_map(&build_key_map(entries)), Err(CoseError::MalformedInput));
+}
+
+#[test]
+fn test_key_invalid_curve_parameters() {
+    // The curve is required, must be known and fit the key type.
+    for crv in &[None, Some(100), Some(Curve::Ed25519.id())] {
+        let mut entries = ec2_p256_map();
+        entries.remove(1);
+        let expected = match *crv {
+            Some(crv) => {
+                entries.push((COSE_KEY_CRV, CborType::Integer(crv as u64)));
+                CoseError::UnexpectedHeaderValue
+            }
+            None => CoseError::MalformedInput,
+        };
+        assert_eq!(CoseKey::from_map(&build_key_map(entries)), Err(expected));
+    }
+
+    // EC2 keys need both coordinates or the private key, and the coordinates must have the size
+    // of the curve.
+    let mut entries = ec2_p256_map();
+    entries.remove(3);
+    assert_eq!(CoseKey::from_map(&build_key_map(entries.clone())), Err(CoseError::MalformedInput));
+    entries.push((COSE_KEY_Y, CborType::Bytes(vec![2; 31])));
+    assert_eq!(CoseKey::from_map(&build_key_map(entries)), Err(CoseError::MalformedInput));
+    let key = CoseKey::new(KeyParameters::Ec2 {
+        crv: Curve::P384,
+        x: None,
+        y: None,
+        d: Some(vec![3; 48]),
+    });
+    assert_eq!(key.validate(), Ok(()));
+    let key = CoseKey::new(KeyParameters::Ec2 {
+        crv: Curve::P384,
+        x: None,
+        y: None,
+        d: Some(vec![3; 49]),
+    });
+    assert_eq!(key.validate(), Err(CoseError::MalformedInput));
+
+    // OKP keys need the public or the private key.
+    let key = CoseKey::new(KeyParameters::Okp {
+        crv: Curve::X25519,
+        x: None,
+        d: None,
+    });
+    assert_eq!(key.validate(), Err(CoseError::MalformedInput));
+    let key = CoseKey::new(KeyParameters::Okp {
+        crv: Curve::Ed448,
+        x: Some(vec![1; 56]),
+        d: None,
+    });
+    assert_eq!(key.validate(), Err(CoseError::MalformedInput));
+    let key = CoseKey::new(KeyParameters::Okp {
+        crv: Curve::P256,
+        x: Some(vec![1; 32]),
+        d: None,
+    });
+    assert_eq!(key.to_bytes(), Err(CoseError::MalformedInput));
+
+    let mut entries = ec2_p256_map();
+    entries.push((COSE_KEY_D, CborType::Bytes(vec![])));
+    assert_eq!(CoseKey::from_map(&build_key_map(entries)), Err(CoseError::MalformedInput));
+}
+
+#[test]
+fn test_key_invalid_rsa_and_symmetric_parameters() {
+    let mut key = rsa_key();
+    if let KeyParameters::Rsa { ref mut private, .. } = key.params {
+        private.as_mut().unwrap().dq = Vec::new();
+    }
+    assert_eq!(key.to_bytes(), Err(CoseError::MalformedInput));
+    let key = CoseKey::new(KeyParameters::Rsa {
+        n: Vec::new(),
+        e: vec![3],
+        private: None,
+    });
+    assert_eq!(key.validate(), Err(CoseError::MalformedInput));
+    let key = CoseKey::new(KeyParameters::Symmetric { k: Vec::new() });
+    assert_eq!(key.to_bytes(), Err(CoseError::MalformedInput));
+    let map = build_key_map(vec![(COSE_KEY_KTY, CborType::Integer(4))]);
+    assert_eq!(CoseKey::from_map(&map), Err(CoseError::MalformedInput));
+}
+
+#[test]
+fn test_key_algorithm() {
+    let key = CoseKey::from_bytes(&test::COSE_KEY_EC2_P256).unwrap();
+    for &(alg, valid) in &[(-7, true), (-35, false), (-47, false), (-37, false), (-8, false),
+                           (-100, true)] {
+        let mut key = key.clone();
+        key.alg = Some(Label::Int(alg));
+        let expected = if valid { Ok(()) } else { Err(CoseError::MalformedInput) };
+        assert_eq!(key.validate(), expected);
+    }
+
+    // EdDSA works with both signature curves, but not with X25519.
+    let mut key = CoseKey::from_bytes(&test::COSE_KEY_OKP_ED25519).unwrap();
+    assert_eq!(key.validate(), Ok(()));
+    key.alg = Some(Label::Int(-53));
+    assert_eq!(key.validate(), Err(CoseError::MalformedInput));
+    key.params = KeyParameters::Okp {
+        crv: Curve::Ed448,
+        x: Some(vec![1; 57]),
+        d: None,
+    };
+    assert_eq!(key.validate(), Ok(()));
+    key.alg = Some(Label::Int(-8));
+    assert_eq!(key.validate(), Ok(()));
+    key.params = KeyParameters::Okp {
+        crv: Curve::X25519,
+        x: Some(vec![1; 32]),
+        d: None,
+    };
+    assert_eq!(key.validate(), Err(CoseError::MalformedInput));
+
+    // RSA algorithms need RSA keys.
+    let mut key = rsa_key();
+    key.alg = Some(Label::Int(-257));
+    assert_eq!(key.validate(), Ok(()));
+    key.alg = Some(Label::Int(-7));
+    assert_eq!(key.validate(), Err(CoseError::MalformedInput));
+    let mut key = CoseKey::from_bytes(&test::COSE_KEY_SYMMETRIC).unwrap();
+    key.alg = Some(Label::Int(-37));
+    assert_eq!(key.validate(), Err(CoseError::MalformedInput));
+}
+
+#[test]
+fn test_key_thumbprint() {
+    // The hash is applied to the deterministic encoding of the required parameters, without the
+    // kid and the private key.
+    let mut key = CoseKey::from_bytes(&test::COSE_KEY_EC2_P256).unwrap();
+    let input = key.thumbprint_input().unwrap();
+    assert_eq!(input, test::COSE_KEY_EC2_P256_THUMBPRINT_INPUT.to_vec());
+    assert_eq!(key.thumbprint(|input| input[..4].to_vec()), Ok(vec![0xa4, 0x01, 0x02, 0x20]));
+    key.set_thumbprint_kid(|input| input.to_vec()).unwrap();
+    assert_eq!(key.kid, Some(input.clone()));
+    assert_eq!(key.thumbprint_input().unwrap(), input);
+
+    let key = CoseKey::from_bytes(&test::COSE_KEY_OKP_ED25519).unwrap();
+    let input = key.thumbprint_input().unwrap();
+    assert_eq!(input[..8], [0xa3, 0x01, 0x01, 0x20, 0x06, 0x21, 0x58, 0x20]);
+    assert_eq!(input.len(), 8 + 32);
+    let key = CoseKey::from_bytes(&test::COSE_KEY_SYMMETRIC).unwrap();
+    let input = key.thumbprint_input().unwrap();
+    assert_eq!(input[..5], [0xa2, 0x01, 0x04, 0x20, 0x58]);
+    let input = rsa_key().thumbprint_input().unwrap();
+    assert_eq!(input[..6], [0xa3, 0x01, 0x03, 0x20, 0x59, 0x01]);
+    assert_eq!(input[input.len() - 5..], [0x21, 0x43, 0x01, 0x00, 0x01]);
+
+    // Private keys need the public key.
+    let key = CoseKey::new(KeyParameters::Ec2 {
+        crv: Curve::P256,
+        x: None,
+        y: None,
+        d: Some(vec![3; 32]),
+    });
+    assert_eq!(key.thumbprint_input(), Err(CoseError::MalformedInput));
+    let key = CoseKey::new(KeyParameters::Okp {
+        crv: Curve::Ed25519,
+        x: None,
+        d: Some(vec![3; 32]),
+    });
+    assert_eq!(key.thumbprint(|input| input.to_vec()), Err(CoseError::MalformedInput));
+}
@@ -0,0 +1,726 @@
+// Keys and end-entity certificates for testing the signing and verification backends.
+// All keys here are from pykey.py/pycert.py from mozilla-central, as in the sign_verify example.
+// NSS is lenient when reading PKCS#8. The copies of PKCS8_P256_EE and PKCS8_P521_EE here are
+// fixed to be well-formed: the former had a trailing newline byte and the latter an invalid
+// public key.
+
+use test_setup as test;
+use {CoseError, SignatureAlgorithm, Signer, Verifier};
+use decoder::{decode_sign, decode_signature};
+use encoder::CoseSignBuilder;
+use x509::ChainValidator;
+#[cfg(any(feature = "openssl", feature = "rustcrypto"))]
+use {Mac, MacAlgorithm};
+
+const PAYLOAD: &[u8] = b"This is the content.";
+
+// Sign a COSE_Sign with `signer` and verify it with `verifier`, then check that the verification
+// fails after tampering with the signature.
+pub fn test_sign_verify<S, V>(algorithm: SignatureAlgorithm, cert: &[u8], signer: &S, verifier: &V)
+where
+    S: Signer + ?Sized,
+    V: Verifier,
+{
+    let cose_sign = CoseSignBuilder::new(PAYLOAD)
+        .signer(algorithm, cert, |to_sign| signer.sign(algorithm, to_sign))
+        .build()
+        .unwrap();
+    let mut cose_sign = decode_sign(&cose_sign, None).unwrap();
+    assert_eq!(cose_sign.verify_with(verifier), Ok(()));
+
+    // Tamper with the signature.
+    cose_sign.signatures[0].signature[3] ^= 1;
+    assert_eq!(cose_sign.verify_with(verifier), Err(CoseError::VerificationFailed));
+}
+
+// Validate the chain of the test signature and of the X.509 test hierarchy with `verifier`, then
+// check that the validation fails with the wrong trust anchor or a tampered certificate.
+pub fn test_validate_chain(verifier: &dyn Verifier) {
+    // The certificates of the test signature are valid in 2020. P256_INT isn't signed by the key
+    // of P256_ROOT.
+    let cose_signatures = decode_signature(&test::COSE_SIGNATURE_BYTES, PAYLOAD).unwrap();
+    let validator = ChainValidator::new(1_577_836_800).trust_anchor(&test::P256_INT);
+    let path = cose_signatures[0].validate_chain(&validator, verifier).unwrap();
+    assert_eq!(path, vec![cose_signatures[0].signer_cert.clone(), test::P256_INT.to_vec()]);
+    let validator = ChainValidator::new(1_577_836_800).trust_anchor(&test::P256_ROOT);
+    assert_eq!(cose_signatures[0].validate_chain(&validator, verifier),
+               Err(CoseError::VerificationFailed));
+
+    let validator = ChainValidator::new(1_780_272_000).trust_anchor(&test::X509_ROOT);
+    let certs = vec![test::X509_INT.to_vec()];
+    assert!(validator.validate(&test::X509_EE, &certs, verifier).is_ok());
+    // Tamper with the serial number.
+    let mut cert = test::X509_EE.to_vec();
+    cert[14] ^= 1;
+    assert_eq!(validator.validate(&cert, &certs, verifier), Err(CoseError::VerificationFailed));
+}
+
+// Compute and verify the HMACs of the test vectors with the MACs `make_mac` creates from a key,
+// then check that the verification fails with the wrong key.
+#[cfg(any(feature = "openssl", feature = "rustcrypto"))]
+pub fn test_mac<M>(make_mac: M)
+where
+    M: Fn(&[u8]) -> Box<dyn Mac>,
+{
+    use decoder::{decode_mac, decode_mac0};
+    use encoder::CoseMac0Builder;
+    use header::Header;
+    use key::{CoseKey, KeyParameters};
+
+    let k = match CoseKey::from_bytes(&test::COSE_KEY_SYMMETRIC).unwrap().params {
+        KeyParameters::Symmetric { k } => k,
+        _ => panic!("expected a symmetric key"),
+    };
+    let mac = make_mac(&k);
+    let header = Header {
+        kid: Some(b"our-secret".to_vec()),
+        ..Header::default()
+    };
+    let vectors: [(MacAlgorithm, &[u8]); 4] = [
+        (MacAlgorithm::HMAC256_64, &test::COSE_MAC0_HMAC256_64),
+        (MacAlgorithm::HMAC256_256, &test::COSE_MAC0_HMAC256_256),
+        (MacAlgorithm::HMAC384_384, &test::COSE_MAC0_HMAC384_384),
+        (MacAlgorithm::HMAC512_512, &test::COSE_MAC0_HMAC512_512),
+    ];
+    for &(algorithm, bytes) in &vectors {
+        assert_eq!(decode_mac0(bytes, None).unwrap().verify_with(mac.as_ref()), Ok(()));
+        let cose_mac0 = CoseMac0Builder::new(PAYLOAD)
+            .unprotected_header(header.clone())
+            .mac_with(algorithm, mac.as_ref())
+            .build()
+            .unwrap();
+        assert_eq!(cose_mac0, bytes.to_vec());
+    }
+    let cose_mac = decode_mac(&test::COSE_MAC_HMAC256_256, None).unwrap();
+    assert_eq!(cose_mac.verify_with(mac.as_ref()), Ok(()));
+
+    let wrong_key = make_mac(b"wrong key");
+    assert_eq!(cose_mac.verify_with(wrong_key.as_ref()), Err(CoseError::VerificationFailed));
+    let cose_mac0 = decode_mac0(&test::COSE_MAC0_HMAC256_64, None).unwrap();
+    assert_eq!(cose_mac0.verify_with(wrong_key.as_ref()), Err(CoseError::VerificationFailed));
+}
+
+// Check the thumbprint of the P-256 test key and the kid set from it with the SHA-256 `sha256`
+// of a backend.
+#[cfg(any(feature = "openssl", feature = "rustcrypto"))]
+pub fn test_key_thumbprint<H>(sha256: H)
+where
+    H: Fn(&[u8]) -> Vec<u8>,
+{
+    use key::CoseKey;
+
+    let mut key = CoseKey::from_bytes(&test::COSE_KEY_EC2_P256).unwrap();
+    let thumbprint = key.thumbprint(&sha256).unwrap();
+    assert_eq!(thumbprint, COSE_KEY_EC2_P256_THUMBPRINT.to_vec());
+    key.set_thumbprint_kid(&sha256).unwrap();
+    assert_eq!(key.kid, Some(thumbprint));
+}
+
+#[rustfmt::skip]
+pub const PKCS8_P256_EE: [u8; 138] = [
+    0x30, 0x81, 0x87, 0x02, 0x01, 0x00, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86,
+    0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x03, 0x01, 0x07, 0x04, 0x6d, 0x30, 0x6b, 0x02, 0x01, 0x01, 0x04, 0x20,
+    0x21, 0x91, 0x40, 0x3d, 0x57, 0x10, 0xbf, 0x15, 0xa2, 0x65, 0x81, 0x8c,
+    0xd4, 0x2e, 0xd6, 0xfe, 0xdf, 0x09, 0xad, 0xd9, 0x2d, 0x78, 0xb1, 0x8e,
+    0x7a, 0x1e, 0x9f, 0xeb, 0x95, 0x52, 0x47, 0x02, 0xa1, 0x44, 0x03, 0x42,
+    0x00, 0x04, 0x4f, 0xbf, 0xbb, 0xbb, 0x61, 0xe0, 0xf8, 0xf9, 0xb1, 0xa6,
+    0x0a, 0x59, 0xac, 0x87, 0x04, 0xe2, 0xec, 0x05, 0x0b, 0x42, 0x3e, 0x3c,
+    0xf7, 0x2e, 0x92, 0x3f, 0x2c, 0x4f, 0x79, 0x4b, 0x45, 0x5c, 0x2a, 0x69,
+    0xd2, 0x33, 0x45, 0x6c, 0x36, 0xc4, 0x11, 0x9d, 0x07, 0x06, 0xe0, 0x0e,
+    0xed, 0xc8, 0xd1, 0x93, 0x90, 0xd7, 0x99, 0x1b, 0x7b, 0x2d, 0x07, 0xa3,
+    0x04, 0xea, 0xa0, 0x4a, 0xa6, 0xc0
+];
+
+#[rustfmt::skip]
+pub const P256_EE: [u8; 300] = [
+    0x30, 0x82, 0x01, 0x28, 0x30, 0x81, 0xcf, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x14, 0x2f, 0xc3, 0x5f, 0x05, 0x80, 0xb4, 0x49, 0x45, 0x13, 0x92,
+    0xd6, 0x93, 0xb7, 0x2d, 0x71, 0x19, 0xc5, 0x8c, 0x40, 0x39, 0x30, 0x0a,
+    0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02, 0x30, 0x13,
+    0x31, 0x11, 0x30, 0x0f, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x08, 0x69,
+    0x6e, 0x74, 0x2d, 0x70, 0x32, 0x35, 0x36, 0x30, 0x22, 0x18, 0x0f, 0x32,
+    0x30, 0x31, 0x32, 0x30, 0x31, 0x30, 0x33, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30, 0x32, 0x31, 0x31, 0x32, 0x33, 0x31,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x12, 0x31, 0x10, 0x30,
+    0x0e, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x07, 0x65, 0x65, 0x2d, 0x70,
+    0x32, 0x35, 0x36, 0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03,
+    0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0x4f, 0xbf, 0xbb, 0xbb, 0x61, 0xe0,
+    0xf8, 0xf9, 0xb1, 0xa6, 0x0a, 0x59, 0xac, 0x87, 0x04, 0xe2, 0xec, 0x05,
+    0x0b, 0x42, 0x3e, 0x3c, 0xf7, 0x2e, 0x92, 0x3f, 0x2c, 0x4f, 0x79, 0x4b,
+    0x45, 0x5c, 0x2a, 0x69, 0xd2, 0x33, 0x45, 0x6c, 0x36, 0xc4, 0x11, 0x9d,
+    0x07, 0x06, 0xe0, 0x0e, 0xed, 0xc8, 0xd1, 0x93, 0x90, 0xd7, 0x99, 0x1b,
+    0x7b, 0x2d, 0x07, 0xa3, 0x04, 0xea, 0xa0, 0x4a, 0xa6, 0xc0, 0x30, 0x0a,
+    0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02, 0x03, 0x48,
+    0x00, 0x30, 0x45, 0x02, 0x20, 0x5c, 0x75, 0x51, 0x9f, 0x13, 0x11, 0x50,
+    0xcd, 0x5d, 0x8a, 0xde, 0x20, 0xa3, 0xbc, 0x06, 0x30, 0x91, 0xff, 0xb2,
+    0x73, 0x75, 0x5f, 0x31, 0x64, 0xec, 0xfd, 0xcb, 0x42, 0x80, 0x0a, 0x70,
+    0xe6, 0x02, 0x21, 0x00, 0xff, 0x81, 0xbe, 0xa8, 0x0d, 0x03, 0x36, 0x6b,
+    0x75, 0xe2, 0x70, 0x6a, 0xac, 0x07, 0x2e, 0x4c, 0xdc, 0xf9, 0xc5, 0x89,
+    0xc1, 0xcf, 0x88, 0xc2, 0xc8, 0x2a, 0x32, 0xf5, 0x42, 0x0c, 0xfa, 0x0b
+];
+
+#[rustfmt::skip]
+pub const PKCS8_P384_EE: [u8; 185] = [
+    0x30, 0x81, 0xb6, 0x02, 0x01, 0x00, 0x30, 0x10, 0x06, 0x07, 0x2a, 0x86,
+    0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x22,
+    0x04, 0x81, 0x9e, 0x30, 0x81, 0x9b, 0x02, 0x01, 0x01, 0x04, 0x30, 0x03,
+    0x5c, 0x7a, 0x1b, 0x10, 0xd9, 0xfa, 0xfe, 0x83, 0x7b, 0x64, 0xad, 0x92,
+    0xf2, 0x2f, 0x5c, 0xed, 0x07, 0x89, 0x18, 0x65, 0x38, 0x66, 0x9b, 0x5c,
+    0x6d, 0x87, 0x2c, 0xec, 0x3d, 0x92, 0x61, 0x22, 0xb3, 0x93, 0x77, 0x2b,
+    0x57, 0x60, 0x2f, 0xf3, 0x13, 0x65, 0xef, 0xe1, 0x39, 0x32, 0x46, 0xa1,
+    0x64, 0x03, 0x62, 0x00, 0x04, 0xa1, 0x68, 0x72, 0x43, 0x36, 0x2b, 0x5c,
+    0x7b, 0x18, 0x89, 0xf3, 0x79, 0x15, 0x46, 0x15, 0xa1, 0xc7, 0x3f, 0xb4,
+    0x8d, 0xee, 0x86, 0x3e, 0x02, 0x29, 0x15, 0xdb, 0x60, 0x8e, 0x25, 0x2d,
+    0xe4, 0xb7, 0x13, 0x2d, 0xa8, 0xce, 0x98, 0xe8, 0x31, 0x53, 0x4e, 0x6a,
+    0x9c, 0x0c, 0x0b, 0x09, 0xc8, 0xd6, 0x39, 0xad, 0xe8, 0x32, 0x06, 0xe5,
+    0xba, 0x81, 0x34, 0x73, 0xa1, 0x1f, 0xa3, 0x30, 0xe0, 0x5d, 0xa8, 0xc9,
+    0x6e, 0x43, 0x83, 0xfe, 0x27, 0x87, 0x3d, 0xa9, 0x71, 0x03, 0xbe, 0x28,
+    0x88, 0xcf, 0xf0, 0x02, 0xf0, 0x5a, 0xf7, 0x1a, 0x1f, 0xdd, 0xcc, 0x83,
+    0x74, 0xaa, 0x6e, 0xa9, 0xce
+];
+
+#[rustfmt::skip]
+pub const P384_EE: [u8; 329] = [
+    0x30, 0x82, 0x01, 0x45, 0x30, 0x81, 0xec, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x14, 0x79, 0xe3, 0x1c, 0x60, 0x97, 0xa4, 0x3c, 0x3b, 0x82, 0x11,
+    0x42, 0x37, 0xaf, 0x57, 0x05, 0xa8, 0xde, 0xd3, 0x40, 0x58, 0x30, 0x0a,
+    0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02, 0x30, 0x13,
+    0x31, 0x11, 0x30, 0x0f, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x08, 0x69,
+    0x6e, 0x74, 0x2d, 0x70, 0x32, 0x35, 0x36, 0x30, 0x22, 0x18, 0x0f, 0x32,
+    0x30, 0x31, 0x32, 0x30, 0x31, 0x30, 0x33, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30, 0x32, 0x31, 0x31, 0x32, 0x33, 0x31,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x12, 0x31, 0x10, 0x30,
+    0x0e, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x07, 0x65, 0x65, 0x2d, 0x70,
+    0x33, 0x38, 0x34, 0x30, 0x76, 0x30, 0x10, 0x06, 0x07, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x02, 0x01, 0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x22, 0x03,
+    0x62, 0x00, 0x04, 0xa1, 0x68, 0x72, 0x43, 0x36, 0x2b, 0x5c, 0x7b, 0x18,
+    0x89, 0xf3, 0x79, 0x15, 0x46, 0x15, 0xa1, 0xc7, 0x3f, 0xb4, 0x8d, 0xee,
+    0x86, 0x3e, 0x02, 0x29, 0x15, 0xdb, 0x60, 0x8e, 0x25, 0x2d, 0xe4, 0xb7,
+    0x13, 0x2d, 0xa8, 0xce, 0x98, 0xe8, 0x31, 0x53, 0x4e, 0x6a, 0x9c, 0x0c,
+    0x0b, 0x09, 0xc8, 0xd6, 0x39, 0xad, 0xe8, 0x32, 0x06, 0xe5, 0xba, 0x81,
+    0x34, 0x73, 0xa1, 0x1f, 0xa3, 0x30, 0xe0, 0x5d, 0xa8, 0xc9, 0x6e, 0x43,
+    0x83, 0xfe, 0x27, 0x87, 0x3d, 0xa9, 0x71, 0x03, 0xbe, 0x28, 0x88, 0xcf,
+    0xf0, 0x02, 0xf0, 0x5a, 0xf7, 0x1a, 0x1f, 0xdd, 0xcc, 0x83, 0x74, 0xaa,
+    0x6e, 0xa9, 0xce, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x03, 0x48, 0x00, 0x30, 0x45, 0x02, 0x20, 0x5c, 0x75,
+    0x51, 0x9f, 0x13, 0x11, 0x50, 0xcd, 0x5d, 0x8a, 0xde, 0x20, 0xa3, 0xbc,
+    0x06, 0x30, 0x91, 0xff, 0xb2, 0x73, 0x75, 0x5f, 0x31, 0x64, 0xec, 0xfd,
+    0xcb, 0x42, 0x80, 0x0a, 0x70, 0xe6, 0x02, 0x21, 0x00, 0xf3, 0x04, 0x26,
+    0xf2, 0xfd, 0xbc, 0x89, 0x3f, 0x29, 0x3b, 0x70, 0xbc, 0x72, 0xa6, 0xc2,
+    0x23, 0xcc, 0x43, 0x4d, 0x84, 0x71, 0xaf, 0x53, 0xe4, 0x4b, 0x3e, 0xc0,
+    0xbf, 0xe5, 0x68, 0x86, 0x49
+];
+
+#[rustfmt::skip]
+pub const PKCS8_P521_EE: [u8; 241] = [
+    0x30, 0x81, 0xee, 0x02, 0x01, 0x00, 0x30, 0x10, 0x06, 0x07, 0x2a, 0x86,
+    0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x23,
+    0x04, 0x81, 0xd6, 0x30, 0x81, 0xd3, 0x02, 0x01, 0x01, 0x04, 0x42, 0x01,
+    0x4f, 0x32, 0x84, 0xfa, 0x69, 0x8d, 0xd9, 0xfe, 0x11, 0x18, 0xdd, 0x33,
+    0x18, 0x51, 0xcd, 0xfa, 0xac, 0x5a, 0x38, 0x29, 0x27, 0x8e, 0xb8, 0x99,
+    0x48, 0x39, 0xde, 0x94, 0x71, 0xc9, 0x40, 0xb8, 0x58, 0xc6, 0x9d, 0x2d,
+    0x05, 0xe8, 0xc0, 0x17, 0x88, 0xa7, 0xd0, 0xb6, 0xe2, 0x35, 0xaa, 0x5e,
+    0x78, 0x3f, 0xc1, 0xbe, 0xe8, 0x07, 0xdc, 0xc3, 0x86, 0x5f, 0x92, 0x0e,
+    0x12, 0xcf, 0x8f, 0x2d, 0x29, 0xa1, 0x81, 0x89, 0x03, 0x81, 0x86, 0x00,
+    0x04, 0x01, 0x4c, 0xdc, 0x9c, 0xac, 0xc4, 0x79, 0x41, 0x09, 0x6b, 0xc9,
+    0xcc, 0x66, 0x75, 0x2e, 0xc2, 0x7f, 0x59, 0x77, 0x34, 0xfa, 0x66, 0xc6,
+    0x2b, 0x79, 0x2f, 0x88, 0xc5, 0x19, 0xd6, 0xd3, 0x7f, 0x0d, 0x16, 0xea,
+    0x1c, 0x48, 0x3a, 0x18, 0x27, 0xa0, 0x10, 0xb9, 0x12, 0x8e, 0x3a, 0x08,
+    0x07, 0x0c, 0xa3, 0x3e, 0xf5, 0xf5, 0x78, 0x35, 0xb7, 0xc1, 0xba, 0x25,
+    0x1f, 0x6c, 0xc3, 0x52, 0x1d, 0xc4, 0x2b, 0x01, 0x06, 0x53, 0x45, 0x19,
+    0x81, 0xb4, 0x45, 0xd3, 0x43, 0xee, 0xd3, 0x78, 0x2a, 0x35, 0xd6, 0xcf,
+    0xf0, 0xff, 0x48, 0x4f, 0x5a, 0x88, 0x3d, 0x20, 0x9f, 0x1b, 0x90, 0x42,
+    0xb7, 0x26, 0x70, 0x35, 0x68, 0xb2, 0xf3, 0x26, 0xe1, 0x8b, 0x83, 0x3b,
+    0xdd, 0x8a, 0xa0, 0x73, 0x43, 0x92, 0xbc, 0xd1, 0x95, 0x01, 0xe1, 0x0d,
+    0x69, 0x8a, 0x79, 0xf5, 0x3e, 0x11, 0xe0, 0xa2, 0x2b, 0xdd, 0x2a, 0xad,
+    0x90
+];
+
+#[rustfmt::skip]
+pub const P521_EE: [u8; 367] = [
+    0x30, 0x82, 0x01, 0x6b, 0x30, 0x82, 0x01, 0x12, 0xa0, 0x03, 0x02, 0x01,
+    0x02, 0x02, 0x14, 0x49, 0xdb, 0x7d, 0xec, 0x87, 0x2b, 0x95, 0xfc, 0xfb,
+    0x57, 0xfb, 0xc8, 0xd5, 0x57, 0xb7, 0x3a, 0x10, 0xcc, 0xf1, 0x7a, 0x30,
+    0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02, 0x30,
+    0x13, 0x31, 0x11, 0x30, 0x0f, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x08,
+    0x69, 0x6e, 0x74, 0x2d, 0x70, 0x32, 0x35, 0x36, 0x30, 0x22, 0x18, 0x0f,
+    0x32, 0x30, 0x31, 0x32, 0x30, 0x31, 0x30, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30, 0x32, 0x31, 0x31, 0x32, 0x33,
+    0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x12, 0x31, 0x10,
+    0x30, 0x0e, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x07, 0x65, 0x65, 0x2d,
+    0x70, 0x35, 0x32, 0x31, 0x30, 0x81, 0x9b, 0x30, 0x10, 0x06, 0x07, 0x2a,
+    0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x05, 0x2b, 0x81, 0x04, 0x00,
+    0x23, 0x03, 0x81, 0x86, 0x00, 0x04, 0x01, 0x4c, 0xdc, 0x9c, 0xac, 0xc4,
+    0x79, 0x41, 0x09, 0x6b, 0xc9, 0xcc, 0x66, 0x75, 0x2e, 0xc2, 0x7f, 0x59,
+    0x77, 0x34, 0xfa, 0x66, 0xc6, 0x2b, 0x79, 0x2f, 0x88, 0xc5, 0x19, 0xd6,
+    0xd3, 0x7f, 0x0d, 0x16, 0xea, 0x1c, 0x48, 0x3a, 0x18, 0x27, 0xa0, 0x10,
+    0xb9, 0x12, 0x8e, 0x3a, 0x08, 0x07, 0x0c, 0xa3, 0x3e, 0xf5, 0xf5, 0x78,
+    0x35, 0xb7, 0xc1, 0xba, 0x25, 0x1f, 0x6c, 0xc3, 0x52, 0x1d, 0xc4, 0x2b,
+    0x01, 0x06, 0x53, 0x45, 0x19, 0x81, 0xb4, 0x45, 0xd3, 0x43, 0xee, 0xd3,
+    0x78, 0x2a, 0x35, 0xd6, 0xcf, 0xf0, 0xff, 0x48, 0x4f, 0x5a, 0x88, 0x3d,
+    0x20, 0x9f, 0x1b, 0x90, 0x42, 0xb7, 0x26, 0x70, 0x35, 0x68, 0xb2, 0xf3,
+    0x26, 0xe1, 0x8b, 0x83, 0x3b, 0xdd, 0x8a, 0xa0, 0x73, 0x43, 0x92, 0xbc,
+    0xd1, 0x95, 0x01, 0xe1, 0x0d, 0x69, 0x8a, 0x79, 0xf5, 0x3e, 0x11, 0xe0,
+    0xa2, 0x2b, 0xdd, 0x2a, 0xad, 0x90, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86,
+    0x48, 0xce, 0x3d, 0x04, 0x03, 0x02, 0x03, 0x47, 0x00, 0x30, 0x44, 0x02,
+    0x20, 0x5c, 0x75, 0x51, 0x9f, 0x13, 0x11, 0x50, 0xcd, 0x5d, 0x8a, 0xde,
+    0x20, 0xa3, 0xbc, 0x06, 0x30, 0x91, 0xff, 0xb2, 0x73, 0x75, 0x5f, 0x31,
+    0x64, 0xec, 0xfd, 0xcb, 0x42, 0x80, 0x0a, 0x70, 0xe6, 0x02, 0x20, 0x35,
+    0x20, 0x7c, 0xff, 0x51, 0xf6, 0x68, 0xce, 0x1d, 0x00, 0xf9, 0xcc, 0x7f,
+    0xa7, 0xbc, 0x79, 0x52, 0xea, 0x56, 0xdf, 0xc1, 0x46, 0x7c, 0x0c, 0xa1,
+    0x2e, 0x32, 0xb1, 0x69, 0x4b, 0x20, 0xc4
+];
+
+#[rustfmt::skip]
+pub const PKCS8_RSA_EE: [u8; 1218] = [
+    0x30, 0x82, 0x04, 0xbe, 0x02, 0x01, 0x00, 0x30, 0x0d, 0x06, 0x09, 0x2a,
+    0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01, 0x05, 0x00, 0x04, 0x82,
+    0x04, 0xa8, 0x30, 0x82, 0x04, 0xa4, 0x02, 0x01, 0x00, 0x02, 0x82, 0x01,
+    0x01, 0x00, 0xba, 0x88, 0x51, 0xa8, 0x44, 0x8e, 0x16, 0xd6, 0x41, 0xfd,
+    0x6e, 0xb6, 0x88, 0x06, 0x36, 0x10, 0x3d, 0x3c, 0x13, 0xd9, 0xea, 0xe4,
+    0x35, 0x4a, 0xb4, 0xec, 0xf5, 0x68, 0x57, 0x6c, 0x24, 0x7b, 0xc1, 0xc7,
+    0x25, 0xa8, 0xe0, 0xd8, 0x1f, 0xbd, 0xb1, 0x9c, 0x06, 0x9b, 0x6e, 0x1a,
+    0x86, 0xf2, 0x6b, 0xe2, 0xaf, 0x5a, 0x75, 0x6b, 0x6a, 0x64, 0x71, 0x08,
+    0x7a, 0xa5, 0x5a, 0xa7, 0x45, 0x87, 0xf7, 0x1c, 0xd5, 0x24, 0x9c, 0x02,
+    0x7e, 0xcd, 0x43, 0xfc, 0x1e, 0x69, 0xd0, 0x38, 0x20, 0x29, 0x93, 0xab,
+    0x20, 0xc3, 0x49, 0xe4, 0xdb, 0xb9, 0x4c, 0xc2, 0x6b, 0x6c, 0x0e, 0xed,
+    0x15, 0x82, 0x0f, 0xf1, 0x7e, 0xad, 0x69, 0x1a, 0xb1, 0xd3, 0x02, 0x3a,
+    0x8b, 0x2a, 0x41, 0xee, 0xa7, 0x70, 0xe0, 0x0f, 0x0d, 0x8d, 0xfd, 0x66,
+    0x0b, 0x2b, 0xb0, 0x24, 0x92, 0xa4, 0x7d, 0xb9, 0x88, 0x61, 0x79, 0x90,
+    0xb1, 0x57, 0x90, 0x3d, 0xd2, 0x3b, 0xc5, 0xe0, 0xb8, 0x48, 0x1f, 0xa8,
+    0x37, 0xd3, 0x88, 0x43, 0xef, 0x27, 0x16, 0xd8, 0x55, 0xb7, 0x66, 0x5a,
+    0xaa, 0x7e, 0x02, 0x90, 0x2f, 0x3a, 0x7b, 0x10, 0x80, 0x06, 0x24, 0xcc,
+    0x1c, 0x6c, 0x97, 0xad, 0x96, 0x61, 0x5b, 0xb7, 0xe2, 0x96, 0x12, 0xc0,
+    0x75, 0x31, 0xa3, 0x0c, 0x91, 0xdd, 0xb4, 0xca, 0xf7, 0xfc, 0xad, 0x1d,
+    0x25, 0xd3, 0x09, 0xef, 0xb9, 0x17, 0x0e, 0xa7, 0x68, 0xe1, 0xb3, 0x7b,
+    0x2f, 0x22, 0x6f, 0x69, 0xe3, 0xb4, 0x8a, 0x95, 0x61, 0x1d, 0xee, 0x26,
+    0xd6, 0x25, 0x9d, 0xab, 0x91, 0x08, 0x4e, 0x36, 0xcb, 0x1c, 0x24, 0x04,
+    0x2c, 0xbf, 0x16, 0x8b, 0x2f, 0xe5, 0xf1, 0x8f, 0x99, 0x17, 0x31, 0xb8,
+    0xb3, 0xfe, 0x49, 0x23, 0xfa, 0x72, 0x51, 0xc4, 0x31, 0xd5, 0x03, 0xac,
+    0xda, 0x18, 0x0a, 0x35, 0xed, 0x8d, 0x02, 0x03, 0x01, 0x00, 0x01, 0x02,
+    0x82, 0x01, 0x01, 0x00, 0x9e, 0xcb, 0xce, 0x38, 0x61, 0xa4, 0x54, 0xec,
+    0xb1, 0xe0, 0xfe, 0x8f, 0x85, 0xdd, 0x43, 0xc9, 0x2f, 0x58, 0x25, 0xce,
+    0x2e, 0x99, 0x78, 0x84, 0xd0, 0xe1, 0xa9, 0x49, 0xda, 0xa2, 0xc5, 0xac,
+    0x55, 0x9b, 0x24, 0x04, 0x50, 0xe5, 0xac, 0x9f, 0xe0, 0xc3, 0xe3, 0x1c,
+    0x0e, 0xef, 0xa6, 0x52, 0x5a, 0x65, 0xf0, 0xc2, 0x21, 0x94, 0x00, 0x4e,
+    0xe1, 0xab, 0x46, 0x3d, 0xde, 0x9e, 0xe8, 0x22, 0x87, 0xcc, 0x93, 0xe7,
+    0x46, 0xa9, 0x19, 0x29, 0xc5, 0xe6, 0xac, 0x3d, 0x88, 0x75, 0x3f, 0x6c,
+    0x25, 0xba, 0x59, 0x79, 0xe7, 0x3e, 0x5d, 0x8f, 0xb2, 0x39, 0x11, 0x1a,
+    0x3c, 0xda, 0xb8, 0xa4, 0xb0, 0xcd, 0xf5, 0xf9, 0xca, 0xb0, 0x5f, 0x12,
+    0x33, 0xa3, 0x83, 0x35, 0xc6, 0x4b, 0x55, 0x60, 0x52, 0x5e, 0x7e, 0x3b,
+    0x92, 0xad, 0x7c, 0x75, 0x04, 0xcf, 0x1d, 0xc7, 0xcb, 0x00, 0x57, 0x88,
+    0xaf, 0xcb, 0xe1, 0xe8, 0xf9, 0x5d, 0xf7, 0x40, 0x2a, 0x15, 0x15, 0x30,
+    0xd5, 0x80, 0x83, 0x46, 0x86, 0x4e, 0xb3, 0x70, 0xaa, 0x79, 0x95, 0x6a,
+    0x58, 0x78, 0x62, 0xcb, 0x53, 0x37, 0x91, 0x30, 0x7f, 0x70, 0xd9, 0x1c,
+    0x96, 0xd2, 0x2d, 0x00, 0x1a, 0x69, 0x00, 0x9b, 0x92, 0x3c, 0x68, 0x33,
+    0x88, 0xc9, 0xf3, 0x6c, 0xb9, 0xb5, 0xeb, 0xe6, 0x43, 0x02, 0x04, 0x1c,
+    0x78, 0xd9, 0x08, 0x20, 0x6b, 0x87, 0x00, 0x9c, 0xb8, 0xca, 0xba, 0xca,
+    0xd3, 0xdb, 0xdb, 0x27, 0x92, 0xfb, 0x91, 0x1b, 0x2c, 0xf4, 0xdb, 0x66,
+    0x03, 0x58, 0x5b, 0xe9, 0xae, 0x0c, 0xa3, 0xb8, 0xe6, 0x41, 0x7a, 0xa0,
+    0x4b, 0x06, 0xe4, 0x70, 0xea, 0x1a, 0x3b, 0x58, 0x1c, 0xa0, 0x3a, 0x67,
+    0x81, 0xc9, 0x31, 0x5b, 0x62, 0xb3, 0x0e, 0x60, 0x11, 0xf2, 0x24, 0x72,
+    0x59, 0x46, 0xee, 0xc5, 0x7c, 0x6d, 0x94, 0x41, 0x02, 0x81, 0x81, 0x00,
+    0xdd, 0x6e, 0x1d, 0x4f, 0xff, 0xeb, 0xf6, 0x8d, 0x88, 0x9c, 0x4d, 0x11,
+    0x4c, 0xda, 0xaa, 0x9c, 0xaa, 0x63, 0xa5, 0x93, 0x74, 0x28, 0x6c, 0x8a,
+    0x5c, 0x29, 0xa7, 0x17, 0xbb, 0xa6, 0x03, 0x75, 0x64, 0x4d, 0x5c, 0xaa,
+    0x67, 0x4c, 0x4b, 0x8b, 0xc7, 0x32, 0x63, 0x58, 0x64, 0x62, 0x20, 0xe4,
+    0x55, 0x0d, 0x76, 0x08, 0xac, 0x27, 0xd5, 0x5b, 0x6d, 0xb7, 0x4f, 0x8d,
+    0x81, 0x27, 0xef, 0x8f, 0xa0, 0x90, 0x98, 0xb6, 0x91, 0x47, 0xde, 0x06,
+    0x55, 0x73, 0x44, 0x7e, 0x18, 0x3d, 0x22, 0xfe, 0x7d, 0x88, 0x5a, 0xce,
+    0xb5, 0x13, 0xd9, 0x58, 0x1d, 0xd5, 0xe0, 0x7c, 0x1a, 0x90, 0xf5, 0xce,
+    0x08, 0x79, 0xde, 0x13, 0x13, 0x71, 0xec, 0xef, 0xc9, 0xce, 0x72, 0xe9,
+    0xc4, 0x3d, 0xc1, 0x27, 0xd2, 0x38, 0x19, 0x0d, 0xe8, 0x11, 0x77, 0x3c,
+    0xa5, 0xd1, 0x93, 0x01, 0xf4, 0x8c, 0x74, 0x2b, 0x02, 0x81, 0x81, 0x00,
+    0xd7, 0xa7, 0x73, 0xd9, 0xeb, 0xc3, 0x80, 0xa7, 0x67, 0xd2, 0xfe, 0xc0,
+    0x93, 0x4a, 0xd4, 0xe8, 0xb5, 0x66, 0x72, 0x40, 0x77, 0x1a, 0xcd, 0xeb,
+    0xb5, 0xad, 0x79, 0x6f, 0x47, 0x8f, 0xec, 0x4d, 0x45, 0x98, 0x5e, 0xfb,
+    0xc9, 0x53, 0x29, 0x68, 0x28, 0x9c, 0x8d, 0x89, 0x10, 0x2f, 0xad, 0xf2,
+    0x1f, 0x34, 0xe2, 0xdd, 0x49, 0x40, 0xeb, 0xa8, 0xc0, 0x9d, 0x6d, 0x1f,
+    0x16, 0xdc, 0xc2, 0x97, 0x29, 0x77, 0x4c, 0x43, 0x27, 0x5e, 0x92, 0x51,
+    0xdd, 0xbe, 0x49, 0x09, 0xe1, 0xfd, 0x3b, 0xf1, 0xe4, 0xbe, 0xdf, 0x46,
+    0xa3, 0x9b, 0x8b, 0x38, 0x33, 0x28, 0xef, 0x4a, 0xe3, 0xb9, 0x5b, 0x92,
+    0xf2, 0x07, 0x0a, 0xf2, 0x6c, 0x9e, 0x7c, 0x5c, 0x9b, 0x58, 0x7f, 0xed,
+    0xde, 0x05, 0xe8, 0xe7, 0xd8, 0x6c, 0xa5, 0x78, 0x86, 0xfb, 0x16, 0x58,
+    0x10, 0xa7, 0x7b, 0x98, 0x45, 0xbc, 0x31, 0x27, 0x02, 0x81, 0x81, 0x00,
+    0x96, 0x47, 0x2b, 0x41, 0xa6, 0x10, 0xc0, 0xad, 0xe1, 0xaf, 0x22, 0x66,
+    0xc1, 0x60, 0x0e, 0x36, 0x71, 0x35, 0x5b, 0xa4, 0x2d, 0x4b, 0x5a, 0x0e,
+    0xb4, 0xe9, 0xd7, 0xeb, 0x35, 0x81, 0x40, 0x0b, 0xa5, 0xdd, 0x13, 0x2c,
+    0xdb, 0x1a, 0x5e, 0x93, 0x28, 0xc7, 0xbb, 0xc0, 0xbb, 0xb0, 0x15, 0x5e,
+    0xa1, 0x92, 0x97, 0x2e, 0xdf, 0x97, 0xd1, 0x27, 0x51, 0xd8, 0xfc, 0xf6,
+    0xae, 0x57, 0x2a, 0x30, 0xb1, 0xea, 0x30, 0x9a, 0x87, 0x12, 0xdd, 0x4e,
+    0x33, 0x24, 0x1d, 0xb1, 0xee, 0x45, 0x5f, 0xc0, 0x93, 0xf5, 0xbc, 0x9b,
+    0x59, 0x2d, 0x75, 0x6e, 0x66, 0x21, 0x47, 0x4f, 0x32, 0xc0, 0x7a, 0xf2,
+    0x2f, 0xb2, 0x75, 0xd3, 0x40, 0x79, 0x2b, 0x32, 0xba, 0x25, 0x90, 0xbb,
+    0xb2, 0x61, 0xae, 0xfb, 0x95, 0xa2, 0x58, 0xee, 0xa5, 0x37, 0x65, 0x53,
+    0x15, 0xbe, 0x9c, 0x24, 0xd1, 0x91, 0x99, 0x2d, 0x02, 0x81, 0x80, 0x28,
+    0xb4, 0x50, 0xa7, 0xa7, 0x5a, 0x85, 0x64, 0x13, 0xb2, 0xbd, 0xa6, 0xf7,
+    0xa6, 0x3e, 0x3d, 0x96, 0x4f, 0xb9, 0xec, 0xf5, 0x0e, 0x38, 0x23, 0xef,
+    0x6c, 0xc8, 0xe8, 0xfa, 0x26, 0xee, 0x41, 0x3f, 0x8b, 0x9d, 0x12, 0x05,
+    0x54, 0x0f, 0x12, 0xbb, 0xe7, 0xa0, 0xc7, 0x68, 0x28, 0xb7, 0xba, 0x65,
+    0xad, 0x83, 0xcc, 0xa4, 0xd0, 0xfe, 0x2a, 0x22, 0x01, 0x14, 0xe1, 0xb3,
+    0x5d, 0x03, 0xd5, 0xa8, 0x5b, 0xfe, 0x27, 0x06, 0xbd, 0x50, 0xfc, 0xe6,
+    0xcf, 0xcd, 0xd5, 0x71, 0xb4, 0x6c, 0xa6, 0x21, 0xb8, 0xed, 0x47, 0xd6,
+    0x05, 0xbb, 0xe7, 0x65, 0xb0, 0xaa, 0x4a, 0x06, 0x65, 0xac, 0x25, 0x36,
+    0x4d, 0xa2, 0x01, 0x54, 0x03, 0x2e, 0x12, 0x04, 0xb8, 0x55, 0x9d, 0x3e,
+    0x34, 0xfb, 0x5b, 0x17, 0x7c, 0x9a, 0x56, 0xff, 0x93, 0x51, 0x0a, 0x5a,
+    0x4a, 0x62, 0x87, 0xc1, 0x51, 0xde, 0x2d, 0x02, 0x81, 0x80, 0x28, 0x06,
+    0x7b, 0x93, 0x55, 0x80, 0x1d, 0x2e, 0xf5, 0x2d, 0xfa, 0x96, 0xd8, 0xad,
+    0xb5, 0x89, 0x67, 0x3c, 0xf8, 0xee, 0x8a, 0x9c, 0x6f, 0xf7, 0x2a, 0xee,
+    0xab, 0xe9, 0xef, 0x6b, 0xe5, 0x8a, 0x4f, 0x4a, 0xbf, 0x05, 0xf7, 0x88,
+    0x94, 0x7d, 0xc8, 0x51, 0xfd, 0xaa, 0x34, 0x54, 0x21, 0x47, 0xa7, 0x1a,
+    0x24, 0x6b, 0xfb, 0x05, 0x4e, 0xe7, 0x6a, 0xa3, 0x46, 0xab, 0xcd, 0x26,
+    0x92, 0xcf, 0xc9, 0xe4, 0x4c, 0x51, 0xe6, 0xf0, 0x69, 0xc7, 0x35, 0xe0,
+    0x73, 0xba, 0x01, 0x9f, 0x6a, 0x72, 0x14, 0x96, 0x1c, 0x91, 0xb2, 0x68,
+    0x71, 0xca, 0xea, 0xbf, 0x8f, 0x06, 0x44, 0x18, 0xa0, 0x26, 0x90, 0xe3,
+    0x9a, 0x8d, 0x5f, 0xf3, 0x06, 0x7b, 0x7c, 0xdb, 0x7f, 0x50, 0xb1, 0xf5,
+    0x34, 0x18, 0xa7, 0x03, 0x96, 0x6c, 0x4f, 0xc7, 0x74, 0xbf, 0x74, 0x02,
+    0xaf, 0x6c, 0x43, 0x24, 0x7f, 0x43
+];
+
+#[rustfmt::skip]
+pub const RSA_EE: [u8; 691] = [
+    0x30, 0x82, 0x02, 0xaf, 0x30, 0x82, 0x01, 0x99, 0xa0, 0x03, 0x02, 0x01,
+    0x02, 0x02, 0x14, 0x07, 0x1c, 0x3b, 0x71, 0x08, 0xbe, 0xd7, 0x9f, 0xfd,
+    0xaf, 0x26, 0xb6, 0x08, 0xa3, 0x99, 0x06, 0x77, 0x69, 0x32, 0x7e, 0x30,
+    0x0b, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b,
+    0x30, 0x12, 0x31, 0x10, 0x30, 0x0e, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c,
+    0x07, 0x69, 0x6e, 0x74, 0x2d, 0x72, 0x73, 0x61, 0x30, 0x22, 0x18, 0x0f,
+    0x32, 0x30, 0x31, 0x32, 0x30, 0x31, 0x30, 0x33, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30, 0x32, 0x31, 0x31, 0x32, 0x33,
+    0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x11, 0x31, 0x0f,
+    0x30, 0x0d, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x06, 0x65, 0x65, 0x2d,
+    0x72, 0x73, 0x61, 0x30, 0x82, 0x01, 0x22, 0x30, 0x0d, 0x06, 0x09, 0x2a,
+    0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01, 0x05, 0x00, 0x03, 0x82,
+    0x01, 0x0f, 0x00, 0x30, 0x82, 0x01, 0x0a, 0x02, 0x82, 0x01, 0x01, 0x00,
+    0xba, 0x88, 0x51, 0xa8, 0x44, 0x8e, 0x16, 0xd6, 0x41, 0xfd, 0x6e, 0xb6,
+    0x88, 0x06, 0x36, 0x10, 0x3d, 0x3c, 0x13, 0xd9, 0xea, 0xe4, 0x35, 0x4a,
+    0xb4, 0xec, 0xf5, 0x68, 0x57, 0x6c, 0x24, 0x7b, 0xc1, 0xc7, 0x25, 0xa8,
+    0xe0, 0xd8, 0x1f, 0xbd, 0xb1, 0x9c, 0x06, 0x9b, 0x6e, 0x1a, 0x86, 0xf2,
+    0x6b, 0xe2, 0xaf, 0x5a, 0x75, 0x6b, 0x6a, 0x64, 0x71, 0x08, 0x7a, 0xa5,
+    0x5a, 0xa7, 0x45, 0x87, 0xf7, 0x1c, 0xd5, 0x24, 0x9c, 0x02, 0x7e, 0xcd,
+    0x43, 0xfc, 0x1e, 0x69, 0xd0, 0x38, 0x20, 0x29, 0x93, 0xab, 0x20, 0xc3,
+    0x49, 0xe4, 0xdb, 0xb9, 0x4c, 0xc2, 0x6b, 0x6c, 0x0e, 0xed, 0x15, 0x82,
+    0x0f, 0xf1, 0x7e, 0xad, 0x69, 0x1a, 0xb1, 0xd3, 0x02, 0x3a, 0x8b, 0x2a,
+    0x41, 0xee, 0xa7, 0x70, 0xe0, 0x0f, 0x0d, 0x8d, 0xfd, 0x66, 0x0b, 0x2b,
+    0xb0, 0x24, 0x92, 0xa4, 0x7d, 0xb9, 0x88, 0x61, 0x79, 0x90, 0xb1, 0x57,
+    0x90, 0x3d, 0xd2, 0x3b, 0xc5, 0xe0, 0xb8, 0x48, 0x1f, 0xa8, 0x37, 0xd3,
+    0x88, 0x43, 0xef, 0x27, 0x16, 0xd8, 0x55, 0xb7, 0x66, 0x5a, 0xaa, 0x7e,
+    0x02, 0x90, 0x2f, 0x3a, 0x7b, 0x10, 0x80, 0x06, 0x24, 0xcc, 0x1c, 0x6c,
+    0x97, 0xad, 0x96, 0x61, 0x5b, 0xb7, 0xe2, 0x96, 0x12, 0xc0, 0x75, 0x31,
+    0xa3, 0x0c, 0x91, 0xdd, 0xb4, 0xca, 0xf7, 0xfc, 0xad, 0x1d, 0x25, 0xd3,
+    0x09, 0xef, 0xb9, 0x17, 0x0e, 0xa7, 0x68, 0xe1, 0xb3, 0x7b, 0x2f, 0x22,
+    0x6f, 0x69, 0xe3, 0xb4, 0x8a, 0x95, 0x61, 0x1d, 0xee, 0x26, 0xd6, 0x25,
+    0x9d, 0xab, 0x91, 0x08, 0x4e, 0x36, 0xcb, 0x1c, 0x24, 0x04, 0x2c, 0xbf,
+    0x16, 0x8b, 0x2f, 0xe5, 0xf1, 0x8f, 0x99, 0x17, 0x31, 0xb8, 0xb3, 0xfe,
+    0x49, 0x23, 0xfa, 0x72, 0x51, 0xc4, 0x31, 0xd5, 0x03, 0xac, 0xda, 0x18,
+    0x0a, 0x35, 0xed, 0x8d, 0x02, 0x03, 0x01, 0x00, 0x01, 0x30, 0x0b, 0x06,
+    0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b, 0x03, 0x82,
+    0x01, 0x01, 0x00, 0x44, 0x92, 0xbb, 0x8e, 0x83, 0x58, 0x56, 0x2e, 0x7a,
+    0x86, 0xfa, 0x1d, 0x77, 0x50, 0x3f, 0x45, 0x8d, 0x90, 0xc4, 0x62, 0x27,
+    0x21, 0x96, 0x5a, 0xef, 0x51, 0x78, 0xd7, 0x7d, 0x0d, 0x02, 0x2d, 0x5a,
+    0x0e, 0x3c, 0x82, 0x6f, 0x1d, 0x92, 0x87, 0xd5, 0x1a, 0x44, 0xae, 0xa7,
+    0x92, 0xd1, 0x8b, 0xfa, 0x16, 0x53, 0x7f, 0xa3, 0x22, 0x96, 0x1a, 0x51,
+    0x8c, 0xeb, 0xa1, 0xe6, 0xf6, 0x37, 0x11, 0xfe, 0x7d, 0x53, 0x3f, 0xae,
+    0xf0, 0x6b, 0xb9, 0xb1, 0x7a, 0x73, 0x07, 0x14, 0xcf, 0x04, 0x05, 0x93,
+    0x9e, 0xe3, 0xd2, 0x4d, 0x9d, 0x6d, 0x35, 0x68, 0xf9, 0x36, 0xe5, 0x10,
+    0x0a, 0x36, 0xd9, 0x48, 0xb0, 0x83, 0xd0, 0xb9, 0x58, 0x74, 0x53, 0xb3,
+    0xbc, 0x99, 0xab, 0xe1, 0x3e, 0xd5, 0x01, 0x8e, 0xcf, 0x3a, 0x69, 0x93,
+    0x9e, 0xa7, 0x88, 0xd4, 0xad, 0x95, 0xf9, 0x2a, 0xb4, 0x7f, 0x95, 0x97,
+    0x86, 0x50, 0x38, 0xb1, 0x04, 0x0a, 0xe4, 0x7a, 0xd5, 0x2d, 0x6c, 0xde,
+    0x3e, 0x1a, 0x47, 0x17, 0x88, 0x63, 0x20, 0x9d, 0x21, 0x3e, 0x0c, 0x6f,
+    0xfd, 0x20, 0x54, 0xd0, 0x67, 0xd2, 0x6b, 0x06, 0xfe, 0x60, 0x13, 0x42,
+    0x3d, 0xb7, 0xca, 0xcb, 0xab, 0x7b, 0x5f, 0x5d, 0x01, 0x56, 0xd3, 0x99,
+    0x80, 0x0f, 0xde, 0x7f, 0x3a, 0x61, 0x9c, 0xd3, 0x6b, 0x5e, 0xfe, 0xb5,
+    0xfc, 0x39, 0x8b, 0x8e, 0xf0, 0x8c, 0x8b, 0x65, 0x46, 0x45, 0xff, 0x47,
+    0x8f, 0xd4, 0xdd, 0xae, 0xc9, 0x72, 0xc7, 0x7f, 0x28, 0x86, 0xf1, 0xf7,
+    0x6e, 0xcb, 0x86, 0x03, 0xeb, 0x0c, 0x46, 0xe5, 0xa0, 0x6b, 0xef, 0xd4,
+    0x5e, 0xa4, 0x0f, 0x53, 0xe1, 0xbc, 0xb4, 0xc9, 0x37, 0x0e, 0x75, 0xdd,
+    0x93, 0xe8, 0x0f, 0x18, 0x0a, 0x02, 0x83, 0x17, 0x74, 0xbb, 0x1a, 0x42,
+    0x5b, 0x63, 0x2c, 0x80, 0x80, 0xa6, 0x84
+];
+
+// Self-signed Ed25519 and Ed448 certificates with their keys, generated with pyca/cryptography.
+#[rustfmt::skip]
+pub const PKCS8_ED25519_EE: [u8; 48] = [
+    0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70,
+    0x04, 0x22, 0x04, 0x20, 0xea, 0x5f, 0x90, 0x24, 0x9d, 0xc0, 0x5d, 0x05,
+    0x94, 0xf8, 0xb1, 0x4e, 0x25, 0xab, 0x4c, 0xb1, 0x44, 0x49, 0x2f, 0xe6,
+    0x8f, 0xb6, 0x2c, 0x3f, 0xa9, 0x86, 0xed, 0xf7, 0x76, 0xcd, 0x08, 0x28
+];
+
+#[rustfmt::skip]
+pub const ED25519_EE: [u8; 238] = [
+    0x30, 0x81, 0xeb, 0x30, 0x81, 0x9e, 0xa0, 0x03, 0x02, 0x01, 0x02, 0x02,
+    0x14, 0x3a, 0xd3, 0xc9, 0xf1, 0xd3, 0xb7, 0xc6, 0x2e, 0xf4, 0x21, 0xf3,
+    0xb5, 0xc6, 0xb8, 0xb9, 0x31, 0x69, 0x20, 0x66, 0x39, 0x30, 0x05, 0x06,
+    0x03, 0x2b, 0x65, 0x70, 0x30, 0x15, 0x31, 0x13, 0x30, 0x11, 0x06, 0x03,
+    0x55, 0x04, 0x03, 0x0c, 0x0a, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39,
+    0x2d, 0x65, 0x65, 0x30, 0x20, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30,
+    0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30,
+    0x35, 0x30, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x5a, 0x30, 0x15, 0x31, 0x13, 0x30, 0x11, 0x06, 0x03, 0x55, 0x04, 0x03,
+    0x0c, 0x0a, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x2d, 0x65, 0x65,
+    0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+    0xab, 0x04, 0x30, 0xd7, 0x22, 0x7b, 0xce, 0x33, 0x2b, 0x86, 0xaf, 0x7a,
+    0xc0, 0x8b, 0xd7, 0x24, 0x0d, 0x03, 0x45, 0x8c, 0x5a, 0x1a, 0xcd, 0x2f,
+    0xfb, 0x89, 0xa0, 0x70, 0x09, 0x38, 0x23, 0xdf, 0x30, 0x05, 0x06, 0x03,
+    0x2b, 0x65, 0x70, 0x03, 0x41, 0x00, 0x5f, 0xec, 0x2e, 0xff, 0x35, 0x06,
+    0xdf, 0xe0, 0x12, 0x28, 0x51, 0xfc, 0x86, 0xe6, 0xd7, 0x9d, 0xb5, 0x53,
+    0xb4, 0x88, 0x84, 0x90, 0xe9, 0x11, 0xa1, 0x5a, 0xd1, 0x2f, 0x9d, 0x11,
+    0x8f, 0x45, 0x7e, 0xc9, 0x50, 0xd1, 0x27, 0x43, 0x26, 0xc2, 0x19, 0xa1,
+    0x34, 0x3e, 0x11, 0x13, 0xdb, 0x07, 0x4b, 0x6f, 0x40, 0x9e, 0xbd, 0xd1,
+    0xd8, 0x96, 0xa8, 0xa1, 0x4f, 0xd0, 0x72, 0x86, 0x2a, 0x01
+];
+
+#[rustfmt::skip]
+pub const PKCS8_ED448_EE: [u8; 73] = [
+    0x30, 0x47, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x71,
+    0x04, 0x3b, 0x04, 0x39, 0x1f, 0x32, 0xa0, 0x3f, 0x66, 0x64, 0x3d, 0xfd,
+    0x58, 0x5d, 0x32, 0x78, 0xa5, 0x78, 0x2b, 0x57, 0xee, 0x1c, 0x49, 0x20,
+    0x70, 0x86, 0x40, 0xcc, 0xe7, 0x34, 0x4f, 0x24, 0x37, 0xfd, 0xff, 0xa5,
+    0x25, 0x72, 0x5f, 0x3e, 0xe4, 0x84, 0x4b, 0x68, 0x22, 0x80, 0xd6, 0x70,
+    0x3c, 0x3a, 0xc8, 0xe1, 0x39, 0xa4, 0x96, 0xe9, 0x0a, 0xa2, 0xb8, 0xca,
+    0xe8
+];
+
+#[rustfmt::skip]
+pub const ED448_EE: [u8; 310] = [
+    0x30, 0x82, 0x01, 0x32, 0x30, 0x81, 0xb3, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x14, 0x7d, 0x55, 0x64, 0x35, 0xcc, 0x73, 0x13, 0xbe, 0xf2, 0xe5,
+    0x71, 0xf9, 0xa3, 0x40, 0x35, 0x83, 0xd5, 0x0c, 0xc7, 0xfa, 0x30, 0x05,
+    0x06, 0x03, 0x2b, 0x65, 0x71, 0x30, 0x13, 0x31, 0x11, 0x30, 0x0f, 0x06,
+    0x03, 0x55, 0x04, 0x03, 0x0c, 0x08, 0x65, 0x64, 0x34, 0x34, 0x38, 0x2d,
+    0x65, 0x65, 0x30, 0x20, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30, 0x35,
+    0x30, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a,
+    0x30, 0x13, 0x31, 0x11, 0x30, 0x0f, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c,
+    0x08, 0x65, 0x64, 0x34, 0x34, 0x38, 0x2d, 0x65, 0x65, 0x30, 0x43, 0x30,
+    0x05, 0x06, 0x03, 0x2b, 0x65, 0x71, 0x03, 0x3a, 0x00, 0x01, 0xab, 0x14,
+    0x6f, 0xe4, 0x96, 0xa1, 0x1c, 0xf3, 0xfc, 0x46, 0x8c, 0x8f, 0x19, 0x57,
+    0x54, 0xf3, 0x2f, 0xe4, 0xce, 0x86, 0xee, 0xc2, 0x68, 0xc4, 0xaf, 0x3a,
+    0x66, 0x63, 0xf5, 0x9f, 0xa8, 0x18, 0x1b, 0xb4, 0x0e, 0x2d, 0x03, 0x02,
+    0x0e, 0x2b, 0x8f, 0x8e, 0x55, 0x3f, 0xe4, 0x5f, 0x52, 0x2c, 0x76, 0x08,
+    0x8c, 0x93, 0xe5, 0xff, 0x6a, 0x80, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65,
+    0x71, 0x03, 0x73, 0x00, 0xcc, 0x9e, 0x05, 0xf8, 0x74, 0x05, 0xae, 0x9b,
+    0x6c, 0x1a, 0x0b, 0xa0, 0x4c, 0x9c, 0xb0, 0x13, 0xdb, 0x7a, 0xa1, 0xce,
+    0xf7, 0xa5, 0xc5, 0xf8, 0x42, 0x72, 0x31, 0x9e, 0x15, 0xa1, 0x88, 0xd8,
+    0xb9, 0x61, 0xdb, 0x54, 0xc4, 0x7c, 0xda, 0x01, 0x3f, 0xb5, 0xd2, 0x7d,
+    0xf3, 0x69, 0x20, 0x38, 0x75, 0x4a, 0xf4, 0xcc, 0x99, 0x43, 0x37, 0xfd,
+    0x00, 0x58, 0xb1, 0x38, 0x1c, 0x78, 0x47, 0xad, 0xe9, 0x05, 0x98, 0x74,
+    0xe3, 0x89, 0xd2, 0x31, 0xef, 0x2d, 0x74, 0xb7, 0x8f, 0xfe, 0x2b, 0x3d,
+    0x20, 0xde, 0x25, 0x14, 0x55, 0x34, 0x08, 0x8e, 0xce, 0x06, 0x32, 0x3d,
+    0x1c, 0xbb, 0xaa, 0x80, 0x36, 0x01, 0x1c, 0x5e, 0x16, 0x79, 0xa7, 0x12,
+    0xa4, 0xbd, 0xae, 0x76, 0x4d, 0xe0, 0xbf, 0x36, 0x33, 0x00
+];
+
+// A self-signed secp256k1 certificate with its key, generated with pyca/cryptography.
+#[rustfmt::skip]
+pub const PKCS8_SECP256K1_EE: [u8; 135] = [
+    0x30, 0x81, 0x84, 0x02, 0x01, 0x00, 0x30, 0x10, 0x06, 0x07, 0x2a, 0x86,
+    0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x0a,
+    0x04, 0x6d, 0x30, 0x6b, 0x02, 0x01, 0x01, 0x04, 0x20, 0x67, 0xe7, 0x8d,
+    0xe6, 0x02, 0x10, 0x01, 0xfd, 0xee, 0xca, 0xff, 0xe3, 0xdd, 0x68, 0xdb,
+    0xb3, 0x2c, 0x34, 0x9c, 0x0f, 0x63, 0xc4, 0x09, 0x25, 0x8a, 0xfe, 0x91,
+    0xa4, 0x73, 0x46, 0xda, 0xfc, 0xa1, 0x44, 0x03, 0x42, 0x00, 0x04, 0xcb,
+    0xfd, 0xcd, 0xa0, 0xa3, 0xcb, 0x63, 0xb6, 0xe9, 0xf8, 0x89, 0x00, 0x1f,
+    0x84, 0xc4, 0xa2, 0x79, 0xd1, 0x54, 0x93, 0x09, 0xd5, 0x7f, 0xe0, 0xaa,
+    0x00, 0xae, 0xe0, 0x51, 0x34, 0x4b, 0x81, 0xe6, 0x4e, 0x56, 0xfc, 0xa9,
+    0x0b, 0x36, 0x4a, 0x07, 0x48, 0x33, 0x9a, 0x23, 0x37, 0x33, 0xf5, 0x99,
+    0xfd, 0xaa, 0xfb, 0x74, 0xd6, 0x7c, 0x39, 0x0a, 0x79, 0x4f, 0xc1, 0x0e,
+    0xc9, 0x8c, 0xaa
+];
+
+#[rustfmt::skip]
+pub const SECP256K1_EE: [u8; 305] = [
+    0x30, 0x82, 0x01, 0x2d, 0x30, 0x81, 0xd3, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x14, 0x21, 0xa2, 0xbe, 0xbe, 0x00, 0xcb, 0x62, 0x5f, 0x04, 0x16,
+    0xd1, 0xc9, 0xc8, 0xc5, 0xce, 0xe0, 0x17, 0x71, 0x7b, 0xf9, 0x30, 0x0a,
+    0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02, 0x30, 0x17,
+    0x31, 0x15, 0x30, 0x13, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x0c, 0x73,
+    0x65, 0x63, 0x70, 0x32, 0x35, 0x36, 0x6b, 0x31, 0x2d, 0x65, 0x65, 0x30,
+    0x20, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30, 0x35, 0x30, 0x30, 0x31,
+    0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x17, 0x31,
+    0x15, 0x30, 0x13, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x0c, 0x73, 0x65,
+    0x63, 0x70, 0x32, 0x35, 0x36, 0x6b, 0x31, 0x2d, 0x65, 0x65, 0x30, 0x56,
+    0x30, 0x10, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06,
+    0x05, 0x2b, 0x81, 0x04, 0x00, 0x0a, 0x03, 0x42, 0x00, 0x04, 0xcb, 0xfd,
+    0xcd, 0xa0, 0xa3, 0xcb, 0x63, 0xb6, 0xe9, 0xf8, 0x89, 0x00, 0x1f, 0x84,
+    0xc4, 0xa2, 0x79, 0xd1, 0x54, 0x93, 0x09, 0xd5, 0x7f, 0xe0, 0xaa, 0x00,
+    0xae, 0xe0, 0x51, 0x34, 0x4b, 0x81, 0xe6, 0x4e, 0x56, 0xfc, 0xa9, 0x0b,
+    0x36, 0x4a, 0x07, 0x48, 0x33, 0x9a, 0x23, 0x37, 0x33, 0xf5, 0x99, 0xfd,
+    0xaa, 0xfb, 0x74, 0xd6, 0x7c, 0x39, 0x0a, 0x79, 0x4f, 0xc1, 0x0e, 0xc9,
+    0x8c, 0xaa, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04,
+    0x03, 0x02, 0x03, 0x49, 0x00, 0x30, 0x46, 0x02, 0x21, 0x00, 0xc4, 0xfb,
+    0x8b, 0x4d, 0xf7, 0x1b, 0xd2, 0xb7, 0x14, 0x4d, 0x6e, 0x0c, 0x2b, 0x01,
+    0x51, 0xe1, 0xb6, 0xfe, 0x92, 0x14, 0xc8, 0x1f, 0x90, 0x76, 0x15, 0xba,
+    0xe1, 0x01, 0x67, 0x21, 0x15, 0x91, 0x02, 0x21, 0x00, 0xc5, 0x3f, 0xed,
+    0x49, 0xbb, 0x72, 0xe5, 0x6b, 0xe5, 0x6a, 0x47, 0x9e, 0xe7, 0x94, 0xdd,
+    0xce, 0xf3, 0xbd, 0xc5, 0xb1, 0x54, 0x29, 0xb7, 0x70, 0x93, 0x30, 0x62,
+    0x20, 0x9c, 0xbb, 0xd1, 0xbb
+];
+
+// A COSE_Sign1 with ES256K over "This is the content." by SECP256K1_EE with a low S value,
+// and the same signature with S replaced by n - S.
+#[rustfmt::skip]
+pub const ES256K_SIGN1_LOW_S: [u8; 95] = [
+    0xd2, 0x84, 0x44, 0xa1, 0x01, 0x38, 0x2e, 0xa0, 0x54, 0x54, 0x68, 0x69,
+    0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x6e, 0x74, 0x2e, 0x58, 0x40, 0x1f, 0x94, 0x3d, 0x23, 0x68,
+    0x8b, 0x58, 0x76, 0xbf, 0x8e, 0x68, 0x0a, 0x76, 0x4d, 0x0f, 0x8b, 0x54,
+    0x83, 0x20, 0xec, 0x43, 0xdc, 0xb0, 0xa7, 0x75, 0x9f, 0x46, 0x60, 0x6a,
+    0x45, 0xc6, 0xaa, 0x70, 0x28, 0x65, 0x4a, 0x56, 0x00, 0x40, 0x22, 0xc6,
+    0x47, 0xcd, 0xb1, 0xda, 0x1c, 0x54, 0xbe, 0xcd, 0xcd, 0xfc, 0xab, 0xfd,
+    0x82, 0x90, 0x42, 0xeb, 0xae, 0x96, 0x0a, 0x1c, 0x69, 0x7a, 0x23
+];
+
+#[rustfmt::skip]
+pub const ES256K_SIGN1_HIGH_S: [u8; 95] = [
+    0xd2, 0x84, 0x44, 0xa1, 0x01, 0x38, 0x2e, 0xa0, 0x54, 0x54, 0x68, 0x69,
+    0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x6e, 0x74, 0x2e, 0x58, 0x40, 0x1f, 0x94, 0x3d, 0x23, 0x68,
+    0x8b, 0x58, 0x76, 0xbf, 0x8e, 0x68, 0x0a, 0x76, 0x4d, 0x0f, 0x8b, 0x54,
+    0x83, 0x20, 0xec, 0x43, 0xdc, 0xb0, 0xa7, 0x75, 0x9f, 0x46, 0x60, 0x6a,
+    0x45, 0xc6, 0xaa, 0x8f, 0xd7, 0x9a, 0xb5, 0xa9, 0xff, 0xbf, 0xdd, 0x39,
+    0xb8, 0x32, 0x4e, 0x25, 0xe3, 0xab, 0x3f, 0xec, 0xe0, 0xe0, 0x3a, 0xb1,
+    0xc6, 0x0f, 0xf8, 0xd4, 0x23, 0xc8, 0x82, 0xb3, 0xcc, 0xc7, 0x1e
+];
+
+// The SHA-256 thumbprint of the P-256 key of example C.7 from RFC 9052, see RFC 9679 section 6.
+#[cfg(any(feature = "openssl", feature = "rustcrypto"))]
+#[rustfmt::skip]
+pub const COSE_KEY_EC2_P256_THUMBPRINT: [u8; 32] = [
+    0x49, 0x6b, 0xd8, 0xaf, 0xad, 0xf3, 0x07, 0xe5, 0xb0, 0x8c, 0x64, 0xb0,
+    0x42, 0x1b, 0xf9, 0xdc, 0x01, 0x52, 0x8a, 0x34, 0x4a, 0x43, 0xbd, 0xa8,
+    0x8f, 0xad, 0xd1, 0x66, 0x9d, 0xa2, 0x53, 0xec
+];
+
+// Half the order of the secp256k1 group. ES256K signatures must have an S value at most this.
+#[cfg(any(feature = "openssl", feature = "rustcrypto"))]
+#[rustfmt::skip]
+pub const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d,
+    0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0
+];
+
+// A self-signed certificate with a 1024 bit RSA key, which is too small for the RSA signature
+// algorithms, generated with pyca/cryptography.
+#[rustfmt::skip]
+pub const PKCS8_RSA1024_EE: [u8; 634] = [
+    0x30, 0x82, 0x02, 0x76, 0x02, 0x01, 0x00, 0x30, 0x0d, 0x06, 0x09, 0x2a,
+    0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01, 0x05, 0x00, 0x04, 0x82,
+    0x02, 0x60, 0x30, 0x82, 0x02, 0x5c, 0x02, 0x01, 0x00, 0x02, 0x81, 0x81,
+    0x00, 0xc9, 0x30, 0x8f, 0x18, 0x85, 0x73, 0x40, 0x19, 0x4a, 0xef, 0xc7,
+    0xcd, 0xe2, 0xce, 0x69, 0x79, 0x6f, 0xf4, 0xc1, 0x44, 0x66, 0xd5, 0x39,
+    0x63, 0x04, 0x6d, 0xa9, 0x40, 0x10, 0xa6, 0xcf, 0x81, 0xa6, 0xa5, 0xe1,
+    0xdc, 0xe9, 0xc1, 0x0a, 0xb8, 0x76, 0x37, 0x25, 0x03, 0xd1, 0x41, 0xdd,
+    0x23, 0xca, 0xc1, 0x43, 0x14, 0xa0, 0xfc, 0xd3, 0x34, 0x50, 0x16, 0x31,
+    0x86, 0xe4, 0x7c, 0x84, 0x47, 0x73, 0xff, 0x80, 0x32, 0x51, 0x04, 0x0a,
+    0xaa, 0x26, 0xe6, 0xec, 0x0a, 0x74, 0xef, 0xd8, 0x3e, 0xb2, 0x53, 0xee,
+    0x0a, 0x17, 0x66, 0x26, 0x29, 0xc6, 0x6f, 0xed, 0xaa, 0xd4, 0x74, 0xbb,
+    0x23, 0xf9, 0x33, 0x1e, 0xfb, 0x76, 0xe0, 0x40, 0x09, 0x38, 0x8b, 0x8a,
+    0xf6, 0x1d, 0x3b, 0xe7, 0xfe, 0x72, 0x8c, 0x0f, 0xa4, 0x15, 0x19, 0xc2,
+    0x59, 0x2b, 0xcb, 0x29, 0x62, 0xba, 0xde, 0xa1, 0x79, 0x02, 0x03, 0x01,
+    0x00, 0x01, 0x02, 0x81, 0x80, 0x3e, 0xa7, 0xea, 0xc8, 0x9e, 0x36, 0xe8,
+    0x4d, 0x78, 0x32, 0xf8, 0xac, 0xbb, 0xe7, 0xc0, 0x9d, 0x5b, 0x2a, 0x84,
+    0x4e, 0x03, 0x20, 0x80, 0x80, 0xbf, 0xce, 0x4f, 0x19, 0x6e, 0x96, 0x24,
+    0xa5, 0xa1, 0xfa, 0xa3, 0x3e, 0xa3, 0x65, 0xc5, 0x2a, 0xf1, 0x55, 0xf7,
+    0x27, 0x1b, 0x32, 0x41, 0xe6, 0x75, 0xac, 0x7e, 0x71, 0x9c, 0x39, 0x60,
+    0xa5, 0x38, 0x13, 0xd1, 0x54, 0xcb, 0x2f, 0xa4, 0x19, 0xf6, 0x5d, 0xdb,
+    0x7b, 0xe0, 0x52, 0xb2, 0x9a, 0xe8, 0x72, 0x68, 0xc0, 0x8f, 0xf4, 0x08,
+    0x3b, 0x8c, 0xcd, 0xf9, 0xd2, 0xe5, 0x92, 0x48, 0xe3, 0x24, 0x58, 0x0d,
+    0xed, 0xe3, 0xf5, 0x1f, 0x8b, 0xad, 0xe8, 0xf5, 0xba, 0x17, 0x2a, 0xca,
+    0x86, 0xc4, 0xfc, 0xc3, 0x7c, 0x12, 0x67, 0xee, 0xf1, 0xb9, 0x94, 0x6f,
+    0xd5, 0x7c, 0xd9, 0x49, 0x66, 0x45, 0x39, 0xa5, 0x2c, 0xf4, 0x66, 0x60,
+    0xa1, 0x02, 0x41, 0x00, 0xfd, 0xd4, 0xd9, 0x24, 0x4f, 0x99, 0x35, 0x1a,
+    0x04, 0xb0, 0x97, 0xb5, 0x54, 0x11, 0xcd, 0xc0, 0x14, 0x2d, 0x7f, 0x17,
+    0x4b, 0x2e, 0x1c, 0x2c, 0xbf, 0x62, 0x13, 0xec, 0x0e, 0x10, 0xc4, 0x63,
+    0x3f, 0xe5, 0xec, 0x6d, 0x36, 0xf8, 0xe6, 0x37, 0xed, 0x66, 0xc4, 0x7b,
+    0x36, 0xc9, 0x63, 0x9b, 0xbb, 0x6d, 0xcc, 0x5a, 0x62, 0x2b, 0x10, 0x64,
+    0x37, 0xc5, 0x65, 0x3b, 0x5f, 0x4c, 0xeb, 0xb5, 0x02, 0x41, 0x00, 0xca,
+    0xe8, 0x94, 0x1e, 0x49, 0xe2, 0x01, 0x44, 0x6f, 0xcf, 0x9d, 0x04, 0xd1,
+    0xf0, 0xad, 0x68, 0x1a, 0x54, 0x9b, 0xbd, 0x99, 0x34, 0x6b, 0xc1, 0x35,
+    0x2b, 0xf9, 0xa1, 0x40, 0x1e, 0x6e, 0xb3, 0xe9, 0x6e, 0xdd, 0xfa, 0x7b,
+    0xbf, 0x4a, 0xf2, 0x7c, 0xf1, 0xd8, 0x67, 0xe9, 0x61, 0xde, 0x4c, 0x14,
+    0xd6, 0x26, 0x10, 0x5e, 0x95, 0x9c, 0xd0, 0xab, 0xe0, 0x82, 0x45, 0x64,
+    0x01, 0xa1, 0x35, 0x02, 0x40, 0x00, 0x92, 0xbe, 0x7a, 0xca, 0x83, 0x3a,
+    0x0f, 0x78, 0xfb, 0x3d, 0x1c, 0x77, 0xb8, 0x22, 0x92, 0xd0, 0x78, 0xd9,
+    0xd4, 0x9f, 0xa2, 0xd7, 0x05, 0x72, 0xd9, 0x31, 0xda, 0x52, 0x9d, 0xcf,
+    0x13, 0xba, 0x4d, 0x36, 0x9f, 0x78, 0x84, 0x4f, 0x60, 0xc8, 0xf4, 0x76,
+    0x75, 0x20, 0xc6, 0x8b, 0xa7, 0x0a, 0x61, 0x51, 0x18, 0xd3, 0x27, 0xed,
+    0xbd, 0x8c, 0x32, 0xb1, 0xeb, 0xd4, 0x67, 0x44, 0x11, 0x02, 0x40, 0x07,
+    0xcc, 0x8e, 0xbc, 0xe7, 0x4c, 0x17, 0x8e, 0x60, 0xd9, 0xd3, 0x08, 0x37,
+    0x65, 0x8f, 0xd4, 0xbb, 0x86, 0x5b, 0x02, 0x1b, 0x9c, 0x67, 0x33, 0xe2,
+    0xee, 0x50, 0xc7, 0x00, 0xe6, 0xea, 0x8c, 0x61, 0xc1, 0x90, 0x28, 0xab,
+    0x8e, 0xfb, 0xbb, 0x7c, 0x4f, 0xf3, 0x68, 0x73, 0x10, 0xfe, 0xf1, 0x5f,
+    0xaf, 0xa7, 0x80, 0xe3, 0x91, 0xce, 0x98, 0x3a, 0xb3, 0x03, 0x6e, 0xd5,
+    0x5c, 0x36, 0xad, 0x02, 0x41, 0x00, 0xe7, 0xc1, 0x77, 0xa5, 0xe4, 0xc8,
+    0x85, 0x2a, 0x26, 0xa7, 0xf5, 0x3b, 0x70, 0x41, 0x64, 0x4e, 0x42, 0x74,
+    0x3a, 0x61, 0xa5, 0xba, 0x59, 0x62, 0x0c, 0x13, 0xcc, 0xee, 0x84, 0x8e,
+    0xc3, 0x83, 0xe7, 0xb5, 0xf2, 0x62, 0x84, 0x41, 0x34, 0x9c, 0x03, 0x90,
+    0x6d, 0x93, 0x29, 0x6f, 0x4b, 0x97, 0xe6, 0x25, 0x67, 0x2f, 0x9f, 0x88,
+    0x61, 0xb3, 0x36, 0x95, 0xed, 0xd3, 0xf4, 0x50, 0x80, 0xbf
+];
+
+#[rustfmt::skip]
+pub const RSA1024_EE: [u8; 439] = [
+    0x30, 0x82, 0x01, 0xb3, 0x30, 0x82, 0x01, 0x1c, 0xa0, 0x03, 0x02, 0x01,
+    0x02, 0x02, 0x14, 0x29, 0xa4, 0x46, 0x33, 0x33, 0x15, 0x35, 0x3c, 0x55,
+    0x10, 0xdd, 0x14, 0xba, 0x63, 0x01, 0x85, 0xbc, 0x45, 0xda, 0xdd, 0x30,
+    0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b,
+    0x05, 0x00, 0x30, 0x15, 0x31, 0x13, 0x30, 0x11, 0x06, 0x03, 0x55, 0x04,
+    0x03, 0x0c, 0x0a, 0x72, 0x73, 0x61, 0x31, 0x30, 0x32, 0x34, 0x2d, 0x65,
+    0x65, 0x30, 0x20, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30, 0x35, 0x30,
+    0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30,
+    0x15, 0x31, 0x13, 0x30, 0x11, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x0a,
+    0x72, 0x73, 0x61, 0x31, 0x30, 0x32, 0x34, 0x2d, 0x65, 0x65, 0x30, 0x81,
+    0x9f, 0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01,
+    0x01, 0x01, 0x05, 0x00, 0x03, 0x81, 0x8d, 0x00, 0x30, 0x81, 0x89, 0x02,
+    0x81, 0x81, 0x00, 0xc9, 0x30, 0x8f, 0x18, 0x85, 0x73, 0x40, 0x19, 0x4a,
+    0xef, 0xc7, 0xcd, 0xe2, 0xce, 0x69, 0x79, 0x6f, 0xf4, 0xc1, 0x44, 0x66,
+    0xd5, 0x39, 0x63, 0x04, 0x6d, 0xa9, 0x40, 0x10, 0xa6, 0xcf, 0x81, 0xa6,
+    0xa5, 0xe1, 0xdc, 0xe9, 0xc1, 0x0a, 0xb8, 0x76, 0x37, 0x25, 0x03, 0xd1,
+    0x41, 0xdd, 0x23, 0xca, 0xc1, 0x43, 0x14, 0xa0, 0xfc, 0xd3, 0x34, 0x50,
+    0x16, 0x31, 0x86, 0xe4, 0x7c, 0x84, 0x47, 0x73, 0xff, 0x80, 0x32, 0x51,
+    0x04, 0x0a, 0xaa, 0x26, 0xe6, 0xec, 0x0a, 0x74, 0xef, 0xd8, 0x3e, 0xb2,
+    0x53, 0xee, 0x0a, 0x17, 0x66, 0x26, 0x29, 0xc6, 0x6f, 0xed, 0xaa, 0xd4,
+    0x74, 0xbb, 0x23, 0xf9, 0x33, 0x1e, 0xfb, 0x76, 0xe0, 0x40, 0x09, 0x38,
+    0x8b, 0x8a, 0xf6, 0x1d, 0x3b, 0xe7, 0xfe, 0x72, 0x8c, 0x0f, 0xa4, 0x15,
+    0x19, 0xc2, 0x59, 0x2b, 0xcb, 0x29, 0x62, 0xba, 0xde, 0xa1, 0x79, 0x02,
+    0x03, 0x01, 0x00, 0x01, 0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86,
+    0xf7, 0x0d, 0x01, 0x01, 0x0b, 0x05, 0x00, 0x03, 0x81, 0x81, 0x00, 0xa4,
+    0x79, 0x7d, 0x21, 0xee, 0xf6, 0xa0, 0x09, 0xe2, 0x45, 0xad, 0x28, 0x92,
+    0xa6, 0x97, 0xb8, 0x8e, 0xba, 0x3c, 0x2c, 0xd3, 0x15, 0x10, 0x64, 0x92,
+    0xe4, 0x1e, 0x1e, 0x88, 0x2e, 0xef, 0xd9, 0xc1, 0x59, 0x6b, 0xa8, 0xf8,
+    0x83, 0xb6, 0x72, 0x4b, 0xf6, 0x1f, 0xcb, 0x1d, 0x99, 0xcd, 0x40, 0x9a,
+    0x45, 0xb9, 0x6e, 0x68, 0x26, 0x82, 0x19, 0xad, 0xf0, 0x46, 0xdc, 0x30,
+    0x0e, 0x25, 0x6e, 0x90, 0x3f, 0xbd, 0x8d, 0x3a, 0x28, 0xc9, 0xdb, 0x04,
+    0x6c, 0xde, 0x7d, 0x29, 0xd7, 0x43, 0x49, 0x9d, 0x22, 0xf5, 0x40, 0x9c,
+    0x64, 0x17, 0xae, 0x73, 0x87, 0xf9, 0x75, 0xe8, 0x30, 0x46, 0xb6, 0x11,
+    0x72, 0x31, 0xab, 0xc5, 0x70, 0x31, 0xa9, 0x09, 0x27, 0xe3, 0xf3, 0x3d,
+    0x41, 0x55, 0x54, 0x37, 0x2e, 0x91, 0xe9, 0x88, 0x2e, 0x1b, 0xed, 0x7a,
+    0x60, 0x1e, 0x4b, 0xc4, 0x41, 0xdc, 0x3d
+];
+
+// A valid COSE_Sign1 with RS256 over "This is the content." by RSA1024_EE.
+#[rustfmt::skip]
+pub const RS256_SIGN1_RSA1024: [u8; 160] = [
+    0xd2, 0x84, 0x45, 0xa1, 0x01, 0x39, 0x01, 0x00, 0xa0, 0x54, 0x54, 0x68,
+    0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x6e, 0x74, 0x2e, 0x58, 0x80, 0xc6, 0x72, 0x3c, 0xb9,
+    0xb4, 0x90, 0xed, 0x60, 0xd6, 0x11, 0x4f, 0x02, 0xe3, 0x3a, 0xdf, 0x9d,
+    0xbc, 0x6b, 0x8a, 0x1c, 0xdd, 0xae, 0xba, 0xab, 0x17, 0xa7, 0x7d, 0x1a,
+    0x22, 0xe8, 0x0b, 0xb8, 0x28, 0xca, 0xf9, 0x78, 0x21, 0x25, 0xcc, 0x5a,
+    0x97, 0x3b, 0xdb, 0x53, 0x61, 0x4c, 0xf1, 0xb5, 0xac, 0xf4, 0x01, 0xf3,
+    0xf9, 0xa1, 0x4b, 0x24, 0x66, 0xcc, 0x31, 0xdf, 0xb7, 0xf5, 0x21, 0xef,
+    0xa6, 0x6d, 0x3a, 0x8b, 0x86, 0xa3, 0xf4, 0x30, 0x8e, 0xe6, 0xdf, 0xda,
+    0xed, 0x5e, 0x7d, 0x53, 0x60, 0x96, 0xc2, 0x3e, 0x0c, 0x72, 0x1d, 0x3c,
+    0xd7, 0xe1, 0x34, 0x42, 0x63, 0x33, 0xb8, 0x41, 0xc1, 0xe6, 0xa8, 0x0f,
+    0xf9, 0x71, 0xee, 0x2a, 0xc3, 0xee, 0x31, 0x00, 0x28, 0xb6, 0xb8, 0xf9,
+    0x35, 0x3c, 0xa0, 0xb2, 0xf2, 0x52, 0x13, 0x2e, 0x46, 0x8c, 0x63, 0x18,
+    0x6f, 0xdb, 0x9e, 0x5f
+];
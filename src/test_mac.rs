@@ -0,0 +1,240 @@
+use test_setup as test;
+use {CoseError, Mac, MacAlgorithm};
+use algorithm::MAC_ALGORITHMS;
+use decoder::{COSE_ALG_DIRECT, CoseRecipient, decode_mac, decode_mac0, decode_mac0_with_options,
+              DecodeOptions};
+use encoder::{CoseMac0Builder, CoseMacBuilder};
+use header::{Header, Label};
+use util::{MacContext, MacStructure};
+
+const PAYLOAD: &[u8] = b"This is the content.";
+
+// A `Mac` for tests that returns the same output for every input. It fails for other algorithms
+// than the expected one.
+struct TestMac {
+    algorithm: MacAlgorithm,
+    output: Vec<u8>,
+}
+
+impl Mac for TestMac {
+    fn mac(&self, algorithm: MacAlgorithm, _: &[u8]) -> Result<Vec<u8>, CoseError> {
+        if algorithm != self.algorithm {
+            return Err(CoseError::InvalidArgument);
+        }
+        Ok(self.output.clone())
+    }
+}
+
+fn kid_header() -> Header {
+    Header {
+        kid: Some(b"our-secret".to_vec()),
+        ..Header::default()
+    }
+}
+
+fn mac0_vectors() -> [(MacAlgorithm, &'static [u8]); 4] {
+    [(MacAlgorithm::HMAC256_64, &test::COSE_MAC0_HMAC256_64),
+     (MacAlgorithm::HMAC256_256, &test::COSE_MAC0_HMAC256_256),
+     (MacAlgorithm::HMAC384_384, &test::COSE_MAC0_HMAC384_384),
+     (MacAlgorithm::HMAC512_512, &test::COSE_MAC0_HMAC512_512)]
+}
+
+#[test]
+fn test_mac0_decode() {
+    for &(algorithm, bytes) in &mac0_vectors() {
+        let cose_mac0 = decode_mac0(bytes, None).unwrap();
+        assert_eq!(cose_mac0.mac_type, algorithm);
+        assert_eq!(cose_mac0.tag.len(), algorithm.info().tag_length);
+        assert_eq!(cose_mac0.payload, PAYLOAD.to_vec());
+        assert_eq!(cose_mac0.unprotected_header, kid_header());
+        let protected = [0xa1, 0x01, algorithm.id() as u8];
+        let to_mac = MacStructure::new(MacContext::Mac0, &protected, PAYLOAD).to_bytes();
+        assert_eq!(cose_mac0.to_mac, to_mac);
+    }
+}
+
+#[test]
+fn test_mac0_verify() {
+    let cose_mac0 = decode_mac0(&test::COSE_MAC0_HMAC256_64, None).unwrap();
+    // The HMAC output is truncated to the tag.
+    let mut output = cose_mac0.tag.clone();
+    output.extend_from_slice(&[0xff; 24]);
+    let mac = TestMac { algorithm: MacAlgorithm::HMAC256_64, output: output.clone() };
+    assert_eq!(cose_mac0.verify_with(&mac), Ok(()));
+
+    output[7] ^= 1;
+    let mac = TestMac { algorithm: MacAlgorithm::HMAC256_64, output: output.clone() };
+    assert_eq!(cose_mac0.verify_with(&mac), Err(CoseError::VerificationFailed));
+    let mac = TestMac { algorithm: MacAlgorithm::HMAC256_64, output: output[..7].to_vec() };
+    assert_eq!(cose_mac0.verify_with(&mac), Err(CoseError::LibraryFailure));
+    let mac = TestMac { algorithm: MacAlgorithm::HMAC256_256, output };
+    assert_eq!(cose_mac0.verify_with(&mac), Err(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_mac0_builder_reproduces_vectors() {
+    for &(algorithm, bytes) in &mac0_vectors() {
+        let tag = decode_mac0(bytes, None).unwrap().tag;
+        let mac = TestMac { algorithm, output: tag };
+        let cose_mac0 = CoseMac0Builder::new(PAYLOAD)
+            .unprotected_header(kid_header())
+            .mac_with(algorithm, &mac)
+            .build()
+            .unwrap();
+        assert_eq!(cose_mac0, bytes.to_vec());
+    }
+}
+
+#[test]
+fn test_mac0_builder_untagged_detached() {
+    let to_mac = MacStructure::new(MacContext::Mac0, &[0xa1, 0x01, 0x06], PAYLOAD)
+        .external_aad(&[0x01, 0x02])
+        .to_bytes();
+    let cose_mac0 = CoseMac0Builder::new(PAYLOAD)
+        .detached()
+        .untagged()
+        .external_aad(&[0x01, 0x02])
+        .mac(MacAlgorithm::HMAC384_384, |bytes| {
+            assert_eq!(bytes, &to_mac[..]);
+            Ok(vec![0x2a; 48])
+        })
+        .build()
+        .unwrap();
+    // An untagged array with a nil payload.
+    assert_eq!(cose_mac0[0], 0x84);
+    assert_eq!(decode_mac0(&cose_mac0, None).unwrap_err(), CoseError::InvalidArgument);
+
+    let options = DecodeOptions::new().external_aad(&[0x01, 0x02]);
+    let decoded = decode_mac0_with_options(&cose_mac0, Some(PAYLOAD), &options).unwrap();
+    assert_eq!(decoded.to_mac, to_mac);
+    assert_eq!(decoded.tag, vec![0x2a; 48]);
+    let decoded = decode_mac0(&cose_mac0, Some(PAYLOAD)).unwrap();
+    assert_ne!(decoded.to_mac, to_mac);
+}
+
+#[test]
+fn test_mac0_builder_invalid_arguments() {
+    assert_eq!(CoseMac0Builder::new(PAYLOAD).build(), Err(CoseError::InvalidArgument));
+
+    let header = Header {
+        alg: Some(Label::Int(5)),
+        ..Header::default()
+    };
+    let builder = CoseMac0Builder::new(PAYLOAD)
+        .protected_header(header)
+        .mac(MacAlgorithm::HMAC256_256, |_| Ok(vec![0; 32]));
+    assert_eq!(builder.build(), Err(CoseError::InvalidArgument));
+
+    let builder = CoseMac0Builder::new(PAYLOAD)
+        .protected_header(kid_header())
+        .unprotected_header(kid_header())
+        .mac(MacAlgorithm::HMAC256_256, |_| Ok(vec![0; 32]));
+    assert_eq!(builder.build(), Err(CoseError::InvalidArgument));
+
+    // The output of the MAC function has to be at least as long as the tag.
+    for &algorithm in MAC_ALGORITHMS.iter() {
+        let tag_length = algorithm.info().tag_length;
+        let builder = CoseMac0Builder::new(PAYLOAD)
+            .mac(algorithm, move |_| Ok(vec![0; tag_length - 1]));
+        assert_eq!(builder.build(), Err(CoseError::LibraryFailure));
+    }
+    let builder = CoseMac0Builder::new(PAYLOAD)
+        .mac(MacAlgorithm::HMAC256_256, |_| Err(CoseError::SigningFailed));
+    assert_eq!(builder.build(), Err(CoseError::SigningFailed));
+}
+
+#[test]
+fn test_mac0_decode_errors() {
+    // COSE_Mac0 has four elements.
+    assert_eq!(decode_mac0(&test::COSE_MAC_HMAC256_256[2..], None).unwrap_err(),
+               CoseError::MalformedInput);
+    assert_eq!(decode_mac0(&test::COSE_MAC_HMAC256_256, None).unwrap_err(),
+               CoseError::UnexpectedTag);
+    assert_eq!(decode_mac0(&test::COSE_SIGN1_BYTES, None).unwrap_err(),
+               CoseError::UnexpectedTag);
+
+    // The tag has to have the length of the algorithm: 0x04 is HMAC 256/64, 0x05 HMAC 256/256.
+    let mut bytes = test::COSE_MAC0_HMAC256_64.to_vec();
+    bytes[5] = 0x05;
+    assert_eq!(decode_mac0(&bytes, None).unwrap_err(), CoseError::MalformedInput);
+    // ES256 is not a MAC algorithm.
+    bytes[5] = 0x26;
+    assert_eq!(decode_mac0(&bytes, None).unwrap_err(), CoseError::UnexpectedHeaderValue);
+    // An empty protected header has no alg.
+    let mut bytes = vec![0xd1, 0x84, 0x40];
+    bytes.extend_from_slice(&test::COSE_MAC0_HMAC256_64[6..]);
+    assert_eq!(decode_mac0(&bytes, None).unwrap_err(), CoseError::MissingHeader);
+}
+
+#[test]
+fn test_mac_decode_verify() {
+    let cose_mac = decode_mac(&test::COSE_MAC_HMAC256_256, None).unwrap();
+    assert_eq!(cose_mac.mac_type, MacAlgorithm::HMAC256_256);
+    assert_eq!(cose_mac.payload, PAYLOAD.to_vec());
+    assert_eq!(cose_mac.to_mac,
+               MacStructure::new(MacContext::Mac, &[0xa1, 0x01, 0x05], PAYLOAD).to_bytes());
+    assert_eq!(cose_mac.recipients, vec![CoseRecipient::direct(b"our-secret")]);
+    let recipient = &cose_mac.recipients[0];
+    assert_eq!(recipient.unprotected_header.alg, Some(Label::Int(COSE_ALG_DIRECT)));
+    assert_eq!(recipient.ciphertext, Some(Vec::new()));
+
+    let mac = TestMac { algorithm: MacAlgorithm::HMAC256_256, output: cose_mac.tag.clone() };
+    assert_eq!(cose_mac.verify_with(&mac), Ok(()));
+    let mac = TestMac { algorithm: MacAlgorithm::HMAC256_256, output: vec![0; 32] };
+    assert_eq!(cose_mac.verify_with(&mac), Err(CoseError::VerificationFailed));
+}
+
+#[test]
+fn test_mac_builder() {
+    let tag = decode_mac(&test::COSE_MAC_HMAC256_256, None).unwrap().tag;
+    let mac = TestMac { algorithm: MacAlgorithm::HMAC256_256, output: tag };
+    let builder = CoseMacBuilder::new(PAYLOAD).mac_with(MacAlgorithm::HMAC256_256, &mac);
+    // There has to be a recipient.
+    assert_eq!(builder.build(), Err(CoseError::InvalidArgument));
+    let builder = builder.recipient(CoseRecipient::direct(b"our-secret"));
+    assert_eq!(builder.build().unwrap(), test::COSE_MAC_HMAC256_256.to_vec());
+
+    // Recipients with nil ciphertext and nested recipients.
+    let nested = CoseRecipient {
+        ciphertext: None,
+        recipients: vec![CoseRecipient::direct(b"inner")],
+        ..CoseRecipient::default()
+    };
+    let cose_mac = CoseMacBuilder::new(PAYLOAD)
+        .untagged()
+        .recipient(CoseRecipient::direct(b"our-secret"))
+        .recipient(nested.clone())
+        .mac(MacAlgorithm::HMAC512_512, |_| Ok(vec![0x01; 64]))
+        .build()
+        .unwrap();
+    let decoded = decode_mac(&cose_mac, None).unwrap();
+    assert_eq!(decoded.recipients, vec![CoseRecipient::direct(b"our-secret"), nested]);
+
+    let mut recipient = CoseRecipient::direct(b"our-secret");
+    recipient.protected_header = kid_header();
+    let builder = CoseMacBuilder::new(PAYLOAD)
+        .recipient(recipient)
+        .mac(MacAlgorithm::HMAC256_256, |_| Ok(vec![0; 32]));
+    assert_eq!(builder.build(), Err(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_mac_decode_errors() {
+    assert_eq!(decode_mac(&test::COSE_MAC0_HMAC256_256, None).unwrap_err(),
+               CoseError::UnexpectedTag);
+    // The last 19 bytes are the array of recipients.
+    let length = test::COSE_MAC_HMAC256_256.len();
+    let mut bytes = test::COSE_MAC_HMAC256_256[..length - 19].to_vec();
+    bytes.push(0x80);
+    assert_eq!(decode_mac(&bytes, None).unwrap_err(), CoseError::MalformedInput);
+    // A recipient needs at least three elements.
+    bytes.pop();
+    bytes.extend_from_slice(&[0x81, 0x82, 0x40, 0xa0]);
+    assert_eq!(decode_mac(&bytes, None).unwrap_err(), CoseError::MalformedInput);
+    // The ciphertext is a bstr or nil.
+    bytes.pop();
+    bytes.pop();
+    bytes.pop();
+    bytes.extend_from_slice(&[0x83, 0x40, 0xa0, 0x01]);
+    assert_eq!(decode_mac(&bytes, None).unwrap_err(), CoseError::UnexpectedType);
+}
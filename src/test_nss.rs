@@ -0,0 +1,206 @@
+use test_keys as keys;
+use test_setup as test;
+use {CoseError, SignatureAlgorithm, Signer, Verifier};
+use decoder::{decode_sign1, decode_signature};
+use nss::{NssSigner, NssVerifier};
+use std::os::raw;
+use std::ptr;
+use std::sync::Once;
+use verify::{SignerPolicy, VerificationPolicy, verify_signature};
+
+static START: Once = Once::new();
+
+#[link(name = "nss3")]
+extern "C" {
+    fn NSS_NoDB_Init(configdir: *const u8) -> raw::c_int;
+}
+
+pub fn setup() {
+    START.call_once(|| {
+        let null_ptr: *const u8 = ptr::null();
+        unsafe {
+            assert_eq!(NSS_NoDB_Init(null_ptr), 0);
+        }
+    });
+}
+
+#[test]
+fn test_nss_sign_verify() {
+    setup();
+    let payload = b"sample";
+
+    // Sign.
+    let signer = NssSigner::from_pkcs8(&keys::PKCS8_P256_EE).unwrap();
+    let signature = signer.sign(SignatureAlgorithm::ES256, payload).unwrap();
+
+    // Verify the signature.
+    let verify_result =
+        NssVerifier.verify(SignatureAlgorithm::ES256, &keys::P256_EE, payload, &signature);
+    assert_eq!(verify_result, Ok(()));
+
+    // Verify the signature with a different payload.
+    let verify_result =
+        NssVerifier.verify(SignatureAlgorithm::ES256, &keys::P256_EE, b"sampli", &signature);
+    assert_eq!(verify_result, Err(CoseError::VerificationFailed));
+
+    // Verify the signature with a wrong cert.
+    let verify_result =
+        NssVerifier.verify(SignatureAlgorithm::ES256, &keys::P384_EE, payload, &signature);
+    assert_eq!(verify_result, Err(CoseError::VerificationFailed));
+}
+
+#[test]
+fn test_nss_invalid_inputs() {
+    setup();
+    assert_eq!(NssSigner::from_pkcs8(&[0x30]).err(), Some(CoseError::DecodingFailure));
+    let result = NssVerifier.verify(SignatureAlgorithm::ES256, &[0x30], b"sample", &[0; 64]);
+    assert_eq!(result, Err(CoseError::DecodingFailure));
+}
+
+fn sign_verify(algorithm: SignatureAlgorithm, pkcs8: &[u8], cert: &[u8]) {
+    setup();
+    let signer = NssSigner::from_pkcs8(pkcs8).unwrap();
+    keys::test_sign_verify(algorithm, cert, &signer, &NssVerifier);
+}
+
+#[test]
+fn test_nss_es256() {
+    sign_verify(SignatureAlgorithm::ES256, &keys::PKCS8_P256_EE, &keys::P256_EE);
+}
+
+#[test]
+fn test_nss_es384() {
+    sign_verify(SignatureAlgorithm::ES384, &keys::PKCS8_P384_EE, &keys::P384_EE);
+}
+
+#[test]
+fn test_nss_es512() {
+    sign_verify(SignatureAlgorithm::ES512, &keys::PKCS8_P521_EE, &keys::P521_EE);
+}
+
+#[test]
+fn test_nss_ps256() {
+    sign_verify(SignatureAlgorithm::PS256, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+}
+
+#[test]
+fn test_nss_ps384_ps512() {
+    sign_verify(SignatureAlgorithm::PS384, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+    sign_verify(SignatureAlgorithm::PS512, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+}
+
+#[test]
+fn test_nss_rs256_rs384_rs512() {
+    sign_verify(SignatureAlgorithm::RS256, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+    sign_verify(SignatureAlgorithm::RS384, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+    sign_verify(SignatureAlgorithm::RS512, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+}
+
+#[test]
+fn test_nss_sign_wrong_algorithm() {
+    setup();
+    let signer = NssSigner::from_pkcs8(&keys::PKCS8_P256_EE).unwrap();
+    let result = signer.sign(SignatureAlgorithm::ES384, b"sample");
+    assert_eq!(result, Err(CoseError::InvalidArgument));
+    assert_eq!(signer.sign(SignatureAlgorithm::PS256, b"sample"), Err(CoseError::InvalidArgument));
+    let signer = NssSigner::from_pkcs8(&keys::PKCS8_RSA_EE).unwrap();
+    assert_eq!(signer.sign(SignatureAlgorithm::ES256, b"sample"), Err(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_nss_verify_wrong_algorithm() {
+    setup();
+    let verify = |algorithm, cert: &[u8], signature_len| {
+        NssVerifier.verify(algorithm, cert, b"sample", &vec![1; signature_len])
+    };
+    let failed = Err(CoseError::VerificationFailed);
+    assert_eq!(verify(SignatureAlgorithm::ES256, &keys::P384_EE, 64), failed);
+    assert_eq!(verify(SignatureAlgorithm::ES384, &keys::P256_EE, 96), failed);
+    assert_eq!(verify(SignatureAlgorithm::ES512, &keys::P256_EE, 132), failed);
+    assert_eq!(verify(SignatureAlgorithm::PS256, &keys::P256_EE, 64), failed);
+    assert_eq!(verify(SignatureAlgorithm::ES256, &keys::RSA_EE, 64), failed);
+}
+
+#[test]
+fn test_nss_verify_xpi_signature() {
+    setup();
+    let cose_signatures = decode_signature(&test::XPI_SIGNATURE, &test::XPI_PAYLOAD).unwrap();
+    assert_eq!(cose_signatures[0].verify_with(&NssVerifier), Ok(()));
+}
+
+#[test]
+fn test_nss_signer_verifier() {
+    setup();
+    let signer = NssSigner::from_pkcs8(&keys::PKCS8_P384_EE).unwrap();
+    let signature = signer.sign(SignatureAlgorithm::ES384, b"sample").unwrap();
+    let result =
+        NssVerifier.verify(SignatureAlgorithm::ES384, &keys::P384_EE, b"sample", &signature);
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn test_nss_eddsa_unsupported() {
+    setup();
+    let signer = NssSigner::from_pkcs8(&keys::PKCS8_P256_EE).unwrap();
+    let result = signer.sign(SignatureAlgorithm::EdDSA, b"sample");
+    assert_eq!(result, Err(CoseError::UnknownSignatureScheme));
+    let keys = [(SignatureAlgorithm::Ed25519, &keys::PKCS8_ED25519_EE[..], &keys::ED25519_EE[..]),
+                (SignatureAlgorithm::Ed448, &keys::PKCS8_ED448_EE, &keys::ED448_EE)];
+    for &(algorithm, pkcs8, cert) in &keys {
+        let result =
+            NssSigner::from_pkcs8(pkcs8).and_then(|signer| signer.sign(algorithm, b"sample"));
+        assert!(result.is_err());
+        let result = NssVerifier.verify(algorithm, cert, b"sample", &[]);
+        assert_eq!(result, Err(CoseError::UnknownSignatureScheme));
+    }
+}
+
+#[test]
+fn test_nss_es256k_unsupported() {
+    setup();
+    let result = NssSigner::from_pkcs8(&keys::PKCS8_SECP256K1_EE)
+        .and_then(|signer| signer.sign(SignatureAlgorithm::ES256K, b"sample"));
+    assert!(result.is_err());
+    let signer = NssSigner::from_pkcs8(&keys::PKCS8_P256_EE).unwrap();
+    let result = signer.sign(SignatureAlgorithm::ES256K, b"sample");
+    assert_eq!(result, Err(CoseError::UnknownSignatureScheme));
+    for cose_sign1 in &[&keys::ES256K_SIGN1_LOW_S, &keys::ES256K_SIGN1_HIGH_S] {
+        let cose_sign1 = decode_sign1(&cose_sign1[..], None).unwrap();
+        let result = cose_sign1.verify_with(&keys::SECP256K1_EE, &NssVerifier);
+        assert_eq!(result, Err(CoseError::UnknownSignatureScheme));
+    }
+}
+
+#[test]
+fn test_nss_rsa_key_size() {
+    setup();
+    // RSA keys must have at least 2048 bits.
+    let signer = NssSigner::from_pkcs8(&keys::PKCS8_RSA1024_EE).unwrap();
+    assert_eq!(signer.sign(SignatureAlgorithm::PS256, b"sample"), Err(CoseError::InvalidArgument));
+    assert_eq!(signer.sign(SignatureAlgorithm::RS256, b"sample"), Err(CoseError::InvalidArgument));
+    let cose_sign1 = decode_sign1(&keys::RS256_SIGN1_RSA1024, None).unwrap();
+    let result = cose_sign1.verify_with(&keys::RSA1024_EE, &NssVerifier);
+    assert_eq!(result, Err(CoseError::VerificationFailed));
+}
+
+#[test]
+fn test_nss_validate_chain() {
+    setup();
+    keys::test_validate_chain(&NssVerifier);
+}
+
+#[test]
+fn test_nss_verify_signature_policy() {
+    setup();
+    // The add-on signature has a single ES256 signature.
+    let policy = VerificationPolicy::new(SignerPolicy::All)
+        .require_algorithm(SignatureAlgorithm::ES256);
+    let report =
+        verify_signature(&test::XPI_SIGNATURE, &test::XPI_PAYLOAD, &NssVerifier, &policy).unwrap();
+    assert!(report.is_verified());
+    let policy = policy.require_algorithm(SignatureAlgorithm::PS256);
+    let report =
+        verify_signature(&test::XPI_SIGNATURE, &test::XPI_PAYLOAD, &NssVerifier, &policy).unwrap();
+    assert_eq!(report.verified_count(), 1);
+    assert_eq!(report.result, Err(CoseError::VerificationFailed));
+}
@@ -0,0 +1,224 @@
+use test_keys as keys;
+use test_setup as test;
+use {CoseError, SignatureAlgorithm, Signer, Verifier};
+use decoder::{decode_sign1, decode_signature};
+use openssl::{OpensslMac, OpensslSigner, OpensslVerifier};
+use ossl::hash::{MessageDigest, hash};
+
+const PAYLOAD: &[u8] = b"This is the content.";
+
+// All test keys with their algorithm and certificate.
+const KEYS: [(SignatureAlgorithm, &[u8], &[u8]); 9] = [
+    (SignatureAlgorithm::ES256, &keys::PKCS8_P256_EE, &keys::P256_EE),
+    (SignatureAlgorithm::ES384, &keys::PKCS8_P384_EE, &keys::P384_EE),
+    (SignatureAlgorithm::ES512, &keys::PKCS8_P521_EE, &keys::P521_EE),
+    (SignatureAlgorithm::PS256, &keys::PKCS8_RSA_EE, &keys::RSA_EE),
+    (SignatureAlgorithm::PS384, &keys::PKCS8_RSA_EE, &keys::RSA_EE),
+    (SignatureAlgorithm::PS512, &keys::PKCS8_RSA_EE, &keys::RSA_EE),
+    (SignatureAlgorithm::RS256, &keys::PKCS8_RSA_EE, &keys::RSA_EE),
+    (SignatureAlgorithm::RS384, &keys::PKCS8_RSA_EE, &keys::RSA_EE),
+    (SignatureAlgorithm::RS512, &keys::PKCS8_RSA_EE, &keys::RSA_EE),
+];
+
+// Sign with the signers `make_signer` creates and verify with `verifier` for all test keys.
+fn sign_verify_all<S, V>(make_signer: S, verifier: &V)
+where
+    S: Fn(&[u8]) -> Box<dyn Signer>,
+    V: Verifier,
+{
+    for &(algorithm, pkcs8, cert) in &KEYS {
+        keys::test_sign_verify(algorithm, cert, make_signer(pkcs8).as_ref(), verifier);
+    }
+}
+
+fn openssl_signer(pkcs8: &[u8]) -> Box<dyn Signer> {
+    Box::new(OpensslSigner::from_pkcs8(pkcs8).unwrap())
+}
+
+#[test]
+fn test_openssl_sign_verify() {
+    sign_verify_all(openssl_signer, &OpensslVerifier);
+}
+
+#[test]
+fn test_openssl_verify_xpi_signature() {
+    let cose_signatures = decode_signature(&test::XPI_SIGNATURE, &test::XPI_PAYLOAD).unwrap();
+    assert_eq!(cose_signatures[0].verify_with(&OpensslVerifier), Ok(()));
+}
+
+#[test]
+fn test_openssl_wrong_key() {
+    let signer = OpensslSigner::from_pkcs8(&keys::PKCS8_P256_EE).unwrap();
+    assert_eq!(signer.sign(SignatureAlgorithm::ES384, PAYLOAD), Err(CoseError::InvalidArgument));
+    assert_eq!(signer.sign(SignatureAlgorithm::PS256, PAYLOAD), Err(CoseError::InvalidArgument));
+    let signature = signer.sign(SignatureAlgorithm::ES256, PAYLOAD).unwrap();
+    assert_eq!(signature.len(), 64);
+    let result =
+        OpensslVerifier.verify(SignatureAlgorithm::ES256, &keys::P384_EE, PAYLOAD, &signature);
+    assert_eq!(result, Err(CoseError::VerificationFailed));
+    let result = OpensslVerifier.verify(SignatureAlgorithm::ES256, &[0x30], PAYLOAD, &signature);
+    assert_eq!(result, Err(CoseError::DecodingFailure));
+    assert_eq!(OpensslSigner::from_pkcs8(&[0x30]).err(), Some(CoseError::DecodingFailure));
+}
+
+#[test]
+fn test_openssl_es256k() {
+    // OpenSSL signatures are randomized and normalized to the low S value.
+    let signer = OpensslSigner::from_pkcs8(&keys::PKCS8_SECP256K1_EE).unwrap();
+    for _ in 0..16 {
+        let signature = signer.sign(SignatureAlgorithm::ES256K, PAYLOAD).unwrap();
+        assert_eq!(signature.len(), 64);
+        assert!(signature[32..] <= keys::SECP256K1_HALF_ORDER[..]);
+        let cert = &keys::SECP256K1_EE;
+        let result = OpensslVerifier.verify(SignatureAlgorithm::ES256K, cert, PAYLOAD, &signature);
+        assert_eq!(result, Ok(()));
+    }
+    assert_eq!(signer.sign(SignatureAlgorithm::ES256, PAYLOAD), Err(CoseError::InvalidArgument));
+
+    // Only the signature with the low S value is accepted.
+    let cose_sign1 = decode_sign1(&keys::ES256K_SIGN1_LOW_S, None).unwrap();
+    let result = cose_sign1.verify_with(&keys::SECP256K1_EE, &OpensslVerifier);
+    assert_eq!(result, Ok(()));
+    let cose_sign1 = decode_sign1(&keys::ES256K_SIGN1_HIGH_S, None).unwrap();
+    let result = cose_sign1.verify_with(&keys::SECP256K1_EE, &OpensslVerifier);
+    assert_eq!(result, Err(CoseError::VerificationFailed));
+}
+
+#[test]
+fn test_openssl_rsa_parameters() {
+    // A signature only verifies with the padding and digest it was made with.
+    let signer = OpensslSigner::from_pkcs8(&keys::PKCS8_RSA_EE).unwrap();
+    let algorithms = [SignatureAlgorithm::PS256,
+                      SignatureAlgorithm::PS384,
+                      SignatureAlgorithm::PS512,
+                      SignatureAlgorithm::RS256,
+                      SignatureAlgorithm::RS384,
+                      SignatureAlgorithm::RS512];
+    for &algorithm in &algorithms {
+        let signature = signer.sign(algorithm, PAYLOAD).unwrap();
+        assert_eq!(signature.len(), 256);
+        for &other in &algorithms {
+            let result = OpensslVerifier.verify(other, &keys::RSA_EE, PAYLOAD, &signature);
+            if other == algorithm {
+                assert_eq!(result, Ok(()));
+            } else {
+                assert_eq!(result, Err(CoseError::VerificationFailed));
+            }
+        }
+    }
+    let signer = OpensslSigner::from_pkcs8(&keys::PKCS8_P256_EE).unwrap();
+    assert_eq!(signer.sign(SignatureAlgorithm::RS256, PAYLOAD), Err(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_openssl_rsa_key_size() {
+    // RSA keys must have at least 2048 bits.
+    let signer = OpensslSigner::from_pkcs8(&keys::PKCS8_RSA1024_EE).unwrap();
+    assert_eq!(signer.sign(SignatureAlgorithm::PS256, PAYLOAD), Err(CoseError::InvalidArgument));
+    assert_eq!(signer.sign(SignatureAlgorithm::RS256, PAYLOAD), Err(CoseError::InvalidArgument));
+    let cose_sign1 = decode_sign1(&keys::RS256_SIGN1_RSA1024, None).unwrap();
+    let result = cose_sign1.verify_with(&keys::RSA1024_EE, &OpensslVerifier);
+    assert_eq!(result, Err(CoseError::VerificationFailed));
+}
+
+#[cfg(feature = "rustcrypto")]
+#[test]
+fn test_openssl_rustcrypto_interop() {
+    use rustcrypto::{RustCryptoSigner, RustCryptoVerifier};
+    sign_verify_all(openssl_signer, &RustCryptoVerifier);
+    let rustcrypto_signer = |pkcs8: &[u8]| -> Box<dyn Signer> {
+        Box::new(RustCryptoSigner::from_pkcs8(pkcs8).unwrap())
+    };
+    sign_verify_all(rustcrypto_signer, &OpensslVerifier);
+
+    // Ed25519 signatures are deterministic, so both backends produce the same signature.
+    let openssl_signature = OpensslSigner::from_pkcs8(&keys::PKCS8_ED25519_EE)
+        .unwrap()
+        .sign(SignatureAlgorithm::Ed25519, PAYLOAD)
+        .unwrap();
+    let rustcrypto_signature = RustCryptoSigner::from_pkcs8(&keys::PKCS8_ED25519_EE)
+        .unwrap()
+        .sign(SignatureAlgorithm::Ed25519, PAYLOAD)
+        .unwrap();
+    assert_eq!(openssl_signature, rustcrypto_signature);
+
+    // ES256K signatures are accepted by the other backend.
+    let openssl_signer = OpensslSigner::from_pkcs8(&keys::PKCS8_SECP256K1_EE).unwrap();
+    let rustcrypto_signer = RustCryptoSigner::from_pkcs8(&keys::PKCS8_SECP256K1_EE).unwrap();
+    let cert = &keys::SECP256K1_EE;
+    let signature = openssl_signer.sign(SignatureAlgorithm::ES256K, PAYLOAD).unwrap();
+    let result = RustCryptoVerifier.verify(SignatureAlgorithm::ES256K, cert, PAYLOAD, &signature);
+    assert_eq!(result, Ok(()));
+    let signature = rustcrypto_signer.sign(SignatureAlgorithm::ES256K, PAYLOAD).unwrap();
+    let result = OpensslVerifier.verify(SignatureAlgorithm::ES256K, cert, PAYLOAD, &signature);
+    assert_eq!(result, Ok(()));
+
+    // So are RSASSA-PKCS1-v1_5 signatures.
+    let algorithms = [SignatureAlgorithm::RS256,
+                      SignatureAlgorithm::RS384,
+                      SignatureAlgorithm::RS512];
+    for &algorithm in &algorithms {
+        let openssl_signature = OpensslSigner::from_pkcs8(&keys::PKCS8_RSA_EE)
+            .unwrap()
+            .sign(algorithm, PAYLOAD)
+            .unwrap();
+        let rustcrypto_signature = RustCryptoSigner::from_pkcs8(&keys::PKCS8_RSA_EE)
+            .unwrap()
+            .sign(algorithm, PAYLOAD)
+            .unwrap();
+        assert_eq!(openssl_signature, rustcrypto_signature);
+    }
+}
+
+#[cfg(feature = "nss")]
+#[test]
+fn test_openssl_nss_interop() {
+    use nss::{NssSigner, NssVerifier};
+    use test_nss::setup;
+    setup();
+    sign_verify_all(openssl_signer, &NssVerifier);
+    let nss_signer = |pkcs8: &[u8]| -> Box<dyn Signer> {
+        Box::new(NssSigner::from_pkcs8(pkcs8).unwrap())
+    };
+    sign_verify_all(nss_signer, &OpensslVerifier);
+}
+
+#[test]
+fn test_openssl_eddsa() {
+    let keys = [(SignatureAlgorithm::EdDSA, &keys::PKCS8_ED25519_EE[..], &keys::ED25519_EE[..]),
+                (SignatureAlgorithm::EdDSA, &keys::PKCS8_ED448_EE, &keys::ED448_EE),
+                (SignatureAlgorithm::Ed25519, &keys::PKCS8_ED25519_EE, &keys::ED25519_EE),
+                (SignatureAlgorithm::Ed448, &keys::PKCS8_ED448_EE, &keys::ED448_EE)];
+    for &(algorithm, pkcs8, cert) in &keys {
+        let signer = OpensslSigner::from_pkcs8(pkcs8).unwrap();
+        let signature = signer.sign(algorithm, PAYLOAD).unwrap();
+        assert_eq!(OpensslVerifier.verify(algorithm, cert, PAYLOAD, &signature), Ok(()));
+        let result = OpensslVerifier.verify(algorithm, cert, b"payload", &signature);
+        assert_eq!(result, Err(CoseError::VerificationFailed));
+    }
+
+    // The fully specified algorithms only accept keys on their curve.
+    let signer = OpensslSigner::from_pkcs8(&keys::PKCS8_ED448_EE).unwrap();
+    assert_eq!(signer.sign(SignatureAlgorithm::Ed25519, PAYLOAD), Err(CoseError::InvalidArgument));
+    let signature = signer.sign(SignatureAlgorithm::Ed448, PAYLOAD).unwrap();
+    let result =
+        OpensslVerifier.verify(SignatureAlgorithm::Ed25519, &keys::ED448_EE, PAYLOAD, &signature);
+    assert_eq!(result, Err(CoseError::VerificationFailed));
+    let signer = OpensslSigner::from_pkcs8(&keys::PKCS8_P256_EE).unwrap();
+    assert_eq!(signer.sign(SignatureAlgorithm::EdDSA, PAYLOAD), Err(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_openssl_key_thumbprint() {
+    keys::test_key_thumbprint(|input| hash(MessageDigest::sha256(), input).unwrap().to_vec());
+}
+
+#[test]
+fn test_openssl_validate_chain() {
+    keys::test_validate_chain(&OpensslVerifier);
+}
+
+#[test]
+fn test_openssl_mac() {
+    keys::test_mac(|k| Box::new(OpensslMac::new(k).unwrap()));
+}
@@ -0,0 +1,136 @@
+use test_keys as keys;
+use test_setup as test;
+use {CoseError, SignatureAlgorithm, Signer, Verifier};
+use decoder::{decode_sign1, decode_signature};
+use rustcrypto::{RustCryptoMac, RustCryptoSigner, RustCryptoVerifier};
+use sha2::{Digest, Sha256};
+
+const PAYLOAD: &[u8] = b"This is the content.";
+
+fn sign_verify(algorithm: SignatureAlgorithm, pkcs8: &[u8], cert: &[u8]) {
+    let signer = RustCryptoSigner::from_pkcs8(pkcs8).unwrap();
+    keys::test_sign_verify(algorithm, cert, &signer, &RustCryptoVerifier);
+}
+
+#[test]
+fn test_rustcrypto_es256() {
+    sign_verify(SignatureAlgorithm::ES256, &keys::PKCS8_P256_EE, &keys::P256_EE);
+}
+
+#[test]
+fn test_rustcrypto_es384() {
+    sign_verify(SignatureAlgorithm::ES384, &keys::PKCS8_P384_EE, &keys::P384_EE);
+}
+
+#[test]
+fn test_rustcrypto_es512() {
+    sign_verify(SignatureAlgorithm::ES512, &keys::PKCS8_P521_EE, &keys::P521_EE);
+}
+
+#[test]
+fn test_rustcrypto_es256k() {
+    sign_verify(SignatureAlgorithm::ES256K, &keys::PKCS8_SECP256K1_EE, &keys::SECP256K1_EE);
+
+    // Signatures are produced with a low S value.
+    let signer = RustCryptoSigner::from_pkcs8(&keys::PKCS8_SECP256K1_EE).unwrap();
+    let signature = signer.sign(SignatureAlgorithm::ES256K, PAYLOAD).unwrap();
+    assert!(signature[32..] <= keys::SECP256K1_HALF_ORDER[..]);
+
+    // Only the signature with the low S value is accepted.
+    let cose_sign1 = decode_sign1(&keys::ES256K_SIGN1_LOW_S, None).unwrap();
+    let result = cose_sign1.verify_with(&keys::SECP256K1_EE, &RustCryptoVerifier);
+    assert_eq!(result, Ok(()));
+    let cose_sign1 = decode_sign1(&keys::ES256K_SIGN1_HIGH_S, None).unwrap();
+    let result = cose_sign1.verify_with(&keys::SECP256K1_EE, &RustCryptoVerifier);
+    assert_eq!(result, Err(CoseError::VerificationFailed));
+}
+
+#[test]
+fn test_rustcrypto_ps256() {
+    sign_verify(SignatureAlgorithm::PS256, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+}
+
+#[test]
+fn test_rustcrypto_ps384_ps512() {
+    sign_verify(SignatureAlgorithm::PS384, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+    sign_verify(SignatureAlgorithm::PS512, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+}
+
+#[test]
+fn test_rustcrypto_rs256_rs384_rs512() {
+    sign_verify(SignatureAlgorithm::RS256, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+    sign_verify(SignatureAlgorithm::RS384, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+    sign_verify(SignatureAlgorithm::RS512, &keys::PKCS8_RSA_EE, &keys::RSA_EE);
+}
+
+#[test]
+fn test_rustcrypto_verify_xpi_signature() {
+    let cose_signatures = decode_signature(&test::XPI_SIGNATURE, &test::XPI_PAYLOAD).unwrap();
+    assert_eq!(cose_signatures[0].verify_with(&RustCryptoVerifier), Ok(()));
+}
+
+#[test]
+fn test_rustcrypto_verify_wrong_cert() {
+    let signer = RustCryptoSigner::from_pkcs8(&keys::PKCS8_P256_EE).unwrap();
+    let signature = signer.sign(SignatureAlgorithm::ES256, PAYLOAD).unwrap();
+    let result = RustCryptoVerifier.verify(
+        SignatureAlgorithm::ES256,
+        &keys::P384_EE,
+        PAYLOAD,
+        &signature,
+    );
+    assert_eq!(result, Err(CoseError::VerificationFailed));
+    let result = RustCryptoVerifier.verify(SignatureAlgorithm::ES256, &[0x30], PAYLOAD, &signature);
+    assert_eq!(result, Err(CoseError::DecodingFailure));
+}
+
+#[test]
+fn test_rustcrypto_sign_wrong_algorithm() {
+    let signer = RustCryptoSigner::from_pkcs8(&keys::PKCS8_P256_EE).unwrap();
+    let result = signer.sign(SignatureAlgorithm::ES384, PAYLOAD);
+    assert_eq!(result, Err(CoseError::InvalidArgument));
+    let result = RustCryptoSigner::from_pkcs8(&[0x30]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_rustcrypto_eddsa() {
+    sign_verify(SignatureAlgorithm::EdDSA, &keys::PKCS8_ED25519_EE, &keys::ED25519_EE);
+    sign_verify(SignatureAlgorithm::Ed25519, &keys::PKCS8_ED25519_EE, &keys::ED25519_EE);
+
+    // Ed448 is not supported.
+    let signer = RustCryptoSigner::from_pkcs8(&keys::PKCS8_ED25519_EE).unwrap();
+    let result = signer.sign(SignatureAlgorithm::Ed448, PAYLOAD);
+    assert_eq!(result, Err(CoseError::UnknownSignatureScheme));
+    let result =
+        RustCryptoVerifier.verify(SignatureAlgorithm::Ed448, &keys::ED448_EE, PAYLOAD, &[]);
+    assert_eq!(result, Err(CoseError::UnknownSignatureScheme));
+    let result = RustCryptoSigner::from_pkcs8(&keys::PKCS8_ED448_EE);
+    assert_eq!(result.err(), Some(CoseError::DecodingFailure));
+}
+
+#[test]
+fn test_rustcrypto_rsa_key_size() {
+    // RSA keys must have at least 2048 bits.
+    let signer = RustCryptoSigner::from_pkcs8(&keys::PKCS8_RSA1024_EE).unwrap();
+    assert_eq!(signer.sign(SignatureAlgorithm::PS256, PAYLOAD), Err(CoseError::InvalidArgument));
+    assert_eq!(signer.sign(SignatureAlgorithm::RS256, PAYLOAD), Err(CoseError::InvalidArgument));
+    let cose_sign1 = decode_sign1(&keys::RS256_SIGN1_RSA1024, None).unwrap();
+    let result = cose_sign1.verify_with(&keys::RSA1024_EE, &RustCryptoVerifier);
+    assert_eq!(result, Err(CoseError::VerificationFailed));
+}
+
+#[test]
+fn test_rustcrypto_key_thumbprint() {
+    keys::test_key_thumbprint(|input| Sha256::digest(input).to_vec());
+}
+
+#[test]
+fn test_rustcrypto_validate_chain() {
+    keys::test_validate_chain(&RustCryptoVerifier);
+}
+
+#[test]
+fn test_rustcrypto_mac() {
+    keys::test_mac(|k| Box::new(RustCryptoMac::new(k)));
+}
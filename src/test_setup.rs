@@ -1,4 +1,4 @@
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 pub const P256_INT: [u8; 332] = [
     0x30, 0x82, 0x01, 0x48, 0x30, 0x81, 0xf0, 0xa0, 0x03, 0x02, 0x01,
     0x02, 0x02, 0x14, 0x43, 0x63, 0x59, 0xad, 0x04, 0x34, 0x56, 0x80,
@@ -33,7 +33,7 @@ pub const P256_INT: [u8; 332] = [
     0x30, 0xa7
 ];
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 pub const P256_ROOT: [u8; 334] = [
     0x30, 0x82, 0x01, 0x4a, 0x30, 0x81, 0xf1, 0xa0, 0x03, 0x02, 0x01, 0x02,
     0x02, 0x14, 0x5f, 0x3f, 0xae, 0x90, 0x49, 0x30, 0x2f, 0x33, 0x6e, 0x95,
@@ -65,7 +65,7 @@ pub const P256_ROOT: [u8; 334] = [
     0x54, 0xc8, 0x9f, 0xef, 0xb8, 0x5d, 0xa2, 0x40, 0xd9, 0x8b
 ];
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 pub const COSE_SIGNATURE_BYTES: [u8; 1062] = [
     0xd8, 0x62, 0x84, 0x59, 0x02, 0xa3, 0xa1, 0x04, 0x82, 0x59, 0x01, 0x4e,
     0x30, 0x82, 0x01, 0x4a, 0x30, 0x81, 0xf1, 0xa0, 0x03, 0x02, 0x01, 0x02,
@@ -158,7 +158,7 @@ pub const COSE_SIGNATURE_BYTES: [u8; 1062] = [
     0x89, 0x74, 0x4d, 0x9e, 0xb4, 0xd7
 ];
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 pub const SIGNATURE_BYTES: [u8; 64] = [
     0x11, 0xe5, 0x73, 0x2c, 0x23, 0x31, 0x6f, 0xb4, 0x17, 0xcf,
     0xa9, 0xee, 0xc5, 0xe3, 0x57, 0xcc, 0x77, 0x82, 0x29, 0x1f, 0xba, 0x97,
@@ -167,3 +167,588 @@ pub const SIGNATURE_BYTES: [u8; 64] = [
     0x50, 0x5f, 0xd8, 0x72, 0xb0, 0x41, 0xb9, 0x39, 0x3d, 0xdf, 0x44, 0xee,
     0x89, 0x74, 0x4d, 0x9e, 0xb4, 0xd7
 ];
+
+// COSE_Sign1 example C.2.1 from RFC 8152 (ES256, kid "11").
+#[rustfmt::skip]
+pub const COSE_SIGN1_BYTES: [u8; 98] = [
+    0xd2, 0x84, 0x43, 0xa1, 0x01, 0x26, 0xa1, 0x04, 0x42, 0x31, 0x31, 0x54,
+    0x54, 0x68, 0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x2e, 0x58, 0x40, 0x8e, 0xb3,
+    0x3e, 0x4c, 0xa3, 0x1d, 0x1c, 0x46, 0x5a, 0xb0, 0x5a, 0xac, 0x34, 0xcc,
+    0x6b, 0x23, 0xd5, 0x8f, 0xef, 0x5c, 0x08, 0x31, 0x06, 0xc4, 0xd2, 0x5a,
+    0x91, 0xae, 0xf0, 0xb0, 0x11, 0x7e, 0x2a, 0xf9, 0xa2, 0x91, 0xaa, 0x32,
+    0xe1, 0x4a, 0xb8, 0x34, 0xdc, 0x56, 0xed, 0x2a, 0x22, 0x34, 0x44, 0x54,
+    0x7e, 0x01, 0xf1, 0x1d, 0x3b, 0x09, 0x16, 0xe5, 0xa4, 0xc3, 0x45, 0xca,
+    0xcb, 0x36
+];
+
+#[rustfmt::skip]
+pub const COSE_SIGN1_TO_VERIFY: [u8; 38] = [
+    0x84, 0x6a, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x31,
+    0x43, 0xa1, 0x01, 0x26, 0x40, 0x54, 0x54, 0x68, 0x69, 0x73, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
+    0x74, 0x2e
+];
+
+#[rustfmt::skip]
+pub const SIGN1_SIGNATURE_BYTES: [u8; 64] = [
+    0x8e, 0xb3, 0x3e, 0x4c, 0xa3, 0x1d, 0x1c, 0x46, 0x5a, 0xb0, 0x5a, 0xac,
+    0x34, 0xcc, 0x6b, 0x23, 0xd5, 0x8f, 0xef, 0x5c, 0x08, 0x31, 0x06, 0xc4,
+    0xd2, 0x5a, 0x91, 0xae, 0xf0, 0xb0, 0x11, 0x7e, 0x2a, 0xf9, 0xa2, 0x91,
+    0xaa, 0x32, 0xe1, 0x4a, 0xb8, 0x34, 0xdc, 0x56, 0xed, 0x2a, 0x22, 0x34,
+    0x44, 0x54, 0x7e, 0x01, 0xf1, 0x1d, 0x3b, 0x09, 0x16, 0xe5, 0xa4, 0xc3,
+    0x45, 0xca, 0xcb, 0x36
+];
+
+// A signature on a Firefox add-on, created with sign_app.py from mozilla-central.
+#[rustfmt::skip]
+pub const XPI_SIGNATURE: [u8; 646] = [
+    0xd8, 0x62, 0x84, 0x43, 0xa1, 0x04, 0x80, 0xa0, 0xf6, 0x81, 0x83, 0x59,
+    0x02, 0x35, 0xa2, 0x01, 0x26, 0x04, 0x59, 0x02, 0x2e, 0x30, 0x82, 0x02,
+    0x2a, 0x30, 0x82, 0x01, 0x12, 0xa0, 0x03, 0x02, 0x01, 0x02, 0x02, 0x14,
+    0x17, 0x03, 0x6b, 0xc1, 0xfe, 0xb4, 0x38, 0xe1, 0x83, 0x8f, 0xe5, 0xa7,
+    0xca, 0xf1, 0x54, 0x32, 0x4c, 0x8b, 0xf3, 0x05, 0x30, 0x0d, 0x06, 0x09,
+    0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b, 0x05, 0x00, 0x30,
+    0x29, 0x31, 0x27, 0x30, 0x25, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x1e,
+    0x78, 0x70, 0x63, 0x73, 0x68, 0x65, 0x6c, 0x6c, 0x20, 0x73, 0x69, 0x67,
+    0x6e, 0x65, 0x64, 0x20, 0x61, 0x70, 0x70, 0x73, 0x20, 0x74, 0x65, 0x73,
+    0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x30, 0x22, 0x18, 0x0f, 0x32, 0x30,
+    0x31, 0x35, 0x31, 0x31, 0x32, 0x38, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x5a, 0x18, 0x0f, 0x32, 0x30, 0x31, 0x38, 0x30, 0x32, 0x30, 0x35, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x2b, 0x31, 0x29, 0x30, 0x27,
+    0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x20, 0x20, 0x78, 0x70, 0x63, 0x73,
+    0x68, 0x65, 0x6c, 0x6c, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20,
+    0x61, 0x70, 0x70, 0x20, 0x74, 0x65, 0x73, 0x74, 0x20, 0x73, 0x69, 0x67,
+    0x6e, 0x65, 0x72, 0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03,
+    0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0x4f, 0xbf, 0xbb, 0xbb, 0x61, 0xe0,
+    0xf8, 0xf9, 0xb1, 0xa6, 0x0a, 0x59, 0xac, 0x87, 0x04, 0xe2, 0xec, 0x05,
+    0x0b, 0x42, 0x3e, 0x3c, 0xf7, 0x2e, 0x92, 0x3f, 0x2c, 0x4f, 0x79, 0x4b,
+    0x45, 0x5c, 0x2a, 0x69, 0xd2, 0x33, 0x45, 0x6c, 0x36, 0xc4, 0x11, 0x9d,
+    0x07, 0x06, 0xe0, 0x0e, 0xed, 0xc8, 0xd1, 0x93, 0x90, 0xd7, 0x99, 0x1b,
+    0x7b, 0x2d, 0x07, 0xa3, 0x04, 0xea, 0xa0, 0x4a, 0xa6, 0xc0, 0xa3, 0x0f,
+    0x30, 0x0d, 0x30, 0x0b, 0x06, 0x03, 0x55, 0x1d, 0x0f, 0x04, 0x04, 0x03,
+    0x02, 0x07, 0x80, 0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7,
+    0x0d, 0x01, 0x01, 0x0b, 0x05, 0x00, 0x03, 0x82, 0x01, 0x01, 0x00, 0x4f,
+    0x5c, 0xcb, 0x1d, 0xea, 0x71, 0x58, 0xfe, 0xe2, 0x49, 0x11, 0x16, 0x65,
+    0xbc, 0x23, 0x6d, 0xda, 0x46, 0x7e, 0x98, 0x93, 0x5d, 0x48, 0x2a, 0xa0,
+    0xbb, 0x7f, 0x4e, 0xbd, 0x01, 0x0a, 0x1a, 0x30, 0xff, 0xce, 0x03, 0xf5,
+    0x9c, 0xd9, 0x84, 0x69, 0x7a, 0x5a, 0xe3, 0x43, 0xd2, 0xd4, 0xbc, 0xab,
+    0x4d, 0x17, 0x8f, 0x10, 0x6a, 0xcf, 0xde, 0x17, 0x1d, 0x7d, 0x16, 0x03,
+    0x7e, 0x21, 0xf0, 0x32, 0x02, 0x89, 0x67, 0x32, 0x5a, 0xfe, 0xd5, 0xd9,
+    0x31, 0x53, 0xdc, 0xd7, 0xba, 0x2a, 0x9f, 0xd3, 0x59, 0x8d, 0x61, 0xb9,
+    0x6e, 0xf7, 0x6e, 0x86, 0x61, 0xdd, 0xfd, 0xe1, 0x73, 0xfe, 0xef, 0x9d,
+    0xe9, 0x99, 0x9e, 0x51, 0xe8, 0x5d, 0xf7, 0x48, 0x77, 0x8e, 0xc6, 0xe8,
+    0x53, 0x05, 0x7b, 0x5c, 0x2c, 0x28, 0xe7, 0x0a, 0x07, 0xbf, 0xea, 0xc1,
+    0x06, 0x11, 0x0d, 0xe7, 0x60, 0xd0, 0x79, 0x94, 0xe9, 0x26, 0xf1, 0x93,
+    0x71, 0x7b, 0x5b, 0x02, 0x3b, 0x5d, 0x51, 0xb8, 0x19, 0x38, 0x16, 0xab,
+    0x48, 0x30, 0xf3, 0xec, 0xd9, 0xd5, 0x8f, 0xc7, 0x9a, 0x02, 0xfd, 0x12,
+    0x57, 0x82, 0x0e, 0xde, 0xce, 0xfc, 0x50, 0x42, 0x2a, 0x41, 0xc7, 0xc6,
+    0xa8, 0x80, 0x37, 0x7c, 0xc4, 0x47, 0xad, 0xf5, 0xd8, 0xcb, 0xe8, 0xae,
+    0x0c, 0x01, 0x80, 0x60, 0x35, 0x93, 0x0a, 0x21, 0x81, 0x33, 0xd1, 0xd6,
+    0x6a, 0x1b, 0xe7, 0xb6, 0xd9, 0x91, 0x50, 0xc2, 0xbd, 0x16, 0xda, 0xb7,
+    0x68, 0x60, 0xf2, 0x20, 0xaa, 0x72, 0x8c, 0x76, 0x0a, 0x54, 0x7a, 0x05,
+    0xd8, 0xa1, 0xcd, 0xe9, 0x07, 0x8a, 0x02, 0x07, 0x4b, 0x87, 0x7d, 0xb5,
+    0x27, 0xca, 0x38, 0xb3, 0x30, 0xaf, 0x97, 0xe0, 0xb7, 0x35, 0x14, 0x08,
+    0xab, 0x01, 0xb0, 0x14, 0x08, 0x5c, 0x4b, 0xfb, 0x76, 0x0a, 0x95, 0xfc,
+    0xb4, 0xb8, 0x34, 0xa0, 0x58, 0x40, 0x5c, 0x75, 0x51, 0x9f, 0x13, 0x11,
+    0x50, 0xcd, 0x5d, 0x8a, 0xde, 0x20, 0xa3, 0xbc, 0x06, 0x30, 0x91, 0xff,
+    0xb2, 0x73, 0x75, 0x5f, 0x31, 0x64, 0xec, 0xfd, 0xcb, 0x42, 0x80, 0x0a,
+    0x70, 0xe6, 0x82, 0x02, 0x0a, 0xe8, 0x69, 0x13, 0xd5, 0xf4, 0x1b, 0xab,
+    0xb6, 0xbb, 0x59, 0x93, 0x08, 0x48, 0x68, 0x9c, 0xbd, 0x72, 0xc7, 0xcb,
+    0x37, 0xde, 0x26, 0xbc, 0xe9, 0x83, 0x0e, 0xd8, 0x90, 0xa3
+];
+
+#[rustfmt::skip]
+pub const XPI_PAYLOAD: [u8; 236] = [
+    0x4E, 0x61, 0x6D, 0x65, 0x3A, 0x20, 0x6D, 0x61, 0x6E, 0x69, 0x66, 0x65,
+    0x73, 0x74, 0x2E, 0x6A, 0x73, 0x6F, 0x6E, 0x0A, 0x53, 0x48, 0x41, 0x32,
+    0x35, 0x36, 0x2D, 0x44, 0x69, 0x67, 0x65, 0x73, 0x74, 0x3A, 0x20, 0x42,
+    0x54, 0x6E, 0x43, 0x70, 0x54, 0x31, 0x35, 0x34, 0x4E, 0x32, 0x36, 0x52,
+    0x5A, 0x6D, 0x38, 0x62, 0x68, 0x64, 0x44, 0x34, 0x33, 0x57, 0x58, 0x64,
+    0x30, 0x74, 0x6A, 0x35, 0x62, 0x67, 0x36, 0x6F, 0x66, 0x4D, 0x31, 0x39,
+    0x4E, 0x4C, 0x49, 0x30, 0x4F, 0x45, 0x3D, 0x0A, 0x0A, 0x4E, 0x61, 0x6D,
+    0x65, 0x3A, 0x20, 0x52, 0x45, 0x41, 0x44, 0x4D, 0x45, 0x0A, 0x53, 0x48,
+    0x41, 0x32, 0x35, 0x36, 0x2D, 0x44, 0x69, 0x67, 0x65, 0x73, 0x74, 0x3A,
+    0x20, 0x62, 0x59, 0x30, 0x6C, 0x39, 0x78, 0x71, 0x47, 0x4A, 0x59, 0x43,
+    0x70, 0x71, 0x59, 0x65, 0x4A, 0x30, 0x4B, 0x36, 0x71, 0x34, 0x44, 0x57,
+    0x55, 0x51, 0x71, 0x75, 0x30, 0x6D, 0x4E, 0x42, 0x46, 0x4D, 0x34, 0x48,
+    0x34, 0x65, 0x6D, 0x68, 0x6A, 0x69, 0x4A, 0x67, 0x3D, 0x0A, 0x0A, 0x4E,
+    0x61, 0x6D, 0x65, 0x3A, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2F, 0x69, 0x6D,
+    0x61, 0x67, 0x65, 0x2E, 0x70, 0x6E, 0x67, 0x0A, 0x53, 0x48, 0x41, 0x32,
+    0x35, 0x36, 0x2D, 0x44, 0x69, 0x67, 0x65, 0x73, 0x74, 0x3A, 0x20, 0x45,
+    0x50, 0x6A, 0x6B, 0x4E, 0x5A, 0x77, 0x79, 0x61, 0x39, 0x58, 0x2B, 0x70,
+    0x72, 0x75, 0x4C, 0x6C, 0x78, 0x47, 0x2B, 0x46, 0x41, 0x43, 0x4C, 0x77,
+    0x47, 0x43, 0x34, 0x38, 0x58, 0x55, 0x34, 0x53, 0x39, 0x6F, 0x5A, 0x4F,
+    0x41, 0x30, 0x6C, 0x56, 0x56, 0x51, 0x3D, 0x0A
+];
+
+// The public and private P-256 key of example C.7 from RFC 9052, kid
+// "meriadoc.brandybuck@buckland.example".
+#[rustfmt::skip]
+pub const COSE_KEY_EC2_P256: [u8; 149] = [
+    0xa6, 0x01, 0x02, 0x02, 0x58, 0x24, 0x6d, 0x65, 0x72, 0x69, 0x61, 0x64,
+    0x6f, 0x63, 0x2e, 0x62, 0x72, 0x61, 0x6e, 0x64, 0x79, 0x62, 0x75, 0x63,
+    0x6b, 0x40, 0x62, 0x75, 0x63, 0x6b, 0x6c, 0x61, 0x6e, 0x64, 0x2e, 0x65,
+    0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x20, 0x01, 0x21, 0x58, 0x20, 0x65,
+    0xed, 0xa5, 0xa1, 0x25, 0x77, 0xc2, 0xba, 0xe8, 0x29, 0x43, 0x7f, 0xe3,
+    0x38, 0x70, 0x1a, 0x10, 0xaa, 0xa3, 0x75, 0xe1, 0xbb, 0x5b, 0x5d, 0xe1,
+    0x08, 0xde, 0x43, 0x9c, 0x08, 0x55, 0x1d, 0x22, 0x58, 0x20, 0x1e, 0x52,
+    0xed, 0x75, 0x70, 0x11, 0x63, 0xf7, 0xf9, 0xe4, 0x0d, 0xdf, 0x9f, 0x34,
+    0x1b, 0x3d, 0xc9, 0xba, 0x86, 0x0a, 0xf7, 0xe0, 0xca, 0x7c, 0xa7, 0xe9,
+    0xee, 0xcd, 0x00, 0x84, 0xd1, 0x9c, 0x23, 0x58, 0x20, 0xaf, 0xf9, 0x07,
+    0xc9, 0x9f, 0x9a, 0xd3, 0xaa, 0xe6, 0xc4, 0xcd, 0xf2, 0x11, 0x22, 0xbc,
+    0xe2, 0xbd, 0x68, 0xb5, 0x28, 0x3e, 0x69, 0x07, 0x15, 0x4a, 0xd9, 0x11,
+    0x84, 0x0f, 0xa2, 0x08, 0xcf
+];
+
+// The symmetric key of example C.7.2 from RFC 9052, kid "our-secret".
+#[rustfmt::skip]
+pub const COSE_KEY_SYMMETRIC: [u8; 50] = [
+    0xa3, 0x01, 0x04, 0x02, 0x4a, 0x6f, 0x75, 0x72, 0x2d, 0x73, 0x65, 0x63,
+    0x72, 0x65, 0x74, 0x20, 0x58, 0x20, 0x84, 0x9b, 0x57, 0x21, 0x9d, 0xae,
+    0x48, 0xde, 0x64, 0x6d, 0x07, 0xdb, 0xb5, 0x33, 0x56, 0x6e, 0x97, 0x66,
+    0x86, 0x45, 0x7c, 0x14, 0x91, 0xbe, 0x3a, 0x76, 0xdc, 0xea, 0x6c, 0x42,
+    0x71, 0x88
+];
+
+// The Ed25519 public key of RFC 8037 appendix A.2 with alg EdDSA and key_ops sign and verify.
+#[rustfmt::skip]
+pub const COSE_KEY_OKP_ED25519: [u8; 46] = [
+    0xa5, 0x01, 0x01, 0x03, 0x27, 0x04, 0x82, 0x01, 0x02, 0x20, 0x06, 0x21,
+    0x58, 0x20, 0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b,
+    0xfe, 0xd3, 0xc9, 0x64, 0x07, 0x3a, 0x0e, 0xe1, 0x72, 0xf3, 0xda, 0xa6,
+    0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68, 0xf7, 0x07, 0x51, 0x1a
+];
+
+// A key set with the three keys above.
+#[rustfmt::skip]
+pub const COSE_KEY_SET: [u8; 246] = [
+    0x83, 0xa6, 0x01, 0x02, 0x02, 0x58, 0x24, 0x6d, 0x65, 0x72, 0x69, 0x61,
+    0x64, 0x6f, 0x63, 0x2e, 0x62, 0x72, 0x61, 0x6e, 0x64, 0x79, 0x62, 0x75,
+    0x63, 0x6b, 0x40, 0x62, 0x75, 0x63, 0x6b, 0x6c, 0x61, 0x6e, 0x64, 0x2e,
+    0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x20, 0x01, 0x21, 0x58, 0x20,
+    0x65, 0xed, 0xa5, 0xa1, 0x25, 0x77, 0xc2, 0xba, 0xe8, 0x29, 0x43, 0x7f,
+    0xe3, 0x38, 0x70, 0x1a, 0x10, 0xaa, 0xa3, 0x75, 0xe1, 0xbb, 0x5b, 0x5d,
+    0xe1, 0x08, 0xde, 0x43, 0x9c, 0x08, 0x55, 0x1d, 0x22, 0x58, 0x20, 0x1e,
+    0x52, 0xed, 0x75, 0x70, 0x11, 0x63, 0xf7, 0xf9, 0xe4, 0x0d, 0xdf, 0x9f,
+    0x34, 0x1b, 0x3d, 0xc9, 0xba, 0x86, 0x0a, 0xf7, 0xe0, 0xca, 0x7c, 0xa7,
+    0xe9, 0xee, 0xcd, 0x00, 0x84, 0xd1, 0x9c, 0x23, 0x58, 0x20, 0xaf, 0xf9,
+    0x07, 0xc9, 0x9f, 0x9a, 0xd3, 0xaa, 0xe6, 0xc4, 0xcd, 0xf2, 0x11, 0x22,
+    0xbc, 0xe2, 0xbd, 0x68, 0xb5, 0x28, 0x3e, 0x69, 0x07, 0x15, 0x4a, 0xd9,
+    0x11, 0x84, 0x0f, 0xa2, 0x08, 0xcf, 0xa3, 0x01, 0x04, 0x02, 0x4a, 0x6f,
+    0x75, 0x72, 0x2d, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x20, 0x58, 0x20,
+    0x84, 0x9b, 0x57, 0x21, 0x9d, 0xae, 0x48, 0xde, 0x64, 0x6d, 0x07, 0xdb,
+    0xb5, 0x33, 0x56, 0x6e, 0x97, 0x66, 0x86, 0x45, 0x7c, 0x14, 0x91, 0xbe,
+    0x3a, 0x76, 0xdc, 0xea, 0x6c, 0x42, 0x71, 0x88, 0xa5, 0x01, 0x01, 0x03,
+    0x27, 0x04, 0x82, 0x01, 0x02, 0x20, 0x06, 0x21, 0x58, 0x20, 0xd7, 0x5a,
+    0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9, 0x64,
+    0x07, 0x3a, 0x0e, 0xe1, 0x72, 0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02,
+    0x1a, 0x68, 0xf7, 0x07, 0x51, 0x1a
+];
+
+// The thumbprint input of the P-256 key of example C.7 from RFC 9052, see RFC 9679 section 6.
+#[rustfmt::skip]
+pub const COSE_KEY_EC2_P256_THUMBPRINT_INPUT: [u8; 75] = [
+    0xa4, 0x01, 0x02, 0x20, 0x01, 0x21, 0x58, 0x20, 0x65, 0xed, 0xa5, 0xa1,
+    0x25, 0x77, 0xc2, 0xba, 0xe8, 0x29, 0x43, 0x7f, 0xe3, 0x38, 0x70, 0x1a,
+    0x10, 0xaa, 0xa3, 0x75, 0xe1, 0xbb, 0x5b, 0x5d, 0xe1, 0x08, 0xde, 0x43,
+    0x9c, 0x08, 0x55, 0x1d, 0x22, 0x58, 0x20, 0x1e, 0x52, 0xed, 0x75, 0x70,
+    0x11, 0x63, 0xf7, 0xf9, 0xe4, 0x0d, 0xdf, 0x9f, 0x34, 0x1b, 0x3d, 0xc9,
+    0xba, 0x86, 0x0a, 0xf7, 0xe0, 0xca, 0x7c, 0xa7, 0xe9, 0xee, 0xcd, 0x00,
+    0x84, 0xd1, 0x9c
+];
+
+// A P-256 certificate hierarchy for X.509 path validation. All certificates are signed with
+// ES256. The root is valid from 2024 to 2050.
+#[rustfmt::skip]
+pub const X509_ROOT: [u8; 318] = [
+    0x30, 0x82, 0x01, 0x3a, 0x30, 0x81, 0xe2, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x01, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x14, 0x31, 0x12, 0x30, 0x10, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x09, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x72, 0x6f, 0x6f,
+    0x74, 0x30, 0x20, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x18, 0x0f, 0x32, 0x30, 0x35, 0x30,
+    0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30,
+    0x14, 0x31, 0x12, 0x30, 0x10, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x09,
+    0x63, 0x6f, 0x73, 0x65, 0x2d, 0x72, 0x6f, 0x6f, 0x74, 0x30, 0x59, 0x30,
+    0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08,
+    0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04,
+    0x60, 0xb2, 0x02, 0x18, 0x2a, 0xd1, 0x62, 0x12, 0xd7, 0xe8, 0x7e, 0x01,
+    0x85, 0x9b, 0x93, 0x12, 0xab, 0xd4, 0x63, 0xf1, 0xa1, 0x93, 0xb8, 0x49,
+    0xab, 0xff, 0xae, 0x89, 0x93, 0x99, 0xe5, 0x35, 0x11, 0x31, 0xc3, 0x38,
+    0x0d, 0xde, 0x24, 0x1c, 0x00, 0x71, 0x84, 0x9e, 0x0c, 0xab, 0x66, 0x60,
+    0xe1, 0x0e, 0x73, 0xca, 0x0e, 0xd9, 0xa3, 0x79, 0x15, 0xbf, 0x52, 0x70,
+    0xce, 0x3e, 0xe8, 0xbd, 0xa3, 0x23, 0x30, 0x21, 0x30, 0x0f, 0x06, 0x03,
+    0x55, 0x1d, 0x13, 0x01, 0x01, 0xff, 0x04, 0x05, 0x30, 0x03, 0x01, 0x01,
+    0xff, 0x30, 0x0e, 0x06, 0x03, 0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04,
+    0x04, 0x03, 0x02, 0x01, 0x06, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x04, 0x03, 0x02, 0x03, 0x47, 0x00, 0x30, 0x44, 0x02, 0x20,
+    0x7c, 0x1c, 0x91, 0x4d, 0xb7, 0xd4, 0x3a, 0xce, 0xeb, 0x3d, 0xfd, 0x5a,
+    0x80, 0x41, 0x51, 0x4d, 0x98, 0xa4, 0xa8, 0x33, 0x9c, 0xd7, 0x89, 0x91,
+    0x32, 0x27, 0x76, 0x27, 0x13, 0xb3, 0x22, 0x63, 0x02, 0x20, 0x00, 0x94,
+    0xf4, 0xff, 0x58, 0xe9, 0xfe, 0x7b, 0x99, 0x1b, 0x59, 0xd3, 0xb1, 0xbd,
+    0xfe, 0x75, 0x95, 0xd2, 0x3f, 0x54, 0x3b, 0x64, 0xa8, 0x50, 0x2d, 0x7e,
+    0x10, 0xb4, 0x71, 0x9a, 0x7a, 0xc3
+];
+
+// An intermediate CA with a path length constraint of 0 and the code signing extended key usage,
+// valid from 2024 to 2040.
+#[rustfmt::skip]
+pub const X509_INT: [u8; 340] = [
+    0x30, 0x82, 0x01, 0x50, 0x30, 0x81, 0xf7, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x02, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x14, 0x31, 0x12, 0x30, 0x10, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x09, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x72, 0x6f, 0x6f,
+    0x74, 0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x17, 0x0d, 0x34, 0x30, 0x30, 0x31,
+    0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x13, 0x31,
+    0x11, 0x30, 0x0f, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x08, 0x63, 0x6f,
+    0x73, 0x65, 0x2d, 0x69, 0x6e, 0x74, 0x30, 0x59, 0x30, 0x13, 0x06, 0x07,
+    0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0x21, 0x6d, 0x06,
+    0xb7, 0xc9, 0x80, 0x82, 0x27, 0xf6, 0x99, 0x6a, 0xa5, 0x9e, 0xca, 0xb7,
+    0xd7, 0x0a, 0x4c, 0x5b, 0x10, 0xe7, 0x5f, 0x52, 0x8c, 0x77, 0xba, 0xdd,
+    0x57, 0x61, 0xe6, 0xeb, 0x4e, 0xd7, 0xd2, 0x62, 0x84, 0x7e, 0x5a, 0xe4,
+    0x55, 0xbb, 0xd6, 0xdb, 0x91, 0xdb, 0x10, 0xfa, 0x03, 0x56, 0x2d, 0xd9,
+    0xf1, 0x4f, 0x64, 0xfc, 0xab, 0xfe, 0xd8, 0x05, 0xa7, 0xab, 0x03, 0x8e,
+    0x60, 0xa3, 0x3b, 0x30, 0x39, 0x30, 0x12, 0x06, 0x03, 0x55, 0x1d, 0x13,
+    0x01, 0x01, 0xff, 0x04, 0x08, 0x30, 0x06, 0x01, 0x01, 0xff, 0x02, 0x01,
+    0x00, 0x30, 0x0e, 0x06, 0x03, 0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04,
+    0x04, 0x03, 0x02, 0x02, 0x04, 0x30, 0x13, 0x06, 0x03, 0x55, 0x1d, 0x25,
+    0x04, 0x0c, 0x30, 0x0a, 0x06, 0x08, 0x2b, 0x06, 0x01, 0x05, 0x05, 0x07,
+    0x03, 0x03, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04,
+    0x03, 0x02, 0x03, 0x48, 0x00, 0x30, 0x45, 0x02, 0x20, 0x0b, 0x05, 0x34,
+    0x8c, 0xed, 0x96, 0x81, 0xcf, 0xc3, 0xee, 0x0c, 0x16, 0x63, 0xf6, 0x00,
+    0xaa, 0xdf, 0xc6, 0x40, 0xfd, 0x76, 0x96, 0x8b, 0x2d, 0x78, 0x8d, 0x7b,
+    0x12, 0xf8, 0x4b, 0x37, 0x76, 0x02, 0x21, 0x00, 0xcf, 0x86, 0xd0, 0xd3,
+    0xe9, 0x8c, 0x10, 0x42, 0x51, 0x14, 0x95, 0xff, 0x6e, 0x80, 0x4c, 0x78,
+    0x66, 0x8e, 0xc9, 0xae, 0xb1, 0x7a, 0xd2, 0x44, 0x9b, 0x6f, 0x89, 0xf8,
+    0x31, 0xfa, 0x7b, 0x85
+];
+
+// The same intermediate CA, expired in 2025.
+#[rustfmt::skip]
+pub const X509_INT_EXPIRED: [u8; 341] = [
+    0x30, 0x82, 0x01, 0x51, 0x30, 0x81, 0xf7, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x03, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x14, 0x31, 0x12, 0x30, 0x10, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x09, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x72, 0x6f, 0x6f,
+    0x74, 0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x17, 0x0d, 0x32, 0x35, 0x30, 0x31,
+    0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x13, 0x31,
+    0x11, 0x30, 0x0f, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x08, 0x63, 0x6f,
+    0x73, 0x65, 0x2d, 0x69, 0x6e, 0x74, 0x30, 0x59, 0x30, 0x13, 0x06, 0x07,
+    0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0x21, 0x6d, 0x06,
+    0xb7, 0xc9, 0x80, 0x82, 0x27, 0xf6, 0x99, 0x6a, 0xa5, 0x9e, 0xca, 0xb7,
+    0xd7, 0x0a, 0x4c, 0x5b, 0x10, 0xe7, 0x5f, 0x52, 0x8c, 0x77, 0xba, 0xdd,
+    0x57, 0x61, 0xe6, 0xeb, 0x4e, 0xd7, 0xd2, 0x62, 0x84, 0x7e, 0x5a, 0xe4,
+    0x55, 0xbb, 0xd6, 0xdb, 0x91, 0xdb, 0x10, 0xfa, 0x03, 0x56, 0x2d, 0xd9,
+    0xf1, 0x4f, 0x64, 0xfc, 0xab, 0xfe, 0xd8, 0x05, 0xa7, 0xab, 0x03, 0x8e,
+    0x60, 0xa3, 0x3b, 0x30, 0x39, 0x30, 0x12, 0x06, 0x03, 0x55, 0x1d, 0x13,
+    0x01, 0x01, 0xff, 0x04, 0x08, 0x30, 0x06, 0x01, 0x01, 0xff, 0x02, 0x01,
+    0x00, 0x30, 0x0e, 0x06, 0x03, 0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04,
+    0x04, 0x03, 0x02, 0x02, 0x04, 0x30, 0x13, 0x06, 0x03, 0x55, 0x1d, 0x25,
+    0x04, 0x0c, 0x30, 0x0a, 0x06, 0x08, 0x2b, 0x06, 0x01, 0x05, 0x05, 0x07,
+    0x03, 0x03, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04,
+    0x03, 0x02, 0x03, 0x49, 0x00, 0x30, 0x46, 0x02, 0x21, 0x00, 0x97, 0x4e,
+    0x01, 0x99, 0x62, 0x77, 0x58, 0x17, 0xa4, 0x92, 0x92, 0x3c, 0xcc, 0x7f,
+    0xa7, 0x26, 0x10, 0x4d, 0xda, 0x89, 0x3c, 0x6b, 0x39, 0xf5, 0x02, 0x19,
+    0xd7, 0x5e, 0x4b, 0x79, 0xb9, 0xda, 0x02, 0x21, 0x00, 0xfe, 0x92, 0x94,
+    0x1e, 0xcd, 0x03, 0xae, 0x56, 0x29, 0x96, 0xa5, 0xb7, 0x1c, 0x66, 0xbd,
+    0x7f, 0xeb, 0xfb, 0xd0, 0x1b, 0x2e, 0x53, 0x48, 0xf8, 0x44, 0xf7, 0x73,
+    0x7d, 0x9e, 0x11, 0x82, 0x1d
+];
+
+// The end-entity certificates are valid from 2024 to 2030. This one is for code signing.
+#[rustfmt::skip]
+pub const X509_EE: [u8; 331] = [
+    0x30, 0x82, 0x01, 0x47, 0x30, 0x81, 0xef, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x04, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x13, 0x31, 0x11, 0x30, 0x0f, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x08, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x69, 0x6e, 0x74,
+    0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x5a, 0x17, 0x0d, 0x33, 0x30, 0x30, 0x31, 0x30,
+    0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x12, 0x31, 0x10,
+    0x30, 0x0e, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x07, 0x63, 0x6f, 0x73,
+    0x65, 0x2d, 0x65, 0x65, 0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86,
+    0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0xb7, 0xa6, 0x23, 0x73, 0x43,
+    0xd4, 0x92, 0x8f, 0x1c, 0x8d, 0xd7, 0x3d, 0x62, 0xae, 0x85, 0xea, 0x15,
+    0x83, 0xae, 0xf4, 0x1f, 0x6a, 0x7b, 0x3c, 0xff, 0xc2, 0x64, 0x27, 0x0e,
+    0xfc, 0xb4, 0x0e, 0x2b, 0x6f, 0xd7, 0xb1, 0xfd, 0x22, 0x57, 0xa7, 0x81,
+    0x4f, 0x17, 0x1a, 0x77, 0xce, 0x49, 0xa4, 0x7c, 0xdc, 0xf8, 0xba, 0x7f,
+    0x71, 0xf1, 0xbd, 0x61, 0x36, 0xfa, 0xdb, 0x0c, 0x3b, 0x0b, 0x3c, 0xa3,
+    0x35, 0x30, 0x33, 0x30, 0x0c, 0x06, 0x03, 0x55, 0x1d, 0x13, 0x01, 0x01,
+    0xff, 0x04, 0x02, 0x30, 0x00, 0x30, 0x0e, 0x06, 0x03, 0x55, 0x1d, 0x0f,
+    0x01, 0x01, 0xff, 0x04, 0x04, 0x03, 0x02, 0x07, 0x80, 0x30, 0x13, 0x06,
+    0x03, 0x55, 0x1d, 0x25, 0x04, 0x0c, 0x30, 0x0a, 0x06, 0x08, 0x2b, 0x06,
+    0x01, 0x05, 0x05, 0x07, 0x03, 0x03, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86,
+    0x48, 0xce, 0x3d, 0x04, 0x03, 0x02, 0x03, 0x47, 0x00, 0x30, 0x44, 0x02,
+    0x20, 0x7e, 0xe3, 0xf9, 0xe4, 0x02, 0x7e, 0x4e, 0x1e, 0x55, 0x8a, 0x7f,
+    0xc6, 0x36, 0x90, 0x78, 0xce, 0x23, 0x67, 0xfc, 0x6a, 0xd0, 0xce, 0x6d,
+    0xf9, 0x11, 0x46, 0x4f, 0xa7, 0xc1, 0x72, 0x6b, 0x00, 0x02, 0x20, 0x25,
+    0x1b, 0x3e, 0xc3, 0xba, 0x62, 0x03, 0x11, 0x6d, 0xd2, 0xdd, 0xd6, 0xa2,
+    0xab, 0x56, 0xe0, 0x06, 0x77, 0x9f, 0x10, 0xc8, 0xcd, 0x80, 0x9e, 0xb5,
+    0xf7, 0x0f, 0x03, 0x03, 0x78, 0x85, 0x66
+];
+
+// An end-entity certificate for TLS servers.
+#[rustfmt::skip]
+pub const X509_EE_TLS: [u8; 336] = [
+    0x30, 0x82, 0x01, 0x4c, 0x30, 0x81, 0xf3, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x05, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x13, 0x31, 0x11, 0x30, 0x0f, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x08, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x69, 0x6e, 0x74,
+    0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x5a, 0x17, 0x0d, 0x33, 0x30, 0x30, 0x31, 0x30,
+    0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x16, 0x31, 0x14,
+    0x30, 0x12, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x0b, 0x63, 0x6f, 0x73,
+    0x65, 0x2d, 0x65, 0x65, 0x2d, 0x74, 0x6c, 0x73, 0x30, 0x59, 0x30, 0x13,
+    0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a,
+    0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0xcc,
+    0x95, 0x32, 0xde, 0x36, 0x32, 0x2f, 0x60, 0xa1, 0x55, 0xb7, 0x45, 0xdf,
+    0x7f, 0xc7, 0x7e, 0x68, 0xd6, 0xa8, 0xb9, 0xe5, 0xb8, 0xdb, 0xfa, 0x06,
+    0x2d, 0xa6, 0x4e, 0xb3, 0xed, 0x12, 0x8d, 0xb5, 0xe8, 0xda, 0x09, 0xdc,
+    0xc0, 0x01, 0xe7, 0xf0, 0xa0, 0xe4, 0xac, 0x37, 0x11, 0x6e, 0x6b, 0x55,
+    0x6a, 0xb0, 0xca, 0x9d, 0xe9, 0x85, 0xc1, 0xed, 0x6e, 0x79, 0x86, 0x11,
+    0xd1, 0x02, 0xaf, 0xa3, 0x35, 0x30, 0x33, 0x30, 0x0c, 0x06, 0x03, 0x55,
+    0x1d, 0x13, 0x01, 0x01, 0xff, 0x04, 0x02, 0x30, 0x00, 0x30, 0x0e, 0x06,
+    0x03, 0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04, 0x04, 0x03, 0x02, 0x07,
+    0x80, 0x30, 0x13, 0x06, 0x03, 0x55, 0x1d, 0x25, 0x04, 0x0c, 0x30, 0x0a,
+    0x06, 0x08, 0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x01, 0x30, 0x0a,
+    0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02, 0x03, 0x48,
+    0x00, 0x30, 0x45, 0x02, 0x20, 0x6d, 0xa8, 0x55, 0x7d, 0x2c, 0xdc, 0x15,
+    0xd6, 0x11, 0x66, 0xad, 0x35, 0x6d, 0x1c, 0xa5, 0x7e, 0x36, 0x0c, 0x69,
+    0x56, 0xbd, 0xfa, 0xf2, 0x66, 0x1b, 0x12, 0x1b, 0x19, 0x00, 0x79, 0xc8,
+    0x17, 0x02, 0x21, 0x00, 0xab, 0x94, 0xa0, 0xa3, 0xf7, 0x7f, 0x1e, 0xd9,
+    0x2d, 0xbd, 0x7b, 0xec, 0xcc, 0x94, 0x21, 0xc5, 0xdb, 0xbf, 0x04, 0xb2,
+    0xf4, 0x93, 0x68, 0x6c, 0x33, 0x0e, 0xdf, 0x0a, 0x35, 0xfb, 0x7c, 0x2f
+];
+
+// An end-entity certificate with the key agreement key usage only.
+#[rustfmt::skip]
+pub const X509_EE_KEY_AGREEMENT: [u8; 325] = [
+    0x30, 0x82, 0x01, 0x41, 0x30, 0x81, 0xe8, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x06, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x13, 0x31, 0x11, 0x30, 0x0f, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x08, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x69, 0x6e, 0x74,
+    0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x5a, 0x17, 0x0d, 0x33, 0x30, 0x30, 0x31, 0x30,
+    0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x20, 0x31, 0x1e,
+    0x30, 0x1c, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x15, 0x63, 0x6f, 0x73,
+    0x65, 0x2d, 0x65, 0x65, 0x2d, 0x6b, 0x65, 0x79, 0x2d, 0x61, 0x67, 0x72,
+    0x65, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x30, 0x59, 0x30, 0x13, 0x06, 0x07,
+    0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0x96, 0x79, 0x5a,
+    0x5e, 0xd2, 0x04, 0xce, 0xcf, 0xed, 0x39, 0xd2, 0x3b, 0x7d, 0x9e, 0xf7,
+    0x3d, 0xfc, 0xb4, 0x7a, 0x75, 0x64, 0x39, 0x5b, 0x23, 0xe0, 0x1b, 0x0b,
+    0x97, 0x1a, 0x9c, 0x57, 0x79, 0xa7, 0x40, 0x19, 0xa7, 0x49, 0xa6, 0x0a,
+    0xdd, 0x5a, 0x7c, 0xfe, 0x16, 0x5e, 0x0a, 0x68, 0xcd, 0xc2, 0x8f, 0xa5,
+    0x73, 0x80, 0x73, 0x15, 0x35, 0x1e, 0x1e, 0xa4, 0x83, 0x16, 0xee, 0xe4,
+    0x9f, 0xa3, 0x20, 0x30, 0x1e, 0x30, 0x0c, 0x06, 0x03, 0x55, 0x1d, 0x13,
+    0x01, 0x01, 0xff, 0x04, 0x02, 0x30, 0x00, 0x30, 0x0e, 0x06, 0x03, 0x55,
+    0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04, 0x04, 0x03, 0x02, 0x03, 0x08, 0x30,
+    0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02, 0x03,
+    0x48, 0x00, 0x30, 0x45, 0x02, 0x21, 0x00, 0xe0, 0x12, 0xc1, 0x11, 0xbf,
+    0xe7, 0x6e, 0xec, 0xa4, 0x4d, 0xd9, 0x74, 0x46, 0xf8, 0x69, 0x38, 0x49,
+    0x9d, 0x9a, 0xa1, 0x01, 0xc0, 0xd0, 0xca, 0xd7, 0xfe, 0xce, 0x06, 0x0d,
+    0xab, 0xf3, 0x57, 0x02, 0x20, 0x3f, 0xa4, 0x8d, 0x05, 0x8d, 0xfd, 0x88,
+    0x2f, 0x3f, 0x54, 0xca, 0x5d, 0xcf, 0x49, 0x7d, 0xc6, 0xa6, 0x42, 0x8b,
+    0xa5, 0x1c, 0xd8, 0x96, 0x54, 0x49, 0x95, 0x4b, 0xd7, 0xb2, 0xef, 0x96,
+    0x80
+];
+
+// An end-entity certificate with an unknown critical extension.
+#[rustfmt::skip]
+pub const X509_EE_CRITICAL: [u8; 340] = [
+    0x30, 0x82, 0x01, 0x50, 0x30, 0x81, 0xf7, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x07, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x13, 0x31, 0x11, 0x30, 0x0f, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x08, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x69, 0x6e, 0x74,
+    0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x5a, 0x17, 0x0d, 0x33, 0x30, 0x30, 0x31, 0x30,
+    0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x1b, 0x31, 0x19,
+    0x30, 0x17, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x10, 0x63, 0x6f, 0x73,
+    0x65, 0x2d, 0x65, 0x65, 0x2d, 0x63, 0x72, 0x69, 0x74, 0x69, 0x63, 0x61,
+    0x6c, 0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07,
+    0x03, 0x42, 0x00, 0x04, 0xb9, 0x4e, 0xae, 0xf3, 0xd9, 0x55, 0x0b, 0xa7,
+    0x2b, 0x53, 0x92, 0x43, 0x68, 0x78, 0xbd, 0x6e, 0x1b, 0xbb, 0x16, 0xab,
+    0x93, 0xce, 0xcf, 0xd8, 0xab, 0x3a, 0xe7, 0xa3, 0xaa, 0xdf, 0x8d, 0x38,
+    0x9f, 0xb5, 0x0a, 0x2c, 0xb3, 0x9c, 0xaf, 0x09, 0x48, 0xca, 0xfb, 0xd3,
+    0x08, 0x5e, 0xf2, 0xa0, 0x74, 0xd7, 0xaf, 0xd7, 0xc0, 0x47, 0xbb, 0xe8,
+    0x0c, 0xd7, 0xa7, 0xf5, 0xeb, 0x30, 0xc2, 0xa9, 0xa3, 0x34, 0x30, 0x32,
+    0x30, 0x0c, 0x06, 0x03, 0x55, 0x1d, 0x13, 0x01, 0x01, 0xff, 0x04, 0x02,
+    0x30, 0x00, 0x30, 0x0e, 0x06, 0x03, 0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff,
+    0x04, 0x04, 0x03, 0x02, 0x07, 0x80, 0x30, 0x12, 0x06, 0x09, 0x2b, 0x06,
+    0x01, 0x04, 0x01, 0x83, 0xb2, 0x03, 0x01, 0x01, 0x01, 0xff, 0x04, 0x02,
+    0x05, 0x00, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04,
+    0x03, 0x02, 0x03, 0x48, 0x00, 0x30, 0x45, 0x02, 0x20, 0x7c, 0x11, 0xbd,
+    0x2a, 0x4b, 0x53, 0xc0, 0x05, 0x87, 0x2c, 0x2c, 0xf4, 0xe0, 0x82, 0x75,
+    0xbe, 0xdc, 0xe7, 0x2e, 0x0a, 0x6d, 0x64, 0x8d, 0xee, 0x65, 0x6f, 0x1c,
+    0x90, 0x6b, 0xa1, 0x4c, 0xc4, 0x02, 0x21, 0x00, 0xfc, 0xf4, 0xe2, 0x3c,
+    0xae, 0x7c, 0x2d, 0x25, 0x71, 0x74, 0x88, 0x39, 0x90, 0x55, 0x80, 0xd6,
+    0xa5, 0x92, 0xb3, 0xed, 0x9b, 0xda, 0xa2, 0xa5, 0x02, 0x04, 0xaa, 0xef,
+    0xe7, 0x20, 0xb0, 0x25
+];
+
+// An intermediate CA issued by X509_INT, which violates its path length constraint.
+#[rustfmt::skip]
+pub const X509_INT2: [u8; 315] = [
+    0x30, 0x82, 0x01, 0x37, 0x30, 0x81, 0xdf, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x08, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x13, 0x31, 0x11, 0x30, 0x0f, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x08, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x69, 0x6e, 0x74,
+    0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x5a, 0x17, 0x0d, 0x33, 0x30, 0x30, 0x31, 0x30,
+    0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x14, 0x31, 0x12,
+    0x30, 0x10, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x09, 0x63, 0x6f, 0x73,
+    0x65, 0x2d, 0x69, 0x6e, 0x74, 0x32, 0x30, 0x59, 0x30, 0x13, 0x06, 0x07,
+    0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0xae, 0x99, 0x14,
+    0x42, 0x1f, 0xaf, 0x41, 0xa6, 0x82, 0xc6, 0x6d, 0xe5, 0xe3, 0xb6, 0x9f,
+    0x82, 0x23, 0x62, 0x4f, 0xb5, 0xf9, 0x91, 0x49, 0x39, 0xae, 0x05, 0x52,
+    0x27, 0xfb, 0xd8, 0x2b, 0xb3, 0x23, 0x3b, 0xa5, 0x05, 0x67, 0x39, 0xbb,
+    0xb5, 0xb8, 0x7e, 0x80, 0x50, 0xa1, 0x6f, 0x41, 0xcb, 0x4a, 0x99, 0x9c,
+    0x25, 0x08, 0x9f, 0x6b, 0xa4, 0xba, 0xf5, 0x18, 0xcc, 0x1a, 0x3a, 0x91,
+    0xfe, 0xa3, 0x23, 0x30, 0x21, 0x30, 0x0f, 0x06, 0x03, 0x55, 0x1d, 0x13,
+    0x01, 0x01, 0xff, 0x04, 0x05, 0x30, 0x03, 0x01, 0x01, 0xff, 0x30, 0x0e,
+    0x06, 0x03, 0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04, 0x04, 0x03, 0x02,
+    0x02, 0x04, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04,
+    0x03, 0x02, 0x03, 0x47, 0x00, 0x30, 0x44, 0x02, 0x20, 0x0f, 0x98, 0xa2,
+    0x6b, 0x2a, 0xe0, 0x62, 0xb1, 0x56, 0xa3, 0x26, 0x9b, 0xc4, 0x71, 0x58,
+    0x12, 0xcb, 0x07, 0x7a, 0xfd, 0x3d, 0x4c, 0x9c, 0x08, 0x25, 0xdb, 0x35,
+    0x86, 0xab, 0x80, 0xaf, 0x6a, 0x02, 0x20, 0x52, 0xf4, 0xc2, 0x93, 0xe6,
+    0xba, 0x9d, 0x22, 0xb8, 0x32, 0xd2, 0x76, 0x87, 0x7b, 0x2e, 0x86, 0x3b,
+    0xb9, 0x81, 0x1e, 0x49, 0x92, 0x14, 0xe8, 0x51, 0x5b, 0x4b, 0x37, 0x2d,
+    0xe9, 0x1b, 0xc5
+];
+
+// An end-entity certificate issued by X509_INT2.
+#[rustfmt::skip]
+pub const X509_EE_INT2: [u8; 317] = [
+    0x30, 0x82, 0x01, 0x39, 0x30, 0x81, 0xe0, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x09, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x14, 0x31, 0x12, 0x30, 0x10, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x09, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x69, 0x6e, 0x74,
+    0x32, 0x30, 0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x17, 0x0d, 0x33, 0x30, 0x30, 0x31,
+    0x30, 0x31, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x17, 0x31,
+    0x15, 0x30, 0x13, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x0c, 0x63, 0x6f,
+    0x73, 0x65, 0x2d, 0x65, 0x65, 0x2d, 0x69, 0x6e, 0x74, 0x32, 0x30, 0x59,
+    0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06,
+    0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+    0x04, 0xde, 0x42, 0x40, 0xa8, 0xf5, 0xc3, 0x02, 0x7d, 0xef, 0xa9, 0x19,
+    0xb1, 0x98, 0x0c, 0xe9, 0xcd, 0xfb, 0x87, 0x66, 0x72, 0x1b, 0xa6, 0xfe,
+    0xe4, 0x00, 0xf2, 0xac, 0x77, 0x62, 0xce, 0xf5, 0xb6, 0xae, 0x9b, 0x53,
+    0x42, 0xab, 0x60, 0xe5, 0x52, 0xbc, 0xc8, 0xc8, 0x5e, 0xce, 0xcb, 0xb4,
+    0xfb, 0x20, 0xad, 0x0d, 0x90, 0x45, 0x69, 0xb7, 0x31, 0x3d, 0x68, 0xbe,
+    0xc3, 0x8a, 0x45, 0x54, 0xeb, 0xa3, 0x20, 0x30, 0x1e, 0x30, 0x0c, 0x06,
+    0x03, 0x55, 0x1d, 0x13, 0x01, 0x01, 0xff, 0x04, 0x02, 0x30, 0x00, 0x30,
+    0x0e, 0x06, 0x03, 0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04, 0x04, 0x03,
+    0x02, 0x07, 0x80, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x03, 0x48, 0x00, 0x30, 0x45, 0x02, 0x21, 0x00, 0x97,
+    0x38, 0xfc, 0xfc, 0x69, 0xb6, 0x7d, 0xc0, 0x25, 0x5f, 0x6b, 0x31, 0x6a,
+    0xf9, 0x0d, 0x7f, 0x96, 0xb0, 0x3c, 0xba, 0x0f, 0x49, 0x5c, 0xff, 0x66,
+    0xc2, 0x3b, 0x16, 0x20, 0x5b, 0x7c, 0x36, 0x02, 0x20, 0x24, 0x1f, 0x99,
+    0x0b, 0xe7, 0x9d, 0xcc, 0x6b, 0xda, 0x83, 0x5d, 0xee, 0x45, 0x06, 0xa7,
+    0xd5, 0xd6, 0x36, 0xef, 0x55, 0x68, 0xf2, 0x25, 0x1f, 0xb7, 0x2e, 0x3b,
+    0x28, 0x9a, 0xd7, 0x48, 0x5b
+];
+
+// A certificate issued by the end-entity X509_EE, which isn't a CA.
+#[rustfmt::skip]
+pub const X509_EE_SUB: [u8; 314] = [
+    0x30, 0x82, 0x01, 0x36, 0x30, 0x81, 0xdd, 0xa0, 0x03, 0x02, 0x01, 0x02,
+    0x02, 0x01, 0x0a, 0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d,
+    0x04, 0x03, 0x02, 0x30, 0x12, 0x31, 0x10, 0x30, 0x0e, 0x06, 0x03, 0x55,
+    0x04, 0x03, 0x0c, 0x07, 0x63, 0x6f, 0x73, 0x65, 0x2d, 0x65, 0x65, 0x30,
+    0x1e, 0x17, 0x0d, 0x32, 0x34, 0x30, 0x31, 0x30, 0x31, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0x5a, 0x17, 0x0d, 0x33, 0x30, 0x30, 0x31, 0x30, 0x31,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x5a, 0x30, 0x16, 0x31, 0x14, 0x30,
+    0x12, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x0b, 0x63, 0x6f, 0x73, 0x65,
+    0x2d, 0x65, 0x65, 0x2d, 0x73, 0x75, 0x62, 0x30, 0x59, 0x30, 0x13, 0x06,
+    0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86,
+    0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0xb4, 0xbb,
+    0x64, 0xdd, 0xf4, 0x7f, 0x82, 0xea, 0xc0, 0xb5, 0x83, 0x2f, 0x53, 0xc0,
+    0xe9, 0x7b, 0x7c, 0xc2, 0x74, 0x19, 0xbe, 0xb2, 0x0e, 0x69, 0x59, 0x3c,
+    0x5d, 0xf5, 0x3e, 0x22, 0x31, 0x61, 0x17, 0xe2, 0x55, 0x4f, 0xee, 0xd3,
+    0x5e, 0xc7, 0x21, 0x32, 0x5c, 0xef, 0xc4, 0xa4, 0xbb, 0x51, 0x7b, 0xe1,
+    0x00, 0xea, 0xec, 0x28, 0xa5, 0x63, 0x92, 0x85, 0x21, 0x60, 0x1c, 0xb6,
+    0x95, 0xdb, 0xa3, 0x20, 0x30, 0x1e, 0x30, 0x0c, 0x06, 0x03, 0x55, 0x1d,
+    0x13, 0x01, 0x01, 0xff, 0x04, 0x02, 0x30, 0x00, 0x30, 0x0e, 0x06, 0x03,
+    0x55, 0x1d, 0x0f, 0x01, 0x01, 0xff, 0x04, 0x04, 0x03, 0x02, 0x07, 0x80,
+    0x30, 0x0a, 0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02,
+    0x03, 0x48, 0x00, 0x30, 0x45, 0x02, 0x20, 0x01, 0x81, 0x9c, 0x01, 0xd1,
+    0x8a, 0x23, 0x46, 0xfc, 0x72, 0x8c, 0x08, 0xf2, 0xee, 0x67, 0x47, 0x69,
+    0x26, 0x56, 0xea, 0xbd, 0xad, 0xe3, 0x10, 0x10, 0x7d, 0xdc, 0x6e, 0x1f,
+    0x48, 0x6c, 0x4e, 0x02, 0x21, 0x00, 0xba, 0x33, 0xe2, 0x36, 0xd2, 0xe6,
+    0x64, 0x0b, 0x38, 0xd4, 0x63, 0xa5, 0x0b, 0x19, 0xfb, 0x7a, 0x5d, 0x85,
+    0x74, 0x7e, 0x81, 0x26, 0x63, 0x21, 0x7d, 0x70, 0x28, 0xbc, 0x77, 0x94,
+    0xba, 0x96
+];
+
+// The COSE_Mac of the cose-wg examples (mac-tests/HMac-01): HMAC 256/256 over "This is the
+// content." with a direct recipient using the key COSE_KEY_SYMMETRIC.
+#[rustfmt::skip]
+pub const COSE_MAC_HMAC256_256: [u8; 82] = [
+    0xd8, 0x61, 0x85, 0x43, 0xa1, 0x01, 0x05, 0xa0, 0x54, 0x54, 0x68, 0x69,
+    0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x6e, 0x74, 0x2e, 0x58, 0x20, 0x2b, 0xdc, 0xc8, 0x9f, 0x05,
+    0x82, 0x16, 0xb8, 0xa2, 0x08, 0xdd, 0xc6, 0xd8, 0xb5, 0x4a, 0xa9, 0x1f,
+    0x48, 0xbd, 0x63, 0x48, 0x49, 0x86, 0x56, 0x51, 0x05, 0xc9, 0xad, 0x5a,
+    0x66, 0x82, 0xf6, 0x81, 0x83, 0x40, 0xa2, 0x01, 0x25, 0x04, 0x4a, 0x6f,
+    0x75, 0x72, 0x2d, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x40
+];
+
+// COSE_Mac0 messages over the same content with the key COSE_KEY_SYMMETRIC, which is identified
+// by the kid. The HMAC 256/256 tag is the one of the cose-wg example mac0-tests/HMac-01.
+#[rustfmt::skip]
+pub const COSE_MAC0_HMAC256_64: [u8; 49] = [
+    0xd1, 0x84, 0x43, 0xa1, 0x01, 0x04, 0xa1, 0x04, 0x4a, 0x6f, 0x75, 0x72,
+    0x2d, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x54, 0x54, 0x68, 0x69, 0x73,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+    0x65, 0x6e, 0x74, 0x2e, 0x48, 0x11, 0xf9, 0xe3, 0x57, 0x97, 0x5f, 0xb8,
+    0x49
+];
+
+// HMAC 256/256.
+#[rustfmt::skip]
+pub const COSE_MAC0_HMAC256_256: [u8; 74] = [
+    0xd1, 0x84, 0x43, 0xa1, 0x01, 0x05, 0xa1, 0x04, 0x4a, 0x6f, 0x75, 0x72,
+    0x2d, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x54, 0x54, 0x68, 0x69, 0x73,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+    0x65, 0x6e, 0x74, 0x2e, 0x58, 0x20, 0xa1, 0xa8, 0x48, 0xd3, 0x47, 0x1f,
+    0x9d, 0x61, 0xee, 0x49, 0x01, 0x8d, 0x24, 0x4c, 0x82, 0x47, 0x72, 0xf2,
+    0x23, 0xad, 0x4f, 0x93, 0x52, 0x93, 0xf1, 0x78, 0x9f, 0xc3, 0xa0, 0x8d,
+    0x8c, 0x58
+];
+
+// HMAC 384/384.
+#[rustfmt::skip]
+pub const COSE_MAC0_HMAC384_384: [u8; 90] = [
+    0xd1, 0x84, 0x43, 0xa1, 0x01, 0x06, 0xa1, 0x04, 0x4a, 0x6f, 0x75, 0x72,
+    0x2d, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x54, 0x54, 0x68, 0x69, 0x73,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+    0x65, 0x6e, 0x74, 0x2e, 0x58, 0x30, 0xc2, 0xbf, 0x6c, 0x50, 0x4c, 0xd0,
+    0x98, 0xa7, 0x26, 0x33, 0xa1, 0xeb, 0x3e, 0xcd, 0xa1, 0xa0, 0x0e, 0x9a,
+    0xe1, 0x37, 0xf2, 0xaf, 0x45, 0xdb, 0xb8, 0xf4, 0xa1, 0x31, 0x00, 0xca,
+    0x48, 0x99, 0x93, 0x0a, 0x5c, 0xb5, 0x85, 0x15, 0x4f, 0x68, 0xe7, 0x7b,
+    0x9b, 0x6f, 0x6c, 0xc0, 0x46, 0x44
+];
+
+// HMAC 512/512.
+#[rustfmt::skip]
+pub const COSE_MAC0_HMAC512_512: [u8; 106] = [
+    0xd1, 0x84, 0x43, 0xa1, 0x01, 0x07, 0xa1, 0x04, 0x4a, 0x6f, 0x75, 0x72,
+    0x2d, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x54, 0x54, 0x68, 0x69, 0x73,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+    0x65, 0x6e, 0x74, 0x2e, 0x58, 0x40, 0x15, 0xcc, 0xe6, 0x5b, 0x77, 0x6c,
+    0x67, 0x1e, 0x07, 0xef, 0x06, 0x41, 0x58, 0x25, 0xb3, 0x0e, 0x65, 0xf7,
+    0x77, 0xed, 0xaf, 0x2b, 0xf4, 0x72, 0x48, 0xc6, 0xf8, 0x1b, 0x0f, 0x12,
+    0xae, 0x33, 0xdf, 0x0d, 0x9f, 0xad, 0xbe, 0x35, 0xb9, 0x00, 0x19, 0xfd,
+    0x5d, 0xd8, 0xcb, 0xe2, 0x38, 0x86, 0x5a, 0x62, 0x47, 0x7c, 0xc3, 0x69,
+    0xee, 0x0b, 0x97, 0x78, 0x86, 0xa9, 0xe8, 0x7a, 0x89, 0xad
+];
@@ -0,0 +1,105 @@
+use test_setup as test;
+use {CoseError, MacAlgorithm};
+use util::{MacContext, MacStructure, SigContext, SigStructure, constant_time_eq, truncate_tag};
+
+#[test]
+fn test_sig_structure_signature1() {
+    let sig_structure = SigStructure::new(
+        SigContext::Signature1,
+        &[0xa1, 0x01, 0x26],
+        b"This is the content.",
+    );
+    assert_eq!(sig_structure.to_bytes().unwrap(), test::COSE_SIGN1_TO_VERIFY.to_vec());
+}
+
+#[test]
+fn test_sig_structure_signature() {
+    let sig_structure = SigStructure::new(SigContext::Signature, &[0xa0], &[0x01])
+        .sign_protected(&[0xa1, 0x01, 0x26])
+        .external_aad(&[0x02]);
+    let expected = vec![0x85, 0x69, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65,
+                        0x41, 0xa0,
+                        0x43, 0xa1, 0x01, 0x26,
+                        0x41, 0x02,
+                        0x41, 0x01];
+    assert_eq!(sig_structure.to_bytes().unwrap(), expected);
+}
+
+#[test]
+fn test_sig_structure_countersignature() {
+    let sig_structure = SigStructure::new(SigContext::CounterSignature, &[], &[0x01])
+        .sign_protected(&[0xa1, 0x01, 0x26])
+        .other_fields(vec![vec![0x03]]);
+    let mut expected = vec![0x86, 0x70];
+    expected.extend_from_slice(b"CounterSignature");
+    expected.extend_from_slice(&[0x40,
+                                 0x43, 0xa1, 0x01, 0x26,
+                                 0x40,
+                                 0x41, 0x01,
+                                 0x81, 0x41, 0x03]);
+    assert_eq!(sig_structure.to_bytes().unwrap(), expected);
+}
+
+#[test]
+fn test_sig_structure_countersignature0() {
+    let sig_structure = SigStructure::new(SigContext::CounterSignature0, &[0xa1, 0x01, 0x26], &[]);
+    let mut expected = vec![0x84, 0x71];
+    expected.extend_from_slice(b"CounterSignature0");
+    expected.extend_from_slice(&[0x43, 0xa1, 0x01, 0x26,
+                                 0x40,
+                                 0x40]);
+    assert_eq!(sig_structure.to_bytes().unwrap(), expected);
+}
+
+#[test]
+fn test_sig_structure_sign_protected() {
+    // "Signature" and "CounterSignature" need the signer's protected header.
+    for context in &[SigContext::Signature, SigContext::CounterSignature] {
+        let sig_structure = SigStructure::new(*context, &[], &[]);
+        assert_eq!(sig_structure.to_bytes(), Err(CoseError::InvalidArgument));
+    }
+    // "Signature1" and "CounterSignature0" don't have one.
+    for context in &[SigContext::Signature1, SigContext::CounterSignature0] {
+        let sig_structure = SigStructure::new(*context, &[], &[]).sign_protected(&[]);
+        assert_eq!(sig_structure.to_bytes(), Err(CoseError::InvalidArgument));
+    }
+}
+
+#[test]
+fn test_sig_structure_other_fields() {
+    let sig_structure = SigStructure::new(SigContext::Signature1, &[], &[])
+        .other_fields(vec![vec![0x01]]);
+    assert_eq!(sig_structure.to_bytes(), Err(CoseError::InvalidArgument));
+}
+
+#[test]
+fn test_mac_structure() {
+    let mac_structure = MacStructure::new(MacContext::Mac0, &[0xa1, 0x01, 0x04], &[0x01])
+        .external_aad(&[0x02]);
+    let expected = vec![0x84, 0x64, 0x4d, 0x41, 0x43, 0x30,
+                        0x43, 0xa1, 0x01, 0x04,
+                        0x41, 0x02,
+                        0x41, 0x01];
+    assert_eq!(mac_structure.to_bytes(), expected);
+    let mac_structure = MacStructure::new(MacContext::Mac, &[], &[]);
+    assert_eq!(mac_structure.to_bytes(), vec![0x84, 0x63, 0x4d, 0x41, 0x43, 0x40, 0x40, 0x40]);
+}
+
+#[test]
+fn test_constant_time_eq() {
+    assert!(constant_time_eq(&[], &[]));
+    assert!(constant_time_eq(&[0x01, 0x02], &[0x01, 0x02]));
+    assert!(!constant_time_eq(&[0x01, 0x02], &[0x01, 0x03]));
+    assert!(!constant_time_eq(&[0x81, 0x02], &[0x01, 0x02]));
+    assert!(!constant_time_eq(&[0x01, 0x02], &[0x01]));
+    assert!(!constant_time_eq(&[], &[0x00]));
+}
+
+#[test]
+fn test_truncate_tag() {
+    let mac = (0..64).collect::<Vec<u8>>();
+    assert_eq!(truncate_tag(MacAlgorithm::HMAC256_64, mac.clone()), Ok((0..8).collect()));
+    assert_eq!(truncate_tag(MacAlgorithm::HMAC512_512, mac.clone()), Ok(mac.clone()));
+    assert_eq!(truncate_tag(MacAlgorithm::HMAC384_384, mac[..47].to_vec()),
+               Err(CoseError::LibraryFailure));
+}
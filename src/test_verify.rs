@@ -0,0 +1,112 @@
+use {CoseError, SignatureAlgorithm, Verifier};
+use encoder::CoseSignBuilder;
+use verify::{SignerPolicy, VerificationPolicy, verify_signature};
+
+const PAYLOAD: &[u8] = b"This is the content.";
+
+// A `Verifier` for tests that accepts signatures that are the signed bytes themselves, unless the
+// signer has the certificate `[0]`.
+struct TestVerifier;
+
+impl Verifier for TestVerifier {
+    fn verify(
+        &self,
+        _: SignatureAlgorithm,
+        key: &[u8],
+        to_verify: &[u8],
+        signature: &[u8],
+    ) -> Result<(), CoseError> {
+        if key == [0] || signature != to_verify {
+            return Err(CoseError::VerificationFailed);
+        }
+        Ok(())
+    }
+}
+
+// Build a detached COSE_Sign in the legacy layout with a signer for each algorithm and
+// certificate.
+fn build_test_signature(signers: &[(SignatureAlgorithm, &[u8])]) -> Vec<u8> {
+    let mut builder = CoseSignBuilder::new(PAYLOAD).detached().nil_external_aad();
+    for &(algorithm, cert) in signers {
+        builder = builder.signer(algorithm, cert, |to_sign| Ok(to_sign.to_vec()));
+    }
+    builder.build().unwrap()
+}
+
+#[test]
+fn test_verify_signer_policies() {
+    let bytes = build_test_signature(&[(SignatureAlgorithm::ES256, &[1]),
+                                       (SignatureAlgorithm::PS256, &[0]),
+                                       (SignatureAlgorithm::ES384, &[2])]);
+    let policy = VerificationPolicy::new(SignerPolicy::All);
+    let report = verify_signature(&bytes, PAYLOAD, &TestVerifier, &policy).unwrap();
+    assert!(!report.is_verified());
+    assert_eq!(report.result, Err(CoseError::VerificationFailed));
+    assert_eq!(report.verified_count(), 2);
+    assert_eq!(report.signers.len(), 3);
+    assert_eq!(report.signers[1].signature_type, SignatureAlgorithm::PS256);
+    assert_eq!(report.signers[1].signer_cert, vec![0]);
+    assert_eq!(report.signers[1].result, Err(CoseError::VerificationFailed));
+    assert_eq!(report.signers[2].result, Ok(()));
+
+    let expected = [(SignerPolicy::Any, Ok(())),
+                    (SignerPolicy::Threshold(1), Ok(())),
+                    (SignerPolicy::Threshold(2), Ok(())),
+                    (SignerPolicy::Threshold(3), Err(CoseError::VerificationFailed)),
+                    (SignerPolicy::Threshold(0), Err(CoseError::InvalidArgument))];
+    for &(signers, ref result) in &expected {
+        let policy = VerificationPolicy::new(signers);
+        let report = verify_signature(&bytes, PAYLOAD, &TestVerifier, &policy).unwrap();
+        assert_eq!(&report.result, result, "{:?}", signers);
+    }
+
+    let bytes = build_test_signature(&[(SignatureAlgorithm::ES256, &[1]),
+                                       (SignatureAlgorithm::PS256, &[2])]);
+    let policy = VerificationPolicy::new(SignerPolicy::All);
+    let report = verify_signature(&bytes, PAYLOAD, &TestVerifier, &policy).unwrap();
+    assert!(report.is_verified());
+    // A different payload doesn't verify.
+    let report = verify_signature(&bytes, b"Another content.", &TestVerifier, &policy).unwrap();
+    assert_eq!(report.verified_count(), 0);
+    let policy = VerificationPolicy::new(SignerPolicy::Any);
+    let report = verify_signature(&bytes, b"Another content.", &TestVerifier, &policy).unwrap();
+    assert_eq!(report.result, Err(CoseError::VerificationFailed));
+
+    // Decoding errors are returned as they are.
+    assert_eq!(verify_signature(&bytes[1..], PAYLOAD, &TestVerifier, &policy),
+               Err(CoseError::DecodingFailure));
+}
+
+#[test]
+fn test_verify_required_algorithms() {
+    // Firefox add-ons are signed with ES256 and PS256.
+    let policy = VerificationPolicy::new(SignerPolicy::Any)
+        .require_algorithms(&[SignatureAlgorithm::ES256, SignatureAlgorithm::PS256]);
+    let bytes = build_test_signature(&[(SignatureAlgorithm::PS256, &[1]),
+                                       (SignatureAlgorithm::ES256, &[2])]);
+    let report = verify_signature(&bytes, PAYLOAD, &TestVerifier, &policy).unwrap();
+    assert_eq!(report.result, Ok(()));
+
+    // The ES256 signature doesn't verify.
+    let bytes = build_test_signature(&[(SignatureAlgorithm::PS256, &[1]),
+                                       (SignatureAlgorithm::ES256, &[0]),
+                                       (SignatureAlgorithm::ES384, &[2])]);
+    let report = verify_signature(&bytes, PAYLOAD, &TestVerifier, &policy).unwrap();
+    assert_eq!(report.result, Err(CoseError::VerificationFailed));
+    let policy = VerificationPolicy::new(SignerPolicy::Threshold(2))
+        .require_algorithm(SignatureAlgorithm::ES384);
+    let report = verify_signature(&bytes, PAYLOAD, &TestVerifier, &policy).unwrap();
+    assert_eq!(report.result, Ok(()));
+
+    // Every required algorithm needs a signature of its own.
+    let policy = VerificationPolicy::new(SignerPolicy::All)
+        .require_algorithm(SignatureAlgorithm::ES256)
+        .require_algorithm(SignatureAlgorithm::ES256);
+    let bytes = build_test_signature(&[(SignatureAlgorithm::ES256, &[1])]);
+    let report = verify_signature(&bytes, PAYLOAD, &TestVerifier, &policy).unwrap();
+    assert_eq!(report.result, Err(CoseError::VerificationFailed));
+    let bytes = build_test_signature(&[(SignatureAlgorithm::ES256, &[1]),
+                                       (SignatureAlgorithm::ES256, &[2])]);
+    let report = verify_signature(&bytes, PAYLOAD, &TestVerifier, &policy).unwrap();
+    assert_eq!(report.result, Ok(()));
+}
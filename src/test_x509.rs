@@ -0,0 +1,261 @@
+use test_setup as test;
+use std::cell::Cell;
+use {CoseError, SignatureAlgorithm, Verifier};
+use algorithm::Curve;
+use decoder::decode_signature;
+use key::KeyParameters;
+use x509::{ChainValidator, EKU_CODE_SIGNING, EKU_SERVER_AUTH, public_key, signer_public_key};
+
+// 2026-06-01, when all certificates of the test hierarchy but X509_INT_EXPIRED are valid.
+const TIME: u64 = 1_780_272_000;
+
+// A `Verifier` for tests that accepts all ES256 signatures of the right size, except the ones
+// made with the key of the certificate `reject`.
+struct TestVerifier<'a> {
+    reject: &'a [u8],
+}
+
+impl<'a> Verifier for TestVerifier<'a> {
+    fn verify(
+        &self,
+        algorithm: SignatureAlgorithm,
+        key: &[u8],
+        _: &[u8],
+        signature: &[u8],
+    ) -> Result<(), CoseError> {
+        if algorithm != SignatureAlgorithm::ES256 || signature.len() != 64 || key == self.reject {
+            return Err(CoseError::VerificationFailed);
+        }
+        Ok(())
+    }
+}
+
+const VERIFIER: TestVerifier = TestVerifier { reject: &[] };
+
+// A `Verifier` that accepts the same signatures as `VERIFIER` and counts the ones it checks.
+struct CountingVerifier {
+    checks: Cell<usize>,
+}
+
+impl Verifier for CountingVerifier {
+    fn verify(
+        &self,
+        algorithm: SignatureAlgorithm,
+        key: &[u8],
+        to_verify: &[u8],
+        signature: &[u8],
+    ) -> Result<(), CoseError> {
+        self.checks.set(self.checks.get() + 1);
+        VERIFIER.verify(algorithm, key, to_verify, signature)
+    }
+}
+
+fn root_validator() -> ChainValidator {
+    ChainValidator::new(TIME).trust_anchor(&test::X509_ROOT)
+}
+
+#[test]
+fn test_x509_validate_chain() {
+    let path = vec![test::X509_EE.to_vec(), test::X509_INT.to_vec(), test::X509_ROOT.to_vec()];
+    let certs = vec![test::X509_INT.to_vec()];
+    assert_eq!(root_validator().validate(&test::X509_EE, &certs, &VERIFIER).unwrap(), path);
+    let validator = root_validator().extended_key_usage(EKU_CODE_SIGNING);
+    assert_eq!(validator.validate(&test::X509_EE, &certs, &VERIFIER).unwrap(), path);
+
+    // Certificates that are not in the path or can't be parsed don't matter.
+    let certs = vec![vec![0x30, 0x00],
+                     test::X509_INT2.to_vec(),
+                     test::X509_ROOT.to_vec(),
+                     test::X509_INT.to_vec()];
+    assert_eq!(validator.validate(&test::X509_EE, &certs, &VERIFIER).unwrap(), path);
+
+    // A trusted signing certificate is a path on its own.
+    let validator = ChainValidator::new(TIME).trust_anchor(&test::X509_EE);
+    assert_eq!(validator.validate(&test::X509_EE, &[], &VERIFIER).unwrap(),
+               vec![test::X509_EE.to_vec()]);
+}
+
+#[test]
+fn test_x509_validity() {
+    let certs = vec![test::X509_INT.to_vec()];
+    for &time in &[1_672_531_200, 1_924_992_000] {
+        let validator = ChainValidator::new(time).trust_anchor(&test::X509_ROOT);
+        assert_eq!(validator.validate(&test::X509_EE, &certs, &VERIFIER),
+                   Err(CoseError::InvalidCertificate));
+    }
+
+    // The expired intermediate is skipped if there is another path.
+    let certs = vec![test::X509_INT_EXPIRED.to_vec()];
+    assert_eq!(root_validator().validate(&test::X509_EE, &certs, &VERIFIER),
+               Err(CoseError::InvalidCertificate));
+    let certs = vec![test::X509_INT_EXPIRED.to_vec(), test::X509_INT.to_vec()];
+    let path = root_validator().validate(&test::X509_EE, &certs, &VERIFIER).unwrap();
+    assert_eq!(path[1], test::X509_INT.to_vec());
+}
+
+#[test]
+fn test_x509_untrusted() {
+    let certs = vec![test::X509_INT.to_vec()];
+    assert_eq!(ChainValidator::new(TIME).validate(&test::X509_EE, &certs, &VERIFIER),
+               Err(CoseError::UntrustedCertificate));
+    assert_eq!(root_validator().validate(&test::X509_EE, &[], &VERIFIER),
+               Err(CoseError::UntrustedCertificate));
+    let validator = ChainValidator::new(TIME).trust_anchor(&test::X509_EE_TLS);
+    assert_eq!(validator.validate(&test::X509_EE, &certs, &VERIFIER),
+               Err(CoseError::UntrustedCertificate));
+
+    // Signatures of certificates have to verify.
+    let verifier = TestVerifier { reject: &test::X509_INT };
+    assert_eq!(root_validator().validate(&test::X509_EE, &certs, &verifier),
+               Err(CoseError::VerificationFailed));
+    let verifier = TestVerifier { reject: &test::X509_ROOT };
+    assert_eq!(root_validator().validate(&test::X509_EE, &certs, &verifier),
+               Err(CoseError::VerificationFailed));
+}
+
+#[test]
+fn test_x509_signature_checks() {
+    // Copies of the root that only differ in the serial number all issue each other, so there
+    // are exponentially many paths through them to a trust anchor that doesn't exist.
+    let copies = |count: u8| {
+        let mut certs = vec![test::X509_INT.to_vec()];
+        certs.extend((0..count).map(|serial| {
+            let mut cert = test::X509_ROOT.to_vec();
+            cert[14] = serial;
+            cert
+        }));
+        certs
+    };
+    let validator = ChainValidator::new(TIME).trust_anchor(&test::X509_EE_TLS);
+
+    // Only one of the copies can be in a path.
+    let verifier = CountingVerifier { checks: Cell::new(0) };
+    assert_eq!(validator.validate(&test::X509_EE, &copies(20), &verifier),
+               Err(CoseError::UntrustedCertificate));
+    assert_eq!(verifier.checks.get(), 21);
+
+    // Building the path gives up after 100 signature checks.
+    let verifier = CountingVerifier { checks: Cell::new(0) };
+    assert_eq!(validator.validate(&test::X509_EE, &copies(150), &verifier),
+               Err(CoseError::UntrustedCertificate));
+    assert_eq!(verifier.checks.get(), 100);
+}
+
+#[test]
+fn test_x509_constraints() {
+    let certs = vec![test::X509_INT.to_vec()];
+    // The extended key usage is only checked if it is required.
+    assert!(root_validator().validate(&test::X509_EE_TLS, &certs, &VERIFIER).is_ok());
+    let code_signing = root_validator().extended_key_usage(EKU_CODE_SIGNING);
+    assert_eq!(code_signing.validate(&test::X509_EE_TLS, &certs, &VERIFIER),
+               Err(CoseError::InvalidCertificate));
+    // The intermediate is only for code signing.
+    let server_auth = root_validator().extended_key_usage(EKU_SERVER_AUTH);
+    assert_eq!(server_auth.validate(&test::X509_EE_TLS, &certs, &VERIFIER),
+               Err(CoseError::InvalidCertificate));
+
+    for cert in &[&test::X509_EE_KEY_AGREEMENT[..], &test::X509_EE_CRITICAL[..]] {
+        assert_eq!(root_validator().validate(cert, &certs, &VERIFIER),
+                   Err(CoseError::InvalidCertificate));
+    }
+
+    // X509_INT allows no intermediates below it.
+    let certs = vec![test::X509_INT2.to_vec(), test::X509_INT.to_vec()];
+    assert_eq!(root_validator().validate(&test::X509_EE_INT2, &certs, &VERIFIER),
+               Err(CoseError::InvalidCertificate));
+    let int_anchor = ChainValidator::new(TIME).trust_anchor(&test::X509_INT);
+    assert!(int_anchor.validate(&test::X509_EE_INT2, &certs, &VERIFIER).is_ok());
+
+    // X509_EE is not a CA.
+    let certs = vec![test::X509_EE.to_vec(), test::X509_INT.to_vec()];
+    assert_eq!(root_validator().validate(&test::X509_EE_SUB, &certs, &VERIFIER),
+               Err(CoseError::InvalidCertificate));
+}
+
+#[test]
+fn test_x509_malformed() {
+    let certs = vec![test::X509_INT.to_vec()];
+    let mut truncated = test::X509_EE.to_vec();
+    truncated.pop();
+    let mut trailing = test::X509_EE.to_vec();
+    trailing.push(0);
+    for cert in &[&[][..], &[0x30, 0x00][..], &truncated[..], &trailing[..]] {
+        assert_eq!(root_validator().validate(cert, &certs, &VERIFIER),
+                   Err(CoseError::DecodingFailure));
+    }
+    let validator = root_validator().trust_anchor(&truncated);
+    assert_eq!(validator.validate(&test::X509_EE, &certs, &VERIFIER),
+               Err(CoseError::DecodingFailure));
+}
+
+#[test]
+fn test_x509_public_key() {
+    let key = public_key(&test::X509_EE).unwrap();
+    match key.params {
+        KeyParameters::Ec2 { crv: Curve::P256, x: Some(ref x), y: Some(ref y), d: None } => {
+            let mut point = vec![0x04];
+            point.extend_from_slice(x);
+            point.extend_from_slice(y);
+            assert!(test::X509_EE.windows(65).any(|window| window == &point[..]));
+        }
+        _ => panic!("expected a P-256 key"),
+    }
+    assert_eq!(signer_public_key(&test::X509_EE, SignatureAlgorithm::ES256), Ok(key));
+    for &algorithm in &[SignatureAlgorithm::ES384,
+                        SignatureAlgorithm::ES256K,
+                        SignatureAlgorithm::PS256,
+                        SignatureAlgorithm::EdDSA] {
+        assert_eq!(signer_public_key(&test::X509_EE, algorithm),
+                   Err(CoseError::KeyAlgorithmMismatch));
+    }
+    assert_eq!(public_key(&test::X509_EE[..100]), Err(CoseError::DecodingFailure));
+
+    let cose_signatures =
+        decode_signature(&test::COSE_SIGNATURE_BYTES, b"This is the content.").unwrap();
+    assert_eq!(cose_signatures[0].public_key().unwrap().crv(), Some(Curve::P256));
+}
+
+#[cfg(any(feature = "nss", feature = "openssl", feature = "rustcrypto"))]
+#[test]
+fn test_x509_public_key_types() {
+    use algorithm::ALGORITHMS;
+    use test_keys as keys;
+    // The certificates with the curves of their keys and the algorithms that fit them.
+    let matching: [(&[u8], Option<Curve>, &[SignatureAlgorithm]); 6] = [
+        (&keys::P384_EE, Some(Curve::P384), &[SignatureAlgorithm::ES384]),
+        (&keys::P521_EE, Some(Curve::P521), &[SignatureAlgorithm::ES512]),
+        (&keys::SECP256K1_EE, Some(Curve::Secp256k1), &[SignatureAlgorithm::ES256K]),
+        // PS256 to RS512.
+        (&keys::RSA_EE, None, &ALGORITHMS[4..10]),
+        // EdDSA and Ed25519.
+        (&keys::ED25519_EE, Some(Curve::Ed25519), &ALGORITHMS[10..12]),
+        (&keys::ED448_EE,
+         Some(Curve::Ed448),
+         &[SignatureAlgorithm::EdDSA, SignatureAlgorithm::Ed448]),
+    ];
+    for &(cert, crv, algorithms) in &matching {
+        let key = public_key(cert).unwrap();
+        assert_eq!(key.crv(), crv);
+        for &algorithm in ALGORITHMS.iter() {
+            let expected = if algorithms.contains(&algorithm) {
+                Ok(key.clone())
+            } else {
+                Err(CoseError::KeyAlgorithmMismatch)
+            };
+            assert_eq!(signer_public_key(cert, algorithm), expected);
+        }
+    }
+
+    // The RSA key has 2048 bits, without the leading zero of the DER integer.
+    match public_key(&keys::RSA_EE).unwrap().params {
+        KeyParameters::Rsa { ref n, ref e, .. } => {
+            assert_eq!(n.len(), 256);
+            assert_eq!(e, &vec![0x01, 0x00, 0x01]);
+        }
+        _ => panic!("expected an RSA key"),
+    }
+    // RSA keys need at least 2048 bits.
+    assert!(public_key(&keys::RSA1024_EE).is_ok());
+    assert_eq!(signer_public_key(&keys::RSA1024_EE, SignatureAlgorithm::RS256),
+               Err(CoseError::KeyAlgorithmMismatch));
+}
@@ -1,4 +1,5 @@
 use cbor::CborType;
+use {CoseError, MacAlgorithm};
 
 /// Sig_structure is a CBOR array:
 ///
@@ -10,8 +11,9 @@ use cbor::CborType;
 ///   payload : bstr
 /// ]
 ///
-/// In this case, the context is "Signature". There is no external_aad, so this defaults to a
-/// zero-length bstr.
+/// In this case, the context is "Signature". Note that external_aad is encoded as nil here rather
+/// than as a bstr. Existing signatures (e.g. on Firefox add-ons) were produced this way, so this
+/// is kept for compatibility. Use `SigStructure` for the form RFC 8152 requires.
 pub fn get_sig_struct_bytes(
     protected_body_header_serialized: CborType,
     protected_signature_header_serialized: CborType,
@@ -25,3 +27,226 @@ pub fn get_sig_struct_bytes(
 
     CborType::Array(sig_structure_array).serialize()
 }
+
+/// The context of a Sig_structure, i.e. the kind of signature it is built for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SigContext {
+    /// A COSE_Signature of a COSE_Sign message.
+    Signature,
+    /// The signature of a COSE_Sign1 message.
+    Signature1,
+    /// A COSE_Countersignature (RFC 9338).
+    CounterSignature,
+    /// A COSE_Countersignature0 (RFC 9338), which has no headers of its own.
+    CounterSignature0,
+}
+
+impl SigContext {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            SigContext::Signature => "Signature",
+            SigContext::Signature1 => "Signature1",
+            SigContext::CounterSignature => "CounterSignature",
+            SigContext::CounterSignature0 => "CounterSignature0",
+        }
+    }
+
+    /// Returns true if signatures in this context have their own protected header.
+    fn has_sign_protected(&self) -> bool {
+        match *self {
+            SigContext::Signature | SigContext::CounterSignature => true,
+            SigContext::Signature1 | SigContext::CounterSignature0 => false,
+        }
+    }
+
+    fn is_countersignature(&self) -> bool {
+        match *self {
+            SigContext::CounterSignature | SigContext::CounterSignature0 => true,
+            SigContext::Signature | SigContext::Signature1 => false,
+        }
+    }
+}
+
+/// Builder for the structure that is actually signed, the Sig_structure of RFC 9052 or the
+/// Countersign_structure of RFC 9338:
+///
+///```rust,ignore
+/// Sig_structure = [
+///   context : "Signature" / "Signature1",
+///   body_protected : empty_or_serialized_map,
+///   ? sign_protected : empty_or_serialized_map,
+///   external_aad : bstr,
+///   payload : bstr
+/// ]
+///
+/// Countersign_structure = [
+///   context : "CounterSignature" / "CounterSignature0",
+///   body_protected : empty_or_serialized_map,
+///   ? sign_protected : empty_or_serialized_map,
+///   external_aad : bstr,
+///   payload : bstr,
+///   ? other_fields : [+ bstr ]
+/// ]
+///```
+///
+/// `sign_protected` has to be set for the "Signature" and "CounterSignature" contexts and must not
+/// be set otherwise. `other_fields` can only be set for countersignatures. Without application
+/// supplied external_aad, it is an empty bstr.
+#[derive(Clone, Debug)]
+pub struct SigStructure {
+    context: SigContext,
+    body_protected: Vec<u8>,
+    sign_protected: Option<Vec<u8>>,
+    external_aad: Vec<u8>,
+    payload: Vec<u8>,
+    other_fields: Vec<Vec<u8>>,
+}
+
+impl SigStructure {
+    /// Start a Sig_structure for the given context over the serialized protected body header
+    /// (empty for an empty header) and the payload.
+    pub fn new(context: SigContext, body_protected: &[u8], payload: &[u8]) -> SigStructure {
+        SigStructure {
+            context,
+            body_protected: body_protected.to_vec(),
+            sign_protected: None,
+            external_aad: Vec::new(),
+            payload: payload.to_vec(),
+            other_fields: Vec::new(),
+        }
+    }
+
+    /// Set the serialized protected header of the signer.
+    pub fn sign_protected(mut self, sign_protected: &[u8]) -> SigStructure {
+        self.sign_protected = Some(sign_protected.to_vec());
+        self
+    }
+
+    /// Set the externally supplied data.
+    pub fn external_aad(mut self, external_aad: &[u8]) -> SigStructure {
+        self.external_aad = external_aad.to_vec();
+        self
+    }
+
+    /// Set the other fields of the countersigned structure, e.g. its signature.
+    pub fn other_fields(mut self, other_fields: Vec<Vec<u8>>) -> SigStructure {
+        self.other_fields = other_fields;
+        self
+    }
+
+    /// Serialize the structure to the bytes that are signed.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CoseError> {
+        let mut sig_structure_array: Vec<CborType> = Vec::new();
+        sig_structure_array.push(CborType::String(String::from(self.context.as_str())));
+        sig_structure_array.push(CborType::Bytes(self.body_protected.clone()));
+        match (self.context.has_sign_protected(), &self.sign_protected) {
+            (true, Some(sign_protected)) => {
+                sig_structure_array.push(CborType::Bytes(sign_protected.clone()))
+            }
+            (false, None) => {}
+            _ => return Err(CoseError::InvalidArgument),
+        };
+        sig_structure_array.push(CborType::Bytes(self.external_aad.clone()));
+        sig_structure_array.push(CborType::Bytes(self.payload.clone()));
+        if !self.other_fields.is_empty() {
+            if !self.context.is_countersignature() {
+                return Err(CoseError::InvalidArgument);
+            }
+            let other_fields = self.other_fields
+                .iter()
+                .map(|field| CborType::Bytes(field.clone()))
+                .collect();
+            sig_structure_array.push(CborType::Array(other_fields));
+        }
+
+        Ok(CborType::Array(sig_structure_array).serialize())
+    }
+}
+
+/// The context of a MAC_structure, i.e. the kind of message it is built for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MacContext {
+    /// A COSE_Mac message, which has recipients.
+    Mac,
+    /// A COSE_Mac0 message, where the key is known to the recipient.
+    Mac0,
+}
+
+impl MacContext {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            MacContext::Mac => "MAC",
+            MacContext::Mac0 => "MAC0",
+        }
+    }
+}
+
+/// Builder for the structure that is actually MACed, the MAC_structure of RFC 9052:
+///
+///```rust,ignore
+/// MAC_structure = [
+///   context : "MAC" / "MAC0",
+///   protected : empty_or_serialized_map,
+///   external_aad : bstr,
+///   payload : bstr
+/// ]
+///```
+///
+/// Without application supplied external_aad, it is an empty bstr.
+#[derive(Clone, Debug)]
+pub struct MacStructure {
+    context: MacContext,
+    protected: Vec<u8>,
+    external_aad: Vec<u8>,
+    payload: Vec<u8>,
+}
+
+impl MacStructure {
+    /// Start a MAC_structure for the given context over the serialized protected header (empty
+    /// for an empty header) and the payload.
+    pub fn new(context: MacContext, protected: &[u8], payload: &[u8]) -> MacStructure {
+        MacStructure {
+            context,
+            protected: protected.to_vec(),
+            external_aad: Vec::new(),
+            payload: payload.to_vec(),
+        }
+    }
+
+    /// Set the externally supplied data.
+    pub fn external_aad(mut self, external_aad: &[u8]) -> MacStructure {
+        self.external_aad = external_aad.to_vec();
+        self
+    }
+
+    /// Serialize the structure to the bytes that are MACed.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mac_structure_array = vec![CborType::String(String::from(self.context.as_str())),
+                                       CborType::Bytes(self.protected.clone()),
+                                       CborType::Bytes(self.external_aad.clone()),
+                                       CborType::Bytes(self.payload.clone())];
+        CborType::Array(mac_structure_array).serialize()
+    }
+}
+
+/// Truncate the output of an HMAC to the tag length of the algorithm. Returns `LibraryFailure`
+/// if the output is shorter than the tag.
+pub fn truncate_tag(algorithm: MacAlgorithm, mut mac: Vec<u8>) -> Result<Vec<u8>, CoseError> {
+    let tag_length = algorithm.info().tag_length;
+    if mac.len() < tag_length {
+        return Err(CoseError::LibraryFailure);
+    }
+    mac.truncate(tag_length);
+    Ok(mac)
+}
+
+/// Compare two byte strings in constant time, e.g. a received tag with the computed one. The
+/// time only depends on the lengths, not on where the contents differ.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference = a.iter().zip(b).fold(0u8, |difference, (x, y)| difference | (x ^ y));
+    // Keep the compiler from turning the fold into an early return.
+    ::std::hint::black_box(difference) == 0
+}
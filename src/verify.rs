@@ -0,0 +1,154 @@
+//! Verify all signatures of a COSE_Sign message against a policy.
+//!
+//! A `VerificationPolicy` says how many of the signatures have to verify: all of them, any one,
+//! or at least a threshold. It can further require verified signatures with certain algorithms,
+//! e.g. one ES256 and one PS256 signature like signed Firefox add-ons have. Every signature is
+//! verified, and the result of each is reported in a `VerificationReport` along with whether the
+//! policy is met.
+
+use {CoseError, SignatureAlgorithm, Verifier};
+use decoder::{CoseSignature, decode_signature};
+
+/// How many signatures have to verify.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SignerPolicy {
+    /// Every signature has to verify.
+    All,
+    /// At least one signature has to verify.
+    Any,
+    /// At least the given number of signatures has to verify.
+    Threshold(usize),
+}
+
+/// The policy `verify_signature` checks signatures against.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerificationPolicy {
+    signers: SignerPolicy,
+    required_algorithms: Vec<SignatureAlgorithm>,
+}
+
+impl VerificationPolicy {
+    /// A policy with the given signer policy and no required algorithms.
+    pub fn new(signers: SignerPolicy) -> VerificationPolicy {
+        VerificationPolicy {
+            signers,
+            required_algorithms: Vec::new(),
+        }
+    }
+
+    /// Require a verified signature with the given algorithm. Every required algorithm needs a
+    /// signature of its own, so requiring ES256 twice requires two verified ES256 signatures.
+    pub fn require_algorithm(mut self, algorithm: SignatureAlgorithm) -> VerificationPolicy {
+        self.required_algorithms.push(algorithm);
+        self
+    }
+
+    /// Require verified signatures with all of the given algorithms, see `require_algorithm`.
+    pub fn require_algorithms(mut self, algorithms: &[SignatureAlgorithm]) -> VerificationPolicy {
+        self.required_algorithms.extend_from_slice(algorithms);
+        self
+    }
+}
+
+/// The result of verifying one signature.
+#[derive(Debug, PartialEq)]
+pub struct SignerReport {
+    pub signature_type: SignatureAlgorithm,
+    pub signer_cert: Vec<u8>,
+    /// The result of the `Verifier`.
+    pub result: Result<(), CoseError>,
+}
+
+/// The result of verifying all signatures of a message against a policy.
+#[derive(Debug, PartialEq)]
+pub struct VerificationReport {
+    /// The results of the signatures, in the order of the message.
+    pub signers: Vec<SignerReport>,
+    /// `Ok` if the policy is met. `VerificationFailed` if too few signatures verified or a
+    /// required algorithm has no verified signature, and `InvalidArgument` for a threshold of 0.
+    pub result: Result<(), CoseError>,
+}
+
+impl VerificationReport {
+    /// Returns true if the policy is met.
+    pub fn is_verified(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// The number of signatures that verified.
+    pub fn verified_count(&self) -> usize {
+        self.signers.iter().filter(|signer| signer.result.is_ok()).count()
+    }
+
+    /// The algorithms of the signatures that verified.
+    fn verified_algorithms(&self) -> Vec<SignatureAlgorithm> {
+        self.signers
+            .iter()
+            .filter(|signer| signer.result.is_ok())
+            .map(|signer| signer.signature_type)
+            .collect()
+    }
+}
+
+/// Check the results of the signatures against the policy.
+fn check_policy(report: &VerificationReport, policy: &VerificationPolicy) -> Result<(), CoseError> {
+    let verified = report.verified_count();
+    let enough = match policy.signers {
+        SignerPolicy::All => verified == report.signers.len() && verified > 0,
+        SignerPolicy::Any => verified > 0,
+        SignerPolicy::Threshold(0) => return Err(CoseError::InvalidArgument),
+        SignerPolicy::Threshold(threshold) => verified >= threshold,
+    };
+    if !enough {
+        return Err(CoseError::VerificationFailed);
+    }
+    let mut verified_algorithms = report.verified_algorithms();
+    for required in &policy.required_algorithms {
+        match verified_algorithms.iter().position(|algorithm| algorithm == required) {
+            Some(index) => {
+                verified_algorithms.remove(index);
+            }
+            None => return Err(CoseError::VerificationFailed),
+        }
+    }
+    Ok(())
+}
+
+/// Verify the decoded signatures with their signing certificates and check the results against
+/// the policy. The signing certificates are not validated, see `x509::ChainValidator`.
+pub fn verify_signatures(
+    signatures: &[CoseSignature],
+    verifier: &dyn Verifier,
+    policy: &VerificationPolicy,
+) -> VerificationReport {
+    let signers = signatures.iter()
+        .map(|signature| {
+            SignerReport {
+                signature_type: signature.signature_type,
+                signer_cert: signature.signer_cert.clone(),
+                result: signature.verify_with(verifier),
+            }
+        })
+        .collect();
+    let mut report = VerificationReport {
+        signers,
+        result: Ok(()),
+    };
+    report.result = check_policy(&report, policy);
+    report
+}
+
+/// Decode the COSE_Sign `bytes` with the detached `payload` like `decode_signature`, verify its
+/// signatures and check the results against the policy.
+///
+/// Decoding errors are returned as errors. Otherwise the report is returned whether the policy
+/// is met or not: check `VerificationReport::is_verified` before trusting the payload.
+pub fn verify_signature(
+    bytes: &[u8],
+    payload: &[u8],
+    verifier: &dyn Verifier,
+    policy: &VerificationPolicy,
+) -> Result<VerificationReport, CoseError> {
+    let signatures = decode_signature(bytes, payload)?;
+    Ok(verify_signatures(&signatures, verifier, policy))
+}
@@ -0,0 +1,795 @@
+//! Validate the X.509 certificate chain of a signer, see RFC 5280 section 6.
+//!
+//! A `ChainValidator` builds a path from the signing certificate through the other certificates
+//! of a signature to one of its trust anchors. Every certificate in the path has to be valid at
+//! the time given by the caller, and its signature is checked with the `Verifier` and the
+//! certificate of its issuer. Intermediate CAs need the basic constraints extension with the cA
+//! flag, must respect path length constraints, and if they have a key usage extension it has to
+//! include keyCertSign. A key usage extension of the signing certificate has to include
+//! digitalSignature. If the validator requires an extended key usage, certificates with an
+//! extended key usage extension have to include it or anyExtendedKeyUsage. Certificates with
+//! other critical extensions, e.g. name constraints, are rejected.
+//!
+//! Trust anchors are taken as they are: their validity and extensions aren't checked. Names are
+//! compared as their DER encodings.
+//!
+//! Certificate signatures are verified with the signature algorithm of the registry that matches
+//! the certificate signature algorithm and the key of the issuer, e.g. ES256 for
+//! ecdsa-with-SHA256 and a P-256 key, and fail with `UnknownSignatureScheme` if there is none.
+//! ECDSA signatures are converted to the `r || s` form of COSE for the verifier. Note that ES256K
+//! verifiers only accept signatures with a low S value.
+//!
+//! `signer_public_key` gets the public key of a signing certificate as a `CoseKey` and checks
+//! that it fits the signature algorithm, so that a certificate with the wrong key fails with
+//! `KeyAlgorithmMismatch` before it is passed to a `Verifier`.
+
+use {CoseError, SignatureAlgorithm, Verifier};
+use algorithm::{ALGORITHMS, Curve, HashAlgorithm, KeyType, SignatureScheme};
+use key::{CoseKey, KeyParameters};
+
+const TAG_BOOLEAN: u8 = 0x01;
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_OID: u8 = 0x06;
+const TAG_UTC_TIME: u8 = 0x17;
+const TAG_GENERALIZED_TIME: u8 = 0x18;
+const TAG_SEQUENCE: u8 = 0x30;
+
+/// The tag of an explicitly tagged, context specific field.
+fn tag_context(number: u8) -> u8 {
+    0xa0 | number
+}
+
+const OID_BASIC_CONSTRAINTS: &str = "2.5.29.19";
+const OID_KEY_USAGE: &str = "2.5.29.15";
+const OID_EXTENDED_KEY_USAGE: &str = "2.5.29.37";
+const OID_SUBJECT_ALT_NAME: &str = "2.5.29.17";
+
+const OID_EC_PUBLIC_KEY: &str = "1.2.840.10045.2.1";
+const OID_RSA_ENCRYPTION: &str = "1.2.840.113549.1.1.1";
+const OID_ED25519: &str = "1.3.101.112";
+const OID_ED448: &str = "1.3.101.113";
+const CURVES: [(&str, Curve); 4] = [("1.2.840.10045.3.1.7", Curve::P256),
+                                    ("1.3.132.0.34", Curve::P384),
+                                    ("1.3.132.0.35", Curve::P521),
+                                    ("1.3.132.0.10", Curve::Secp256k1)];
+
+const OID_RSASSA_PSS: &str = "1.2.840.113549.1.1.10";
+const OID_MGF1: &str = "1.2.840.113549.1.1.8";
+const HASHES: [(&str, HashAlgorithm); 3] = [("2.16.840.1.101.3.4.2.1", HashAlgorithm::SHA256),
+                                            ("2.16.840.1.101.3.4.2.2", HashAlgorithm::SHA384),
+                                            ("2.16.840.1.101.3.4.2.3", HashAlgorithm::SHA512)];
+const CERTIFICATE_SIGNATURE_ALGORITHMS: [(&str, SignatureScheme, Option<HashAlgorithm>); 8] =
+    [("1.2.840.10045.4.3.2", SignatureScheme::Ecdsa, Some(HashAlgorithm::SHA256)),
+     ("1.2.840.10045.4.3.3", SignatureScheme::Ecdsa, Some(HashAlgorithm::SHA384)),
+     ("1.2.840.10045.4.3.4", SignatureScheme::Ecdsa, Some(HashAlgorithm::SHA512)),
+     ("1.2.840.113549.1.1.11", SignatureScheme::RsaPkcs1v15, Some(HashAlgorithm::SHA256)),
+     ("1.2.840.113549.1.1.12", SignatureScheme::RsaPkcs1v15, Some(HashAlgorithm::SHA384)),
+     ("1.2.840.113549.1.1.13", SignatureScheme::RsaPkcs1v15, Some(HashAlgorithm::SHA512)),
+     (OID_ED25519, SignatureScheme::EdDSA, None),
+     (OID_ED448, SignatureScheme::EdDSA, None)];
+
+/// The extended key usage for TLS servers.
+pub const EKU_SERVER_AUTH: &str = "1.3.6.1.5.5.7.3.1";
+/// The extended key usage for TLS clients.
+pub const EKU_CLIENT_AUTH: &str = "1.3.6.1.5.5.7.3.2";
+/// The extended key usage for signing code.
+pub const EKU_CODE_SIGNING: &str = "1.3.6.1.5.5.7.3.3";
+/// The extended key usage that allows any purpose.
+pub const EKU_ANY: &str = "2.5.29.37.0";
+
+const KEY_USAGE_DIGITAL_SIGNATURE: usize = 0;
+const KEY_USAGE_KEY_CERT_SIGN: usize = 5;
+
+/// The maximum number of certificates in a path, including the signing certificate and the trust
+/// anchor.
+const MAX_PATH_LENGTH: usize = 8;
+
+/// The maximum number of certificate signatures checked while building a path.
+const MAX_SIGNATURE_CHECKS: usize = 100;
+
+/// A reader of DER encoded elements. Only the low tag numbers and lengths X.509 certificates
+/// need are supported.
+struct Der<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Der<'a> {
+    fn new(input: &'a [u8]) -> Der<'a> {
+        Der { input }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    fn peek_tag(&self) -> Option<u8> {
+        self.input.first().cloned()
+    }
+
+    /// Read the next element and return its tag, its contents and its whole encoding.
+    fn read_element(&mut self) -> Result<(u8, &'a [u8], &'a [u8]), CoseError> {
+        let byte = |index: usize| self.input.get(index).cloned().ok_or(CoseError::DecodingFailure);
+        let tag = byte(0)?;
+        if tag & 0x1f == 0x1f {
+            return Err(CoseError::DecodingFailure);
+        }
+        // Lengths have to be encoded with the minimal number of bytes.
+        let (length, header_length) = match byte(1)? {
+            length if length < 0x80 => (length as usize, 2),
+            0x81 if byte(2)? >= 0x80 => (byte(2)? as usize, 3),
+            0x82 if byte(2)? > 0 => ((byte(2)? as usize) << 8 | byte(3)? as usize, 4),
+            _ => return Err(CoseError::DecodingFailure),
+        };
+        if self.input.len() - header_length < length {
+            return Err(CoseError::DecodingFailure);
+        }
+        let (element, rest) = self.input.split_at(header_length + length);
+        self.input = rest;
+        Ok((tag, &element[header_length..], element))
+    }
+
+    /// Read the next element, which must have the given tag, and return its contents.
+    fn read(&mut self, tag: u8) -> Result<&'a [u8], CoseError> {
+        match self.read_element()? {
+            (element_tag, contents, _) if element_tag == tag => Ok(contents),
+            _ => Err(CoseError::DecodingFailure),
+        }
+    }
+
+    /// Read the next element if it has the given tag.
+    fn read_optional(&mut self, tag: u8) -> Result<Option<&'a [u8]>, CoseError> {
+        if self.peek_tag() != Some(tag) {
+            return Ok(None);
+        }
+        self.read(tag).map(Some)
+    }
+
+    fn finish(&self) -> Result<(), CoseError> {
+        if !self.is_empty() {
+            return Err(CoseError::DecodingFailure);
+        }
+        Ok(())
+    }
+}
+
+/// Get the contents of `input`, which must be exactly one element with the given tag.
+fn read_single(input: &[u8], tag: u8) -> Result<&[u8], CoseError> {
+    let mut der = Der::new(input);
+    let contents = der.read(tag)?;
+    der.finish()?;
+    Ok(contents)
+}
+
+/// Decode an object identifier to its dotted form, e.g. "2.5.29.19".
+fn read_oid(contents: &[u8]) -> Result<String, CoseError> {
+    let mut arcs: Vec<u64> = Vec::new();
+    let mut arc: u64 = 0;
+    let mut arc_start = true;
+    for &byte in contents {
+        if (arc_start && byte == 0x80) || arc > u64::MAX >> 7 {
+            return Err(CoseError::DecodingFailure);
+        }
+        arc = arc << 7 | u64::from(byte & 0x7f);
+        arc_start = byte & 0x80 == 0;
+        if arc_start {
+            arcs.push(arc);
+            arc = 0;
+        }
+    }
+    if arcs.is_empty() || !arc_start {
+        return Err(CoseError::DecodingFailure);
+    }
+    let (first, second) = match arcs[0] {
+        arc if arc < 80 => (arc / 40, arc % 40),
+        arc => (2, arc - 80),
+    };
+    let mut oid = format!("{}.{}", first, second);
+    for arc in &arcs[1..] {
+        oid.push_str(&format!(".{}", arc));
+    }
+    Ok(oid)
+}
+
+/// Decode a non-negative integer that fits into a u64.
+fn read_small_integer(contents: &[u8]) -> Result<u64, CoseError> {
+    let minimal = match contents {
+        [] => false,
+        [first, ..] if first & 0x80 != 0 => false,
+        [0, second, ..] => second & 0x80 != 0,
+        _ => true,
+    };
+    if !minimal || contents.len() > 9 || (contents.len() == 9 && contents[0] != 0) {
+        return Err(CoseError::DecodingFailure);
+    }
+    Ok(contents.iter().fold(0, |value, &byte| value << 8 | u64::from(byte)))
+}
+
+/// Decode a bit string and return the number of unused bits and the bytes with the bits.
+fn read_bit_string(contents: &[u8]) -> Result<(u8, &[u8]), CoseError> {
+    match contents.split_first() {
+        Some((&0, bits)) => Ok((0, bits)),
+        Some((&unused, bits)) if unused < 8 && !bits.is_empty() => Ok((unused, bits)),
+        _ => Err(CoseError::DecodingFailure),
+    }
+}
+
+/// Decode the digits of a time.
+fn read_digits(digits: &[u8]) -> Result<i64, CoseError> {
+    digits.iter().try_fold(0, |value, &digit| {
+        if !digit.is_ascii_digit() {
+            return Err(CoseError::DecodingFailure);
+        }
+        Ok(value * 10 + i64::from(digit - b'0'))
+    })
+}
+
+/// The number of days from 1970-01-01 to the given date, see
+/// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Decode a UTCTime or GeneralizedTime in the form RFC 5280 requires, i.e. in UTC with seconds,
+/// to seconds since the Unix epoch.
+fn read_time(der: &mut Der) -> Result<i64, CoseError> {
+    let (tag, time, _) = der.read_element()?;
+    let (year, time) = match (tag, time.len()) {
+        (TAG_UTC_TIME, 13) => {
+            let year = read_digits(&time[..2])?;
+            (if year < 50 { 2000 + year } else { 1900 + year }, &time[2..])
+        }
+        (TAG_GENERALIZED_TIME, 15) => (read_digits(&time[..4])?, &time[4..]),
+        _ => return Err(CoseError::DecodingFailure),
+    };
+    let month = read_digits(&time[0..2])?;
+    let day = read_digits(&time[2..4])?;
+    let hour = read_digits(&time[4..6])?;
+    let minute = read_digits(&time[6..8])?;
+    let second = read_digits(&time[8..10])?;
+    if time[10] != b'Z' || !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 ||
+        minute > 59 || second > 59
+    {
+        return Err(CoseError::DecodingFailure);
+    }
+    Ok(days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second)
+}
+
+#[derive(Debug)]
+struct BasicConstraints {
+    ca: bool,
+    path_length: Option<u64>,
+}
+
+/// The parts of a DER encoded certificate that are needed for path validation.
+#[derive(Debug)]
+struct Certificate<'a> {
+    der: &'a [u8],
+    /// The encoded TBSCertificate, i.e. the signed bytes.
+    tbs: &'a [u8],
+    /// The contents of the signature AlgorithmIdentifier.
+    signature_algorithm: &'a [u8],
+    signature: &'a [u8],
+    /// The encoded names.
+    issuer: &'a [u8],
+    subject: &'a [u8],
+    not_before: i64,
+    not_after: i64,
+    /// The contents of the SubjectPublicKeyInfo.
+    public_key_info: &'a [u8],
+    basic_constraints: Option<BasicConstraints>,
+    /// The bits of the key usage extension.
+    key_usage: Option<&'a [u8]>,
+    extended_key_usage: Option<Vec<String>>,
+    unknown_critical_extension: bool,
+}
+
+impl<'a> Certificate<'a> {
+    fn parse(der: &'a [u8]) -> Result<Certificate<'a>, CoseError> {
+        let mut certificate = Der::new(read_single(der, TAG_SEQUENCE)?);
+        let (tag, tbs_contents, tbs) = certificate.read_element()?;
+        let signature_algorithm = certificate.read(TAG_SEQUENCE)?;
+        let signature = match read_bit_string(certificate.read(TAG_BIT_STRING)?)? {
+            (0, signature) => signature,
+            _ => return Err(CoseError::DecodingFailure),
+        };
+        certificate.finish()?;
+        if tag != TAG_SEQUENCE {
+            return Err(CoseError::DecodingFailure);
+        }
+
+        let mut tbs_der = Der::new(tbs_contents);
+        let version = match tbs_der.read_optional(tag_context(0))? {
+            Some(version) => read_small_integer(read_single(version, TAG_INTEGER)?)?,
+            None => 0,
+        };
+        tbs_der.read(TAG_INTEGER)?;
+        if tbs_der.read(TAG_SEQUENCE)? != signature_algorithm || version > 2 {
+            return Err(CoseError::DecodingFailure);
+        }
+        let (tag, _, issuer) = tbs_der.read_element()?;
+        let mut validity = Der::new(tbs_der.read(TAG_SEQUENCE)?);
+        let not_before = read_time(&mut validity)?;
+        let not_after = read_time(&mut validity)?;
+        validity.finish()?;
+        let (subject_tag, _, subject) = tbs_der.read_element()?;
+        let public_key_info = tbs_der.read(TAG_SEQUENCE)?;
+        if tag != TAG_SEQUENCE || subject_tag != TAG_SEQUENCE {
+            return Err(CoseError::DecodingFailure);
+        }
+        // The unique identifiers are not used.
+        tbs_der.read_optional(0x81)?;
+        tbs_der.read_optional(0x82)?;
+        let mut certificate = Certificate {
+            der,
+            tbs,
+            signature_algorithm,
+            signature,
+            issuer,
+            subject,
+            not_before,
+            not_after,
+            public_key_info,
+            basic_constraints: None,
+            key_usage: None,
+            extended_key_usage: None,
+            unknown_critical_extension: false,
+        };
+        if let Some(extensions) = tbs_der.read_optional(tag_context(3))? {
+            if version != 2 {
+                return Err(CoseError::DecodingFailure);
+            }
+            certificate.parse_extensions(read_single(extensions, TAG_SEQUENCE)?)?;
+        }
+        tbs_der.finish()?;
+        Ok(certificate)
+    }
+
+    fn parse_extensions(&mut self, extensions: &'a [u8]) -> Result<(), CoseError> {
+        let mut extensions = Der::new(extensions);
+        let mut ids = Vec::new();
+        while !extensions.is_empty() {
+            let mut extension = Der::new(extensions.read(TAG_SEQUENCE)?);
+            let id = read_oid(extension.read(TAG_OID)?)?;
+            let critical = match extension.read_optional(TAG_BOOLEAN)? {
+                None | Some([0x00]) => false,
+                Some([0xff]) => true,
+                _ => return Err(CoseError::DecodingFailure),
+            };
+            let value = extension.read(TAG_OCTET_STRING)?;
+            extension.finish()?;
+            match id.as_str() {
+                OID_BASIC_CONSTRAINTS => {
+                    let mut constraints = Der::new(read_single(value, TAG_SEQUENCE)?);
+                    let ca = constraints.read_optional(TAG_BOOLEAN)? == Some(&[0xff][..]);
+                    let path_length = match constraints.read_optional(TAG_INTEGER)? {
+                        Some(path_length) => Some(read_small_integer(path_length)?),
+                        None => None,
+                    };
+                    constraints.finish()?;
+                    self.basic_constraints = Some(BasicConstraints { ca, path_length });
+                }
+                OID_KEY_USAGE => {
+                    let (_, bits) = read_bit_string(read_single(value, TAG_BIT_STRING)?)?;
+                    self.key_usage = Some(bits);
+                }
+                OID_EXTENDED_KEY_USAGE => {
+                    let mut usages = Der::new(read_single(value, TAG_SEQUENCE)?);
+                    let mut extended_key_usage = Vec::new();
+                    while !usages.is_empty() {
+                        extended_key_usage.push(read_oid(usages.read(TAG_OID)?)?);
+                    }
+                    self.extended_key_usage = Some(extended_key_usage);
+                }
+                // The subject alternative name doesn't constrain the path.
+                OID_SUBJECT_ALT_NAME => {}
+                _ if critical => self.unknown_critical_extension = true,
+                _ => {}
+            }
+            if ids.contains(&id) {
+                return Err(CoseError::DecodingFailure);
+            }
+            ids.push(id);
+        }
+        Ok(())
+    }
+
+    /// Returns true if the key usage extension has the given bit set, or if there is none.
+    fn has_key_usage(&self, bit: usize) -> bool {
+        match self.key_usage {
+            Some(bits) => {
+                match bits.get(bit / 8) {
+                    Some(byte) => byte & (0x80 >> (bit % 8)) != 0,
+                    None => false,
+                }
+            }
+            None => true,
+        }
+    }
+}
+
+/// Get the key type and the curve of a SubjectPublicKeyInfo. RSA keys have no curve.
+fn read_public_key_type(public_key_info: &[u8]) -> Result<(KeyType, Option<Curve>), CoseError> {
+    let mut public_key_info = Der::new(public_key_info);
+    let mut algorithm = Der::new(public_key_info.read(TAG_SEQUENCE)?);
+    match read_oid(algorithm.read(TAG_OID)?)?.as_str() {
+        OID_EC_PUBLIC_KEY => {
+            let curve = read_oid(algorithm.read(TAG_OID)?)?;
+            match CURVES.iter().find(|&&(oid, _)| oid == curve) {
+                Some(&(_, curve)) => Ok((KeyType::EC2, Some(curve))),
+                None => Err(CoseError::UnknownSignatureScheme),
+            }
+        }
+        OID_RSA_ENCRYPTION => Ok((KeyType::RSA, None)),
+        OID_ED25519 => Ok((KeyType::OKP, Some(Curve::Ed25519))),
+        OID_ED448 => Ok((KeyType::OKP, Some(Curve::Ed448))),
+        _ => Err(CoseError::UnknownSignatureScheme),
+    }
+}
+
+/// Decode a non-negative integer to its big-endian bytes without leading zeros.
+fn read_unsigned(contents: &[u8]) -> Result<Vec<u8>, CoseError> {
+    match contents.first() {
+        Some(&first) if first & 0x80 == 0 => (),
+        _ => return Err(CoseError::MalformedInput),
+    }
+    let start = contents.iter().position(|&byte| byte != 0).unwrap_or(contents.len());
+    Ok(contents[start..].to_vec())
+}
+
+/// Get the key of a SubjectPublicKeyInfo. EC points have to be uncompressed.
+fn read_public_key(public_key_info: &[u8]) -> Result<KeyParameters, CoseError> {
+    let (key_type, curve) = read_public_key_type(public_key_info)?;
+    let mut der = Der::new(public_key_info);
+    der.read(TAG_SEQUENCE)?;
+    let key = match read_bit_string(der.read(TAG_BIT_STRING)?)? {
+        (0, key) => key,
+        _ => return Err(CoseError::DecodingFailure),
+    };
+    der.finish()?;
+    match (key_type, curve) {
+        (KeyType::EC2, Some(crv)) => {
+            let size = crv.key_size();
+            match key.split_first() {
+                Some((&0x04, point)) if point.len() == 2 * size => Ok(KeyParameters::Ec2 {
+                    crv,
+                    x: Some(point[..size].to_vec()),
+                    y: Some(point[size..].to_vec()),
+                    d: None,
+                }),
+                Some((&0x02, _)) | Some((&0x03, _)) => Err(CoseError::Unimplemented),
+                _ => Err(CoseError::MalformedInput),
+            }
+        }
+        (KeyType::OKP, Some(crv)) => Ok(KeyParameters::Okp {
+            crv,
+            x: Some(key.to_vec()),
+            d: None,
+        }),
+        _ => {
+            let mut rsa_public_key = Der::new(read_single(key, TAG_SEQUENCE)?);
+            let n = read_unsigned(rsa_public_key.read(TAG_INTEGER)?)?;
+            let e = read_unsigned(rsa_public_key.read(TAG_INTEGER)?)?;
+            rsa_public_key.finish()?;
+            Ok(KeyParameters::Rsa { n, e, private: None })
+        }
+    }
+}
+
+/// The size of an RSA modulus in bits.
+fn rsa_key_size(n: &[u8]) -> usize {
+    match n.first() {
+        Some(first) => n.len() * 8 - first.leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+/// Get the public key of the DER encoded certificate `cert`. EC2, OKP and RSA keys of the curves
+/// and key types of the algorithm registry are supported, others fail with
+/// `UnknownSignatureScheme`. Compressed EC points fail with `Unimplemented`.
+pub fn public_key(cert: &[u8]) -> Result<CoseKey, CoseError> {
+    let certificate = Certificate::parse(cert)?;
+    let key = CoseKey::new(read_public_key(certificate.public_key_info)?);
+    key.validate()?;
+    Ok(key)
+}
+
+/// Get the public key of the DER encoded certificate `cert` to verify signatures with
+/// `algorithm`, e.g. the `signer_cert` and `signature_type` of a `decoder::CoseSignature`.
+/// Fails with `KeyAlgorithmMismatch` if the key type or curve of the key isn't the one of the
+/// algorithm, e.g. for an ES256 signature with a P-384 key, or if an RSA key is shorter than the
+/// algorithm requires.
+pub fn signer_public_key(cert: &[u8], algorithm: SignatureAlgorithm) -> Result<CoseKey, CoseError> {
+    let key = public_key(cert)?;
+    if !key.fits_algorithm(algorithm) {
+        return Err(CoseError::KeyAlgorithmMismatch);
+    }
+    if let (KeyParameters::Rsa { n, .. }, Some(min_key_size)) =
+        (&key.params, algorithm.info().min_key_size)
+    {
+        if rsa_key_size(n) < min_key_size {
+            return Err(CoseError::KeyAlgorithmMismatch);
+        }
+    }
+    Ok(key)
+}
+
+/// Get the hash of an AlgorithmIdentifier of a hash function.
+fn read_hash_algorithm(algorithm_identifier: &[u8]) -> Result<HashAlgorithm, CoseError> {
+    let oid = read_oid(Der::new(algorithm_identifier).read(TAG_OID)?)?;
+    match HASHES.iter().find(|&&(hash_oid, _)| hash_oid == oid) {
+        Some(&(_, hash)) => Ok(hash),
+        None => Err(CoseError::UnknownSignatureScheme),
+    }
+}
+
+/// Get the hash of RSASSA-PSS-params (RFC 4055). The parameters must be the ones of PS256, PS384
+/// and PS512: MGF1 with the same hash and a salt as long as the hash.
+fn read_pss_parameters(parameters: &[u8]) -> Result<HashAlgorithm, CoseError> {
+    let mut parameters = Der::new(parameters);
+    // The defaults use SHA-1.
+    let hash = match parameters.read_optional(tag_context(0))? {
+        Some(hash) => read_hash_algorithm(read_single(hash, TAG_SEQUENCE)?)?,
+        None => return Err(CoseError::UnknownSignatureScheme),
+    };
+    let mask_generation = match parameters.read_optional(tag_context(1))? {
+        Some(mask_generation) => read_single(mask_generation, TAG_SEQUENCE)?,
+        None => return Err(CoseError::UnknownSignatureScheme),
+    };
+    let mut mask_generation = Der::new(mask_generation);
+    if read_oid(mask_generation.read(TAG_OID)?)? != OID_MGF1 ||
+        read_hash_algorithm(mask_generation.read(TAG_SEQUENCE)?)? != hash
+    {
+        return Err(CoseError::UnknownSignatureScheme);
+    }
+    let salt_length = match parameters.read_optional(tag_context(2))? {
+        Some(salt_length) => read_small_integer(read_single(salt_length, TAG_INTEGER)?)?,
+        None => return Err(CoseError::UnknownSignatureScheme),
+    };
+    if let Some(trailer) = parameters.read_optional(tag_context(3))? {
+        if read_small_integer(read_single(trailer, TAG_INTEGER)?)? != 1 {
+            return Err(CoseError::UnknownSignatureScheme);
+        }
+    }
+    parameters.finish()?;
+    if salt_length != hash.size() as u64 {
+        return Err(CoseError::UnknownSignatureScheme);
+    }
+    Ok(hash)
+}
+
+/// Find the signature algorithm of the registry for the signature algorithm of a certificate and
+/// the key of its issuer.
+fn find_signature_algorithm(
+    algorithm_identifier: &[u8],
+    key_type: KeyType,
+    curve: Option<Curve>,
+) -> Result<SignatureAlgorithm, CoseError> {
+    let mut algorithm_identifier = Der::new(algorithm_identifier);
+    let oid = read_oid(algorithm_identifier.read(TAG_OID)?)?;
+    let (scheme, hash) = if oid == OID_RSASSA_PSS {
+        let parameters = algorithm_identifier.read(TAG_SEQUENCE)?;
+        (SignatureScheme::RsaPss, Some(read_pss_parameters(parameters)?))
+    } else {
+        match CERTIFICATE_SIGNATURE_ALGORITHMS.iter().find(|&&(known, _, _)| known == oid) {
+            Some(&(_, scheme, hash)) => (scheme, hash),
+            None => return Err(CoseError::UnknownSignatureScheme),
+        }
+    };
+    ALGORITHMS.iter()
+        .map(|algorithm| algorithm.info())
+        .find(|info| {
+            info.scheme == scheme && info.hash == hash && info.key_type == key_type &&
+                !info.deprecated && (info.curve.is_none() || info.curve == curve)
+        })
+        .map(|info| info.algorithm)
+        .ok_or(CoseError::UnknownSignatureScheme)
+}
+
+/// Convert a DER encoded ECDSA signature to the `r || s` form of COSE.
+fn convert_ecdsa_signature(signature: &[u8], curve: Curve) -> Result<Vec<u8>, CoseError> {
+    let size = curve.key_size();
+    let mut integers = Der::new(read_single(signature, TAG_SEQUENCE)?);
+    let mut converted = Vec::with_capacity(2 * size);
+    for _ in 0..2 {
+        let integer = integers.read(TAG_INTEGER)?;
+        let integer = match integer.iter().position(|&byte| byte != 0) {
+            Some(start) => &integer[start..],
+            None => &[],
+        };
+        if integer.len() > size {
+            return Err(CoseError::VerificationFailed);
+        }
+        converted.resize(converted.len() + size - integer.len(), 0);
+        converted.extend_from_slice(integer);
+    }
+    integers.finish()?;
+    Ok(converted)
+}
+
+/// Verify the signature of `certificate` with the key of `issuer`.
+fn verify_issued_by(
+    certificate: &Certificate,
+    issuer: &Certificate,
+    verifier: &dyn Verifier,
+) -> Result<(), CoseError> {
+    let (key_type, curve) = read_public_key_type(issuer.public_key_info)?;
+    let algorithm = find_signature_algorithm(certificate.signature_algorithm, key_type, curve)?;
+    let signature = match (algorithm.info().scheme, curve) {
+        (SignatureScheme::Ecdsa, Some(curve)) => {
+            convert_ecdsa_signature(certificate.signature, curve)?
+        }
+        _ => certificate.signature.to_vec(),
+    };
+    verifier.verify(algorithm, issuer.der, certificate.tbs, &signature)
+}
+
+/// Validates certificate chains of signers against a set of trust anchors.
+#[derive(Clone, Debug)]
+pub struct ChainValidator {
+    trust_anchors: Vec<Vec<u8>>,
+    time: u64,
+    extended_key_usage: Option<String>,
+}
+
+impl ChainValidator {
+    /// A validator without trust anchors that checks the validity of certificates at `time`, in
+    /// seconds since the Unix epoch.
+    pub fn new(time: u64) -> ChainValidator {
+        ChainValidator {
+            trust_anchors: Vec::new(),
+            time,
+            extended_key_usage: None,
+        }
+    }
+
+    /// Add the DER encoded certificate `cert` to the trust anchors.
+    pub fn trust_anchor(mut self, cert: &[u8]) -> ChainValidator {
+        self.trust_anchors.push(cert.to_vec());
+        self
+    }
+
+    /// Require the extended key usage with the given object identifier, e.g.
+    /// `EKU_CODE_SIGNING`.
+    pub fn extended_key_usage(mut self, oid: &str) -> ChainValidator {
+        self.extended_key_usage = Some(oid.to_owned());
+        self
+    }
+
+    /// Validate the chain of the DER encoded signing certificate `signer_cert`, e.g. the
+    /// `signer_cert` and `certs` of a `decoder::CoseSignature`, and return the DER encoded
+    /// certificates of the path from `signer_cert` to the trust anchor.
+    ///
+    /// Paths through `certs` are tried until one is valid, certificates in `certs` that can't be
+    /// parsed are skipped. If there is no valid path, the error of the last path that was tried
+    /// is returned, `UntrustedCertificate` if no issuer was found or no path was found within
+    /// 100 signature checks. Certificates that are not valid at the time of the validator, or
+    /// that violate constraints, fail with `InvalidCertificate`. Malformed signing certificates
+    /// and trust anchors fail with `DecodingFailure`.
+    pub fn validate(
+        &self,
+        signer_cert: &[u8],
+        certs: &[Vec<u8>],
+        verifier: &dyn Verifier,
+    ) -> Result<Vec<Vec<u8>>, CoseError> {
+        let trust_anchors = self.trust_anchors
+            .iter()
+            .map(|cert| Certificate::parse(cert))
+            .collect::<Result<Vec<Certificate>, CoseError>>()?;
+        let intermediates: Vec<Certificate> =
+            certs.iter().filter_map(|cert| Certificate::parse(cert).ok()).collect();
+        let signer = Certificate::parse(signer_cert)?;
+        self.check_certificate(&signer)?;
+        if !signer.has_key_usage(KEY_USAGE_DIGITAL_SIGNATURE) {
+            return Err(CoseError::InvalidCertificate);
+        }
+        let mut path = vec![&signer];
+        if !trust_anchors.iter().any(|anchor| anchor.der == signer_cert) {
+            let mut signature_checks = MAX_SIGNATURE_CHECKS;
+            self.build_path(&mut path, &trust_anchors, &intermediates, verifier,
+                            &mut signature_checks)?;
+        }
+        Ok(path.iter().map(|cert| cert.der.to_vec()).collect())
+    }
+
+    /// Extend `path` with issuers until it reaches a trust anchor, checking at most
+    /// `signature_checks` signatures.
+    fn build_path<'a>(
+        &self,
+        path: &mut Vec<&'a Certificate<'a>>,
+        trust_anchors: &'a [Certificate<'a>],
+        intermediates: &'a [Certificate<'a>],
+        verifier: &dyn Verifier,
+        signature_checks: &mut usize,
+    ) -> Result<(), CoseError> {
+        let certificate = path[path.len() - 1];
+        let mut error = CoseError::UntrustedCertificate;
+        for anchor in trust_anchors.iter().filter(|anchor| anchor.subject == certificate.issuer) {
+            if *signature_checks == 0 {
+                return Err(CoseError::UntrustedCertificate);
+            }
+            *signature_checks -= 1;
+            match verify_issued_by(certificate, anchor, verifier) {
+                Ok(()) => {
+                    path.push(anchor);
+                    return Ok(());
+                }
+                Err(e) => error = e,
+            }
+        }
+        // Leave room for the issuer and a trust anchor.
+        if path.len() + 2 > MAX_PATH_LENGTH {
+            return Err(error);
+        }
+        for issuer in intermediates.iter().filter(|issuer| issuer.subject == certificate.issuer) {
+            // Certificates with the same subject and key can't make a path longer, whatever else
+            // differs between them.
+            if path.iter().any(|cert| {
+                cert.subject == issuer.subject && cert.public_key_info == issuer.public_key_info
+            }) {
+                continue;
+            }
+            if *signature_checks == 0 {
+                return Err(CoseError::UntrustedCertificate);
+            }
+            let length = path.len();
+            let result = self.check_issuer(issuer, length - 1).and_then(|_| {
+                *signature_checks -= 1;
+                verify_issued_by(certificate, issuer, verifier)
+            });
+            let result = result.and_then(|_| {
+                path.push(issuer);
+                self.build_path(path, trust_anchors, intermediates, verifier, signature_checks)
+            });
+            match result {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    path.truncate(length);
+                    error = e;
+                }
+            }
+        }
+        Err(error)
+    }
+
+    /// Check the validity period, the extended key usage and the critical extensions.
+    fn check_certificate(&self, certificate: &Certificate) -> Result<(), CoseError> {
+        let time = self.time as i64;
+        if time < certificate.not_before || time > certificate.not_after ||
+            certificate.unknown_critical_extension
+        {
+            return Err(CoseError::InvalidCertificate);
+        }
+        if let (Some(required), Some(usages)) =
+            (self.extended_key_usage.as_ref(), certificate.extended_key_usage.as_ref())
+        {
+            if !usages.iter().any(|usage| usage == required || usage == EKU_ANY) {
+                return Err(CoseError::InvalidCertificate);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that `issuer` may issue certificates to paths with `intermediates` intermediate CAs
+    /// below it.
+    fn check_issuer(&self, issuer: &Certificate, intermediates: usize) -> Result<(), CoseError> {
+        self.check_certificate(issuer)?;
+        match issuer.basic_constraints {
+            Some(BasicConstraints { ca: true, path_length: None }) => {}
+            Some(BasicConstraints { ca: true, path_length: Some(path_length) })
+                if intermediates as u64 <= path_length => {}
+            _ => return Err(CoseError::InvalidCertificate),
+        }
+        if !issuer.has_key_usage(KEY_USAGE_KEY_CERT_SIGN) {
+            return Err(CoseError::InvalidCertificate);
+        }
+        Ok(())
+    }
+}